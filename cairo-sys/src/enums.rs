@@ -167,6 +167,18 @@ pub enum PathDataType {
     PathClosePath
 }
 
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum Format {
+    FormatInvalid   = -1,
+    FormatARgb32    = 0,
+    FormatRgb24     = 1,
+    FormatA8        = 2,
+    FormatA1        = 3,
+    FormatRgb16_565 = 4,
+    FormatRgb30     = 5
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 pub enum Content {
@@ -267,3 +279,11 @@ pub enum HintMetrics {
     HintMetricsOff,
     HintMetricsOn
 }
+
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum RegionOverlap {
+    RegionOverlapIn,
+    RegionOverlapOut,
+    RegionOverlapPart
+}
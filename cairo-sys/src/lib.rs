@@ -17,7 +17,7 @@
 
 extern crate libc;
 
-use libc::{c_int, c_uint, c_char, c_double, c_ulong};
+use libc::{c_int, c_uint, c_uchar, c_char, c_double, c_ulong};
 
 pub mod enums;
 
@@ -37,13 +37,18 @@ use enums::{
     Extend,
     Filter,
     PathDataType,
-    PatternType
+    PatternType,
+    RegionOverlap,
+    Format
 };
 
 #[repr(C)]
 pub struct cairo_t;
 #[repr(C)]
 pub struct cairo_surface_t;
+/// Value of cairo_surface_type_t returned by cairo_surface_get_type() for image surfaces, kept
+/// as an integer since new surface types are added by newer versions of cairo.
+pub const CAIRO_SURFACE_TYPE_IMAGE: c_int = 0;
 #[repr(C)]
 pub struct cairo_pattern_t;
 #[repr(C)]
@@ -65,7 +70,13 @@ pub struct cairo_rectangle_list_t {
     pub num_rectangles: c_int
 }
 #[repr(C)]
-pub struct cairo_rectangle_int_t;
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct cairo_rectangle_int_t {
+    pub x: c_int,
+    pub y: c_int,
+    pub width: c_int,
+    pub height: c_int
+}
 #[repr(C)]
 pub struct cairo_content_t;
 #[repr(C)]
@@ -104,8 +115,6 @@ pub struct cairo_font_options_t;
 pub struct cairo_extend_t;
 #[repr(C)]
 pub struct cairo_filter_t;
-#[repr(C)]
-pub struct cairo_region_overlap_t;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -467,8 +476,7 @@ extern "C" {
 
     pub fn cairo_region_contains_point(region: *mut cairo_region_t, x: c_int, y: c_int) -> cairo_bool_t;
 
-    //enum                cairo_region_overlap_t;
-    pub fn cairo_region_contains_rectangle(region: *mut cairo_region_t, rectangle: *mut cairo_rectangle_int_t) -> cairo_region_overlap_t;
+    pub fn cairo_region_contains_rectangle(region: *mut cairo_region_t, rectangle: *mut cairo_rectangle_int_t) -> RegionOverlap;
 
     pub fn cairo_region_equal(a: *mut cairo_region_t, b: *mut cairo_region_t) -> cairo_bool_t;
 
@@ -578,6 +586,52 @@ extern "C" {
     //void                (*mut cairo_raster_source_finish_func_t)
     //                                                        (pattern: *mut cairo_pattern_t, callback_data: *mut void);
 
+    //CAIRO SURFACE
+    pub fn cairo_surface_reference(surface: *mut cairo_surface_t) -> *mut cairo_surface_t;
+
+    pub fn cairo_surface_destroy(surface: *mut cairo_surface_t);
+
+    pub fn cairo_surface_status(surface: *mut cairo_surface_t) -> Status;
+
+    pub fn cairo_surface_finish(surface: *mut cairo_surface_t);
+
+    pub fn cairo_surface_flush(surface: *mut cairo_surface_t);
+
+    pub fn cairo_surface_get_type(surface: *mut cairo_surface_t) -> c_int;
+
+    pub fn cairo_surface_mark_dirty(surface: *mut cairo_surface_t);
+
+    pub fn cairo_surface_mark_dirty_rectangle(surface: *mut cairo_surface_t, x: c_int, y: c_int, width: c_int, height: c_int);
+
+    pub fn cairo_surface_get_reference_count(surface: *mut cairo_surface_t) -> c_uint;
+
+    pub fn cairo_surface_set_device_offset(surface: *mut cairo_surface_t, x_offset: c_double, y_offset: c_double);
+
+    pub fn cairo_surface_get_device_offset(surface: *mut cairo_surface_t, x_offset: *mut c_double, y_offset: *mut c_double);
+
+    pub fn cairo_surface_write_to_png(surface: *mut cairo_surface_t, filename: *const c_char) -> Status;
+
+    //CAIRO IMAGE SURFACE
+    pub fn cairo_format_stride_for_width(format: Format, width: c_int) -> c_int;
+
+    pub fn cairo_image_surface_create(format: Format, width: c_int, height: c_int) -> *mut cairo_surface_t;
+
+    pub fn cairo_image_surface_create_for_data(data: *mut c_uchar, format: Format, width: c_int, height: c_int,
+        stride: c_int) -> *mut cairo_surface_t;
+
+    pub fn cairo_image_surface_create_from_png(filename: *const c_char) -> *mut cairo_surface_t;
+
+    pub fn cairo_image_surface_get_data(surface: *mut cairo_surface_t) -> *mut c_uchar;
+
+    pub fn cairo_image_surface_get_format(surface: *mut cairo_surface_t) -> Format;
+
+    pub fn cairo_image_surface_get_width(surface: *mut cairo_surface_t) -> c_int;
+
+    pub fn cairo_image_surface_get_height(surface: *mut cairo_surface_t) -> c_int;
+
+    pub fn cairo_image_surface_get_stride(surface: *mut cairo_surface_t) -> c_int;
+
+
     //CAIRO FONT
     pub fn cairo_font_face_reference(font_face: *mut cairo_font_face_t) -> *mut cairo_font_face_t;
 
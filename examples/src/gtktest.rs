@@ -174,8 +174,8 @@ fn main() {
     }));
 
     Connect::connect(&window, KeyPressEvent::new(&mut |key|{
-        let keyval = unsafe { (*key).keyval };
-        let keystate = unsafe { (*key).state };
+        let keyval = key.keyval;
+        let keystate = key.state;
 
         println!("key pressed: {} / {:?}", keyval, keystate);
         println!("text: {}", entry.get_text().unwrap());
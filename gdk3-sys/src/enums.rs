@@ -116,33 +116,6 @@ pub enum WindowEdge {
     SouthEast
 }
 
-#[repr(C)]
-#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
-/// Used to indicate which fields of a GdkGeometry struct should be paid attention to. Also, the presence/absence of GDK_HINT_POS,
-/// GDK_HINT_USER_POS , and GDK_HINT_USER_SIZE is significant, though they don't directly refer to GdkGeometry fields. GDK_HINT_USER_POS
-/// will be set automatically by GtkWindow if you call gtk_window_move(). GDK_HINT_USER_POS and GDK_HINT_USER_SIZE should be set if the
-/// user specified a size/position using a --geometry command-line argument; gtk_window_parse_geometry() automatically sets these flags.
-pub enum WindowHints {
-    /// indicates that the program has positioned the window
-    Pos,
-    /// min size fields are set
-    MinSize,
-    /// max size fields are set
-    MaxSize,
-    /// base size fields are set
-    BaseSize,
-    /// aspect ratio fields are set
-    Aspect,
-    /// resize increment fields are set
-    ResizeInc,
-    /// window gravity field is set
-    WinGravity,
-    /// indicates that the window’s position was explicitly set by the user
-    UserPos,
-    /// indicates that the window’s size was explicitly set by the user
-    UserSize
-}
-
 #[repr(C)]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 /// These are hints for the window manager that indicate what type of function the window has. The window manager can use this when
@@ -181,73 +154,6 @@ pub enum WindowTypeHint {
     DND
 }
 
-#[repr(C)]
-#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
-/// A set of bit-flags to indicate which events a window is to receive. Most of these masks map onto one or more of the GdkEventType
-/// event types above.
-/// 
-/// GDK_POINTER_MOTION_HINT_MASK is deprecated. It is a special mask to reduce the number of GDK_MOTION_NOTIFY events received. When
-/// using GDK_POINTER_MOTION_HINT_MASK, fewer GDK_MOTION_NOTIFY events will be sent, some of which are marked as a hint (the is_hint
-/// member is TRUE). To receive more motion events after a motion hint event, the application needs to asks for more, by calling
-/// gdk_event_request_motions().
-/// 
-/// Since GTK 3.8, motion events are already compressed by default, independent of this mechanism. This compression can be disabled with
-/// gdk_window_set_event_compression(). See the documentation of that function for details.
-/// 
-/// If GDK_TOUCH_MASK is enabled, the window will receive touch events from touch-enabled devices. Those will come as sequences of
-/// GdkEventTouch with type GDK_TOUCH_UPDATE, enclosed by two events with type GDK_TOUCH_BEGIN and GDK_TOUCH_END (or GDK_TOUCH_CANCEL).
-/// gdk_event_get_event_sequence() returns the event sequence for these events, so different sequences may be distinguished.
-pub enum EventMask {
-    /// receive expose events
-    ExposureMask,
-    /// receive all pointer motion events
-    PointerMotionMask,
-    /// deprecated. see the explanation above
-    PointerMotionHintMask,
-    /// receive pointer motion events while any button is pressed
-    ButtonMotionMask,
-    /// receive pointer motion events while 1 button is pressed
-    Button1MotionMask,
-    /// receive pointer motion events while 2 button is pressed
-    Button2MotionMask,
-    /// receive pointer motion events while 3 button is pressed
-    Button3MotionMask,
-    /// receive button press events
-    ButtonPressMask,
-    /// receive button release events
-    ButtonReleaseMask,
-    /// receive key press events
-    KeyPressMask,
-    /// receive key release events
-    KeyReleaseMask,
-    /// receive window enter events
-    EnterNotifyMask,
-    /// receive window leave events
-    LeaveNotifyMask,
-    /// receive focus change events
-    FocusChangeMask,
-    /// receive events about window configuration change
-    StructureMask,
-    /// receive property change events
-    PropertyChangeMask,
-    /// receive visibility change events
-    VisibilityNotifyMask,
-    /// receive proximity in events
-    ProximityInMask,
-    /// receive proximity out events
-    ProximityOutMask,
-    /// receive events about window configuration changes of child windows
-    SubstructureMask,
-    /// receive scroll events
-    ScrollMask,
-    /// receive touch events. Since 3.4
-    TouchMask,
-    /// receive smooth scrolling events. Since 3.4
-    SmoothScrollMask,
-    /// the combination of all the above event masks.
-    AllEventsMask
-}
-
 #[repr(C)]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 /// These are hints originally defined by the Motif toolkit. The window manager can use them when determining how to decorate the
@@ -382,7 +288,9 @@ pub enum GrabStatus {
     /// the grab window or the confine_to window are not viewable.
     NotViewable,
     /// the resource is frozen by an active grab of another client.
-    Frozen
+    Frozen,
+    /// the grab failed for some other reason, like a device without associated device.
+    Failed
 }
 
 #[repr(C)]
@@ -391,7 +299,7 @@ pub enum GrabStatus {
 /// "implementation notes" section of the Extended Window Manager Hints specification for more details.
 pub enum Gravity {
     /// the reference point is at the top left corner.
-    NorthWest,
+    NorthWest = 1,
     /// the reference point is in the middle of the top edge.
     North,
     /// the reference point is at the top right corner.
@@ -413,7 +321,7 @@ pub enum Gravity {
 }
 
 #[repr(C)]
-#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Hash, Debug, Copy)]
 /// The standard cursors available.
 /// For more information, please go [here](https://developer.gnome.org/gdk3/stable/gdk3-Cursors.html#GdkCursorType).
 pub enum CursorType {
@@ -540,6 +448,36 @@ pub enum PixbufAlphaMode {
     AlphaFull
 }
 
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+/// This enumeration describes the different interpolation modes that can be used with the scaling functions. Nearest is the
+/// fastest scaling method, but has horrible quality when scaling down. Bilinear is the best choice if you aren't sure what to
+/// choose, it has a good speed/quality balance.
+pub enum InterpType {
+    /// Nearest neighbor sampling; this is the fastest and lowest quality mode.
+    Nearest,
+    /// This is an accurate simulation of the PostScript image operator without any interpolation enabled.
+    Tiles,
+    /// Best quality/speed balance; use this mode by default.
+    Bilinear,
+    /// This is the slowest and highest quality reconstruction function.
+    Hyper
+}
+
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+/// The possible rotations which can be passed to gdk_pixbuf_rotate_simple().
+pub enum PixbufRotation {
+    /// No rotation.
+    None = 0,
+    /// Rotate by 90 degrees.
+    Counterclockwise = 90,
+    /// Rotate by 180 degrees.
+    Upsidedown = 180,
+    /// Rotate by 270 degrees.
+    Clockwise = 270
+}
+
 #[repr(C)]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 /// GdkFrameClockPhase is used to represent the different paint clock phases that can be requested. The elements of the enumeration
@@ -599,22 +537,134 @@ pub enum DragProtocol {
     Wayland
 }
 
-#[repr(C)]
-#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
-/// Used in GdkDragContext to indicate what the destination should do with the dropped data.
-pub enum DragAction {
-    /// Means nothing, and should not be used.
-    Default,
-    /// Copy the data.
-    Copy,
-    /// Move the data, i.e. first copy it, then delete it from the source using the DELETE target of the X selection protocol.
-    Move,
-    /// Add a link to the data. Note that this is only useful if source and destination agree on what it means.
-    Link,
-    /// Special action which tells the source that the destination will do something that the source doesn’t understand.
-    Private,
-    /// Ask the user what to do with the data.
-    Ask
+pub mod drag_action {
+    #![allow(non_upper_case_globals)]
+
+    bitflags! {
+        #[derive(Debug)]
+        #[repr(C)]
+        /// Used in GdkDragContext to indicate what the destination should do with the dropped data.
+        flags DragAction: u32 {
+            /// Means nothing, and should not be used.
+            const ActionDefault = 1 << 0,
+            /// Copy the data.
+            const ActionCopy    = 1 << 1,
+            /// Move the data, i.e. first copy it, then delete it from the source using the DELETE target of the X selection protocol.
+            const ActionMove    = 1 << 2,
+            /// Add a link to the data. Note that this is only useful if source and destination agree on what it means.
+            const ActionLink    = 1 << 3,
+            /// Special action which tells the source that the destination will do something that the source doesn’t understand.
+            const ActionPrivate = 1 << 4,
+            /// Ask the user what to do with the data.
+            const ActionAsk     = 1 << 5
+        }
+    }
+}
+
+pub mod window_hints {
+    #![allow(non_upper_case_globals)]
+
+    bitflags! {
+        #[derive(Debug)]
+        #[repr(C)]
+        /// Used to indicate which fields of a GdkGeometry struct should be paid attention to. Also, the presence/absence of GDK_HINT_POS,
+        /// GDK_HINT_USER_POS , and GDK_HINT_USER_SIZE is significant, though they don't directly refer to GdkGeometry fields. GDK_HINT_USER_POS
+        /// will be set automatically by GtkWindow if you call gtk_window_move(). GDK_HINT_USER_POS and GDK_HINT_USER_SIZE should be set if the
+        /// user specified a size/position using a --geometry command-line argument; gtk_window_parse_geometry() automatically sets these flags.
+        flags WindowHints: u32 {
+            /// indicates that the program has positioned the window
+            const HintPos        = 1 << 0,
+            /// min size fields are set
+            const HintMinSize    = 1 << 1,
+            /// max size fields are set
+            const HintMaxSize    = 1 << 2,
+            /// base size fields are set
+            const HintBaseSize   = 1 << 3,
+            /// aspect ratio fields are set
+            const HintAspect     = 1 << 4,
+            /// resize increment fields are set
+            const HintResizeInc  = 1 << 5,
+            /// window gravity field is set
+            const HintWinGravity = 1 << 6,
+            /// indicates that the window’s position was explicitly set by the user
+            const HintUserPos    = 1 << 7,
+            /// indicates that the window’s size was explicitly set by the user
+            const HintUserSize   = 1 << 8
+        }
+    }
+}
+
+pub mod event_mask {
+    #![allow(non_upper_case_globals)]
+
+    bitflags! {
+        #[derive(Debug)]
+        #[repr(C)]
+        /// A set of bit-flags to indicate which events a window is to receive. Most of these masks map onto one or more of the GdkEventType
+        /// event types above.
+        /// 
+        /// GDK_POINTER_MOTION_HINT_MASK is deprecated. It is a special mask to reduce the number of GDK_MOTION_NOTIFY events received. When
+        /// using GDK_POINTER_MOTION_HINT_MASK, fewer GDK_MOTION_NOTIFY events will be sent, some of which are marked as a hint (the is_hint
+        /// member is TRUE). To receive more motion events after a motion hint event, the application needs to asks for more, by calling
+        /// gdk_event_request_motions().
+        /// 
+        /// Since GTK 3.8, motion events are already compressed by default, independent of this mechanism. This compression can be disabled with
+        /// gdk_window_set_event_compression(). See the documentation of that function for details.
+        /// 
+        /// If GDK_TOUCH_MASK is enabled, the window will receive touch events from touch-enabled devices. Those will come as sequences of
+        /// GdkEventTouch with type GDK_TOUCH_UPDATE, enclosed by two events with type GDK_TOUCH_BEGIN and GDK_TOUCH_END (or GDK_TOUCH_CANCEL).
+        /// gdk_event_get_event_sequence() returns the event sequence for these events, so different sequences may be distinguished.
+        flags EventMask: u32 {
+            /// receive expose events
+            const ExposureMask          = 1 << 1,
+            /// receive all pointer motion events
+            const PointerMotionMask     = 1 << 2,
+            /// deprecated. see the explanation above
+            const PointerMotionHintMask = 1 << 3,
+            /// receive pointer motion events while any button is pressed
+            const ButtonMotionMask      = 1 << 4,
+            /// receive pointer motion events while 1 button is pressed
+            const Button1MotionMask     = 1 << 5,
+            /// receive pointer motion events while 2 button is pressed
+            const Button2MotionMask     = 1 << 6,
+            /// receive pointer motion events while 3 button is pressed
+            const Button3MotionMask     = 1 << 7,
+            /// receive button press events
+            const ButtonPressMask       = 1 << 8,
+            /// receive button release events
+            const ButtonReleaseMask     = 1 << 9,
+            /// receive key press events
+            const KeyPressMask          = 1 << 10,
+            /// receive key release events
+            const KeyReleaseMask        = 1 << 11,
+            /// receive window enter events
+            const EnterNotifyMask       = 1 << 12,
+            /// receive window leave events
+            const LeaveNotifyMask       = 1 << 13,
+            /// receive focus change events
+            const FocusChangeMask       = 1 << 14,
+            /// receive events about window configuration change
+            const StructureMask         = 1 << 15,
+            /// receive property change events
+            const PropertyChangeMask    = 1 << 16,
+            /// receive visibility change events
+            const VisibilityNotifyMask  = 1 << 17,
+            /// receive proximity in events
+            const ProximityInMask       = 1 << 18,
+            /// receive proximity out events
+            const ProximityOutMask      = 1 << 19,
+            /// receive events about window configuration changes of child windows
+            const SubstructureMask      = 1 << 20,
+            /// receive scroll events
+            const ScrollMask            = 1 << 21,
+            /// receive touch events. Since 3.4
+            const TouchMask             = 1 << 22,
+            /// receive smooth scrolling events. Since 3.4
+            const SmoothScrollMask      = 1 << 23,
+            /// the combination of all the above event masks.
+            const AllEventsMask         = 0xFFFFFE
+        }
+    }
 }
 
 pub mod modifier_type {
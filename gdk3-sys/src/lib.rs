@@ -19,11 +19,15 @@
 extern crate libc;
 #[macro_use] extern crate bitflags;
 extern crate glib_sys as glib_ffi;
+extern crate cairo_sys as cairo_ffi;
 
 pub mod enums;
+#[cfg(feature = "X11")]
+pub mod x11;
 
-use libc::{c_int, c_char, c_double, c_void, c_uint, c_uchar, c_ulong};
-use glib_ffi::Gboolean;
+use libc::{c_int, c_char, c_double, c_void, c_uint, c_uchar, c_ulong, size_t};
+use glib_ffi::{Gboolean, GQuark, C_GError, C_GSList, C_GTimeVal};
+use cairo_ffi::{cairo_t, cairo_surface_t, cairo_region_t};
 
 #[repr(C)]
 pub struct C_GdkWindow;
@@ -38,6 +42,8 @@ pub struct C_GdkVisual;
 #[repr(C)]
 pub struct C_GdkEvent;
 #[repr(C)]
+pub struct C_GdkEventSequence;
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct C_GdkRectangle { // FIXME should be just an alias to cairo_rectangle_int_t
     pub x: c_int,
@@ -49,7 +55,7 @@ pub struct C_GdkRectangle { // FIXME should be just an alias to cairo_rectangle_
 pub struct C_GdkFrameClock;
 /// The Color structure is used to describe a color, similar to the XColor struct used in the X11 drawing API.
 #[repr(C)]
-#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+#[derive(Clone, Debug, Copy)]
 pub struct C_GdkColor {
     /// For allocated colors, the pixel value used to draw this color on the screen. Not used anymore.
     pub pixel:  u32,
@@ -60,9 +66,31 @@ pub struct C_GdkColor {
     /// The blue component of the color
     pub blue:   u16
 }
+
+// The pixel isn't used anymore, colors are compared on their components like gdk_color_equal
+impl PartialEq for C_GdkColor {
+    fn eq(&self, other: &C_GdkColor) -> bool {
+        self.red == other.red && self.green == other.green && self.blue == other.blue
+    }
+}
+
+impl Eq for C_GdkColor {}
+
+impl ::std::hash::Hash for C_GdkColor {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        (self.red, self.green, self.blue).hash(state)
+    }
+}
+
+impl PartialOrd for C_GdkColor {
+    fn partial_cmp(&self, other: &C_GdkColor) -> Option<::std::cmp::Ordering> {
+        (self.red, self.green, self.blue).partial_cmp(&(other.red, other.green, other.blue))
+    }
+}
+
 /// The GdkRGBA structure is used to represent a (possibly translucent) color, in a way that is compatible with cairos notion of color.
 #[repr(C)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct C_GdkRGBA {
     /// The intensity of the red channel from 0.0 to 1.0 inclusive
     pub red: f64,
@@ -73,6 +101,31 @@ pub struct C_GdkRGBA {
     /// The opacity of the color from 0.0 for completely translucent to 1.0 for opaque
     pub alpha: f64
 }
+
+impl C_GdkRGBA {
+    // Adding 0. turns -0. into 0., the channels are then compared by their bits so the equality
+    // stays reflexive with NaN channels
+    fn channel_bits(&self) -> [u64; 4] {
+        let channels = [self.red + 0., self.green + 0., self.blue + 0., self.alpha + 0.];
+
+        unsafe { ::std::mem::transmute(channels) }
+    }
+}
+
+impl PartialEq for C_GdkRGBA {
+    fn eq(&self, other: &C_GdkRGBA) -> bool {
+        self.channel_bits() == other.channel_bits()
+    }
+}
+
+impl Eq for C_GdkRGBA {}
+
+impl ::std::hash::Hash for C_GdkRGBA {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        self.channel_bits().hash(state)
+    }
+}
+
 #[repr(C)]
 pub struct C_GdkCursor;
 #[repr(C)]
@@ -103,8 +156,15 @@ pub struct C_GdkGeometry {
 }
 #[repr(C)]
 pub struct C_GdkDevice;
+/// The maximum number of axes in a C_GdkTimeCoord
+pub const GDK_MAX_TIMECOORD_AXES: usize = 128;
 #[repr(C)]
-pub struct C_GdkTimeCoord;
+pub struct C_GdkTimeCoord {
+    /// the timestamp of the event
+    pub time: u32,
+    /// the values of the axes of the device
+    pub axes: [c_double; GDK_MAX_TIMECOORD_AXES]
+}
 pub type C_GdkAtom = *mut c_void;
 #[repr(C)]
 pub struct C_GdkDeviceManager;
@@ -113,6 +173,14 @@ pub struct C_GdkAppLaunchContext;
 #[repr(C)]
 pub struct C_GdkPixbuf;
 #[repr(C)]
+pub struct C_GdkPixbufFormat;
+#[repr(C)]
+pub struct C_GdkPixbufLoader;
+#[repr(C)]
+pub struct C_GdkPixbufAnimation;
+#[repr(C)]
+pub struct C_GdkPixbufAnimationIter;
+#[repr(C)]
 pub struct C_GdkFrameTimings;
 #[repr(C)]
 pub struct C_GdkWindowAttr {
@@ -134,6 +202,17 @@ pub struct C_GdkWindowAttr {
 #[repr(C)]
 pub struct C_GdkDragContext;
 
+#[repr(C)]
+pub struct C_GdkKeymap;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct C_GdkKeymapKey {
+    pub keycode: c_uint,
+    pub group:   c_int,
+    pub level:   c_int
+}
+
 // GdkWindowAttributesTypes
 /// Honor the title field
 pub const GDK_WA_TITLE: i32 = 1 << 1;
@@ -173,7 +252,7 @@ extern "C" {
     pub fn gdk_set_double_click_time     (msec: c_uint);
     pub fn gdk_beep                      ();
     pub fn gdk_error_trap_push           ();
-    pub fn gdk_error_trap_pop            ();
+    pub fn gdk_error_trap_pop            () -> c_int;
     pub fn gdk_error_trap_pop_ignored    ();
 
     //=========================================================================
@@ -232,11 +311,11 @@ extern "C" {
     pub fn gdk_window_begin_move_drag_for_device(window: *mut C_GdkWindow, device: *mut C_GdkDevice, button: c_int, root_x: c_int,
         root_y: c_int, timestamp: u32);
     pub fn gdk_window_show_window_menu   (window: *mut C_GdkWindow, event: *mut C_GdkEvent);
-    pub fn gdk_window_constrain_size     (window: *mut C_GdkWindow, flags: enums::WindowHints, width: c_int, height: c_int,
+    pub fn gdk_window_constrain_size     (window: *mut C_GdkWindow, flags: enums::window_hints::WindowHints, width: c_int, height: c_int,
         new_width: *mut c_int, new_height: *mut c_int);
     pub fn gdk_window_beep               (window: *mut C_GdkWindow);
     pub fn gdk_window_get_scale_factor   (window: *mut C_GdkWindow) -> c_int;
-    //pub fn gdk_window_set_opaque_region  (window: *mut C_GdkWindow, region: *mut cairo_region_t);
+    pub fn gdk_window_set_opaque_region  (window: *mut C_GdkWindow, region: *mut cairo_region_t);
     //pub fn gdk_window_get_clip_region    (window: *mut C_GdkWindow) -> *mut cairo_region_t;
     pub fn gdk_window_begin_paint_rect   (window: *mut C_GdkWindow, rectangle: *const C_GdkRectangle);
     //pub fn gdk_window_begin_paint_region (window: *mut C_GdkWindow, region: *const cairo_region_t);
@@ -262,12 +341,12 @@ extern "C" {
     pub fn gdk_window_get_focus_on_map   (window: *mut C_GdkWindow) -> Gboolean;
     //pub fn gdk_window_add_filter         (window: *mut C_GdkWindow, function: GdkFilterFunc, data: *mut c_void);
     //pub fn gdk_window_remove_filter      (window: *mut C_GdkWindow, function: GdkFilterFunc, data: *mut c_void);
-    //pub fn gdk_window_shape_combine_region(window: *mut C_GdkWindow, shape_region: *const cairo_region_t, offset_x: c_int,
-    //    offset_y: c_int);
+    pub fn gdk_window_shape_combine_region(window: *mut C_GdkWindow, shape_region: *const cairo_region_t, offset_x: c_int,
+        offset_y: c_int);
     pub fn gdk_window_set_child_shapes   (window: *mut C_GdkWindow);
     pub fn gdk_window_merge_child_shapes (window: *mut C_GdkWindow);
-    //pub fn gdk_window_input_shape_combine_region(window: *mut C_GdkWindow, shape_region: *const cairo_region_t, offset_x: c_int,
-    //    offset_y: c_int);
+    pub fn gdk_window_input_shape_combine_region(window: *mut C_GdkWindow, shape_region: *const cairo_region_t, offset_x: c_int,
+        offset_y: c_int);
     pub fn gdk_window_set_child_input_shapes(window: *mut C_GdkWindow);
     pub fn gdk_window_merge_child_input_shapes(window: *mut C_GdkWindow);
     pub fn gdk_window_set_static_gravities(window: *mut C_GdkWindow, use_static: Gboolean) -> Gboolean;
@@ -279,7 +358,7 @@ extern "C" {
     pub fn gdk_window_get_cursor         (window: *mut C_GdkWindow) -> *mut C_GdkCursor;
     pub fn gdk_window_get_user_data      (window: *mut C_GdkWindow, data: *mut *mut c_void);
     pub fn gdk_window_get_geometry       (window: *mut C_GdkWindow, x: *mut c_int, y: *mut c_int, width: *mut c_int, height: *mut c_int);
-    pub fn gdk_window_set_geometry_hints (window: *mut C_GdkWindow, geometry: *const C_GdkGeometry, geom_mask: enums::WindowHints);
+    pub fn gdk_window_set_geometry_hints (window: *mut C_GdkWindow, geometry: *const C_GdkGeometry, geom_mask: enums::window_hints::WindowHints);
     pub fn gdk_window_get_width          (window: *mut C_GdkWindow) -> c_int;
     pub fn gdk_window_get_height         (window: *mut C_GdkWindow) -> c_int;
     //pub fn gdk_window_set_icon_list      (window: *mut C_GdkWindow, pixbufs: *mut GList);
@@ -305,8 +384,8 @@ extern "C" {
     //pub fn gdk_window_get_children       (window: *mut C_GdkWindow) -> *mut GList;
     //pub fn gdk_window_get_children_with_user_data(window: *mut C_GdkWindow, user_data: *mut c_void) -> *mut GList;
     //pub fn gdk_window_peek_children      (window: *mut C_GdkWindow) -> *mut GList;
-    pub fn gdk_window_get_events         (window: *mut C_GdkWindow) -> enums::EventMask;
-    pub fn gdk_window_set_events         (window: *mut C_GdkWindow, event_mask: enums::EventMask);
+    pub fn gdk_window_get_events         (window: *mut C_GdkWindow) -> enums::event_mask::EventMask;
+    pub fn gdk_window_set_events         (window: *mut C_GdkWindow, event_mask: enums::event_mask::EventMask);
     pub fn gdk_window_set_icon_name      (window: *mut C_GdkWindow, name: *const c_char);
     pub fn gdk_window_set_transient_for  (window: *mut C_GdkWindow, parent: *mut C_GdkWindow);
     pub fn gdk_window_set_role           (window: *mut C_GdkWindow, role: *const c_char);
@@ -321,10 +400,10 @@ extern "C" {
     pub fn gdk_window_set_support_multidevice(window: *mut C_GdkWindow, support_multidevice: Gboolean);
     pub fn gdk_window_get_device_cursor  (window: *mut C_GdkWindow, device: *mut C_GdkDevice) -> *mut C_GdkCursor;
     pub fn gdk_window_set_device_cursor  (window: *mut C_GdkWindow, device: *mut C_GdkDevice, cursor: *mut C_GdkCursor);
-    pub fn gdk_window_get_device_events  (window: *mut C_GdkWindow, device: *mut C_GdkDevice) -> enums::EventMask;
-    pub fn gdk_window_set_device_events  (window: *mut C_GdkWindow, device: *mut C_GdkDevice, event_mask: enums::EventMask);
-    pub fn gdk_window_get_source_events  (window: *mut C_GdkWindow, source: enums::InputSource) -> enums::EventMask;
-    pub fn gdk_window_set_source_events  (window: *mut C_GdkWindow, source: enums::InputSource, event_mask: enums::EventMask);
+    pub fn gdk_window_get_device_events  (window: *mut C_GdkWindow, device: *mut C_GdkDevice) -> enums::event_mask::EventMask;
+    pub fn gdk_window_set_device_events  (window: *mut C_GdkWindow, device: *mut C_GdkDevice, event_mask: enums::event_mask::EventMask);
+    pub fn gdk_window_get_source_events  (window: *mut C_GdkWindow, source: enums::InputSource) -> enums::event_mask::EventMask;
+    pub fn gdk_window_set_source_events  (window: *mut C_GdkWindow, source: enums::InputSource, event_mask: enums::event_mask::EventMask);
     pub fn gdk_window_get_event_compression(window: *mut C_GdkWindow) -> Gboolean;
     pub fn gdk_window_set_event_compression(window: *mut C_GdkWindow, event_compression: Gboolean);
     //pub fn gdk_offscreen_window_get_surface(window: *mut C_GdkWindow) -> *mut cairo_surface_t;
@@ -343,6 +422,37 @@ extern "C" {
     //let GdkWindowChildFunc = fn(window: *mut C_GdkWindow, user_data: *mut c_void);
     //let GdkFilterFunc = fn(xevent: *mut C_GdkXEvent, event: *mut C_GdkEvent, data: *mut c_void) -> GdkFilterReturn;
 
+    //=========================================================================
+    // GdkEvent                                                          NOT OK
+    //=========================================================================
+    pub fn gdk_events_pending                () -> Gboolean;
+    pub fn gdk_event_peek                    () -> *mut C_GdkEvent;
+    pub fn gdk_event_get                     () -> *mut C_GdkEvent;
+    pub fn gdk_event_put                     (event: *const C_GdkEvent);
+    pub fn gdk_event_new                     (type_: c_int) -> *mut C_GdkEvent;
+    pub fn gdk_event_copy                    (event: *const C_GdkEvent) -> *mut C_GdkEvent;
+    pub fn gdk_event_free                    (event: *mut C_GdkEvent);
+    pub fn gdk_event_get_button              (event: *const C_GdkEvent, button: *mut c_uint) -> Gboolean;
+    pub fn gdk_event_get_click_count         (event: *const C_GdkEvent, click_count: *mut c_uint) -> Gboolean;
+    pub fn gdk_event_get_coords              (event: *const C_GdkEvent, x_win: *mut c_double, y_win: *mut c_double) -> Gboolean;
+    pub fn gdk_event_get_keycode             (event: *const C_GdkEvent, keycode: *mut u16) -> Gboolean;
+    pub fn gdk_event_get_keyval              (event: *const C_GdkEvent, keyval: *mut c_uint) -> Gboolean;
+    pub fn gdk_event_get_root_coords         (event: *const C_GdkEvent, x_root: *mut c_double, y_root: *mut c_double) -> Gboolean;
+    pub fn gdk_event_get_scroll_direction    (event: *const C_GdkEvent, direction: *mut c_int) -> Gboolean;
+    pub fn gdk_event_get_scroll_deltas       (event: *const C_GdkEvent, delta_x: *mut c_double, delta_y: *mut c_double) -> Gboolean;
+    pub fn gdk_event_get_axis                (event: *const C_GdkEvent, axis_use: enums::AxisUse, value: *mut c_double) -> Gboolean;
+    pub fn gdk_event_get_state               (event: *const C_GdkEvent, state: *mut enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gdk_event_get_time                (event: *const C_GdkEvent) -> u32;
+    pub fn gdk_event_get_window              (event: *const C_GdkEvent) -> *mut C_GdkWindow;
+    pub fn gdk_event_get_event_sequence      (event: *const C_GdkEvent) -> *mut C_GdkEventSequence;
+    pub fn gdk_event_triggers_context_menu   (event: *const C_GdkEvent) -> Gboolean;
+    pub fn gdk_event_set_screen              (event: *mut C_GdkEvent, screen: *mut C_GdkScreen);
+    pub fn gdk_event_get_screen              (event: *const C_GdkEvent) -> *mut C_GdkScreen;
+    pub fn gdk_event_get_device              (event: *const C_GdkEvent) -> *mut C_GdkDevice;
+    pub fn gdk_event_set_device              (event: *mut C_GdkEvent, device: *mut C_GdkDevice);
+    pub fn gdk_event_get_source_device       (event: *const C_GdkEvent) -> *mut C_GdkDevice;
+    pub fn gdk_event_set_source_device       (event: *mut C_GdkEvent, device: *mut C_GdkDevice);
+
     //=========================================================================
     // GdkDevice                                                         NOT OK
     //=========================================================================
@@ -364,7 +474,7 @@ extern "C" {
     pub fn gdk_device_get_n_keys           (device: *mut C_GdkDevice) -> c_int;
     pub fn gdk_device_warp                 (device: *mut C_GdkDevice, screen: *mut C_GdkScreen, x: c_int, y: c_int);
     pub fn gdk_device_grab                 (device: *mut C_GdkDevice, window: *mut C_GdkWindow, grab_ownership: enums::GrabOwnership,
-        owner_events: Gboolean, event_mask: enums::EventMask, cursor: *mut C_GdkCursor, time_: u32) -> enums::GrabStatus;
+        owner_events: Gboolean, event_mask: enums::event_mask::EventMask, cursor: *mut C_GdkCursor, time_: u32) -> enums::GrabStatus;
     pub fn gdk_device_ungrab               (device: *mut C_GdkDevice, time_: u32);
     pub fn gdk_device_get_state            (device: *mut C_GdkDevice, window: *mut C_GdkWindow, axes: *mut c_double,
         mask: *mut enums::modifier_type::ModifierType);
@@ -374,7 +484,7 @@ extern "C" {
     pub fn gdk_device_get_window_at_position_double(device: *mut C_GdkDevice, win_x: *mut c_double,
         win_y: *mut c_double) -> *mut C_GdkWindow;
     pub fn gdk_device_get_history          (device: *mut C_GdkDevice, window: *mut C_GdkWindow, start: u32, stop: u32,
-        events: *mut *mut *mut C_GdkTimeCoord, n_events: *mut c_int);
+        events: *mut *mut *mut C_GdkTimeCoord, n_events: *mut c_int) -> Gboolean;
     pub fn gdk_device_free_history         (events: *mut *mut C_GdkTimeCoord, n_events: c_int);
     pub fn gdk_device_get_axis             (device: *mut C_GdkDevice, axes: *mut c_double, use_: enums::AxisUse,
         value: *mut c_double) -> Gboolean;
@@ -500,13 +610,13 @@ extern "C" {
     //=========================================================================
     pub fn gdk_cursor_new                     (cursor_type: enums::CursorType) -> *mut C_GdkCursor;
     pub fn gdk_cursor_new_from_pixbuf         (display: *mut C_GdkDisplay, pixbuf: *mut C_GdkPixbuf, x: c_int, y: c_int) -> *mut C_GdkCursor;
-    //pub fn gdk_cursor_new_from_surface        (display: *mut C_GdkDisplay, surface: *mut cairo_surface_t, x: c_double,
-    //    y: c_double) -> *mut C_GdkCursor;
+    pub fn gdk_cursor_new_from_surface        (display: *mut C_GdkDisplay, surface: *mut cairo_surface_t, x: c_double,
+        y: c_double) -> *mut C_GdkCursor;
     pub fn gdk_cursor_new_from_name           (display: *mut C_GdkDisplay, name: *const c_char) -> *mut C_GdkCursor;
     pub fn gdk_cursor_new_for_display         (display: *mut C_GdkDisplay, cursor_type: enums::CursorType) -> *mut C_GdkCursor;
     pub fn gdk_cursor_get_display             (cursor: *mut C_GdkCursor) -> *mut C_GdkDisplay;
     pub fn gdk_cursor_get_image               (cursor: *mut C_GdkCursor) -> *mut C_GdkPixbuf;
-    //pub fn gdk_cursor_get_surface             (cursor: *mut C_GdkCursor, x_hot: *mut c_double, y_hot: *mut c_double) -> *mut cairo_surface_t;
+    pub fn gdk_cursor_get_surface             (cursor: *mut C_GdkCursor, x_hot: *mut c_double, y_hot: *mut c_double) -> *mut cairo_surface_t;
     pub fn gdk_cursor_get_cursor_type         (cursor: *mut C_GdkCursor) -> enums::CursorType;
 
     //=========================================================================
@@ -523,6 +633,115 @@ extern "C" {
     pub fn gdk_pixbuf_get_rowstride           (pixbuf: *const C_GdkPixbuf) -> c_int;
     pub fn gdk_pixbuf_get_byte_length         (pixbuf: *const C_GdkPixbuf) -> c_ulong;
     pub fn gdk_pixbuf_get_option              (pixbuf: *const C_GdkPixbuf, key: *const c_char) -> *const c_char;
+    pub fn gdk_pixbuf_new                     (colorspace: enums::ColorSpace, has_alpha: Gboolean, bits_per_sample: c_int, width: c_int,
+        height: c_int) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_new_from_data           (data: *const c_uchar, colorspace: enums::ColorSpace, has_alpha: Gboolean,
+        bits_per_sample: c_int, width: c_int, height: c_int, rowstride: c_int,
+        destroy_fn: Option<extern "C" fn(*mut c_uchar, *mut c_void)>, destroy_fn_data: *mut c_void) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_new_from_file           (filename: *const c_char, error: *mut *mut C_GError) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_new_from_file_at_size   (filename: *const c_char, width: c_int, height: c_int,
+        error: *mut *mut C_GError) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_new_from_file_at_scale  (filename: *const c_char, width: c_int, height: c_int, preserve_aspect_ratio: Gboolean,
+        error: *mut *mut C_GError) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_new_from_resource       (resource_path: *const c_char, error: *mut *mut C_GError) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_new_from_resource_at_scale(resource_path: *const c_char, width: c_int, height: c_int,
+        preserve_aspect_ratio: Gboolean, error: *mut *mut C_GError) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_copy                    (pixbuf: *const C_GdkPixbuf) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_get_file_info           (filename: *const c_char, width: *mut c_int, height: *mut c_int) -> *mut C_GdkPixbufFormat;
+    pub fn gdk_pixbuf_savev                   (pixbuf: *mut C_GdkPixbuf, filename: *const c_char, type_: *const c_char,
+        option_keys: *mut *mut c_char, option_values: *mut *mut c_char, error: *mut *mut C_GError) -> Gboolean;
+    pub fn gdk_pixbuf_save_to_bufferv         (pixbuf: *mut C_GdkPixbuf, buffer: *mut *mut c_char, buffer_size: *mut size_t,
+        type_: *const c_char, option_keys: *mut *mut c_char, option_values: *mut *mut c_char, error: *mut *mut C_GError) -> Gboolean;
+    pub fn gdk_pixbuf_new_subpixbuf           (src_pixbuf: *mut C_GdkPixbuf, src_x: c_int, src_y: c_int, width: c_int,
+        height: c_int) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_scale_simple            (src: *const C_GdkPixbuf, dest_width: c_int, dest_height: c_int,
+        interp_type: enums::InterpType) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_scale                   (src: *const C_GdkPixbuf, dest: *mut C_GdkPixbuf, dest_x: c_int, dest_y: c_int,
+        dest_width: c_int, dest_height: c_int, offset_x: c_double, offset_y: c_double, scale_x: c_double, scale_y: c_double,
+        interp_type: enums::InterpType);
+    pub fn gdk_pixbuf_composite_color_simple  (src: *const C_GdkPixbuf, dest_width: c_int, dest_height: c_int,
+        interp_type: enums::InterpType, overall_alpha: c_int, check_size: c_int, color1: u32, color2: u32) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_composite               (src: *const C_GdkPixbuf, dest: *mut C_GdkPixbuf, dest_x: c_int, dest_y: c_int,
+        dest_width: c_int, dest_height: c_int, offset_x: c_double, offset_y: c_double, scale_x: c_double, scale_y: c_double,
+        interp_type: enums::InterpType, overall_alpha: c_int);
+    pub fn gdk_pixbuf_composite_color         (src: *const C_GdkPixbuf, dest: *mut C_GdkPixbuf, dest_x: c_int, dest_y: c_int,
+        dest_width: c_int, dest_height: c_int, offset_x: c_double, offset_y: c_double, scale_x: c_double, scale_y: c_double,
+        interp_type: enums::InterpType, overall_alpha: c_int, check_x: c_int, check_y: c_int, check_size: c_int, color1: u32,
+        color2: u32);
+    pub fn gdk_pixbuf_rotate_simple           (src: *const C_GdkPixbuf, angle: enums::PixbufRotation) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_flip                    (src: *const C_GdkPixbuf, horizontal: Gboolean) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_add_alpha               (pixbuf: *const C_GdkPixbuf, substitute_color: Gboolean, r: c_uchar, g: c_uchar,
+        b: c_uchar) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_copy_area               (src_pixbuf: *const C_GdkPixbuf, src_x: c_int, src_y: c_int, width: c_int, height: c_int,
+        dest_pixbuf: *mut C_GdkPixbuf, dest_x: c_int, dest_y: c_int);
+    pub fn gdk_pixbuf_saturate_and_pixelate   (src: *const C_GdkPixbuf, dest: *mut C_GdkPixbuf, saturation: f32, pixelate: Gboolean);
+    pub fn gdk_pixbuf_fill                    (pixbuf: *mut C_GdkPixbuf, pixel: u32);
+    pub fn gdk_pixbuf_apply_embedded_orientation(src: *mut C_GdkPixbuf) -> *mut C_GdkPixbuf;
+
+    //=========================================================================
+    // GdkPixbufFormat                                                   NOT OK
+    //=========================================================================
+    pub fn gdk_pixbuf_get_formats             () -> *mut C_GSList;
+    pub fn gdk_pixbuf_format_get_name         (format: *mut C_GdkPixbufFormat) -> *mut c_char;
+    pub fn gdk_pixbuf_format_get_description  (format: *mut C_GdkPixbufFormat) -> *mut c_char;
+    pub fn gdk_pixbuf_format_get_mime_types   (format: *mut C_GdkPixbufFormat) -> *mut *mut c_char;
+    pub fn gdk_pixbuf_format_get_extensions   (format: *mut C_GdkPixbufFormat) -> *mut *mut c_char;
+    pub fn gdk_pixbuf_format_is_writable      (format: *mut C_GdkPixbufFormat) -> Gboolean;
+    pub fn gdk_pixbuf_format_is_scalable      (format: *mut C_GdkPixbufFormat) -> Gboolean;
+    pub fn gdk_pixbuf_format_is_disabled      (format: *mut C_GdkPixbufFormat) -> Gboolean;
+    pub fn gdk_pixbuf_format_set_disabled     (format: *mut C_GdkPixbufFormat, disabled: Gboolean);
+    pub fn gdk_pixbuf_format_get_license      (format: *mut C_GdkPixbufFormat) -> *mut c_char;
+
+    //=========================================================================
+    // GdkPixbufLoader                                                   NOT OK
+    //=========================================================================
+    pub fn gdk_pixbuf_error_quark             () -> GQuark;
+    pub fn gdk_pixbuf_loader_new              () -> *mut C_GdkPixbufLoader;
+    pub fn gdk_pixbuf_loader_new_with_type    (image_type: *const c_char, error: *mut *mut C_GError) -> *mut C_GdkPixbufLoader;
+    pub fn gdk_pixbuf_loader_new_with_mime_type(mime_type: *const c_char, error: *mut *mut C_GError) -> *mut C_GdkPixbufLoader;
+    pub fn gdk_pixbuf_loader_get_format       (loader: *mut C_GdkPixbufLoader) -> *mut C_GdkPixbufFormat;
+    pub fn gdk_pixbuf_loader_write            (loader: *mut C_GdkPixbufLoader, buf: *const c_uchar, count: size_t,
+        error: *mut *mut C_GError) -> Gboolean;
+    pub fn gdk_pixbuf_loader_set_size         (loader: *mut C_GdkPixbufLoader, width: c_int, height: c_int);
+    pub fn gdk_pixbuf_loader_get_pixbuf       (loader: *mut C_GdkPixbufLoader) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_loader_get_animation    (loader: *mut C_GdkPixbufLoader) -> *mut C_GdkPixbufAnimation;
+    pub fn gdk_pixbuf_loader_close            (loader: *mut C_GdkPixbufLoader, error: *mut *mut C_GError) -> Gboolean;
+
+    //=========================================================================
+    // GdkPixbufAnimation                                                NOT OK
+    //=========================================================================
+    pub fn gdk_pixbuf_animation_new_from_file (filename: *const c_char, error: *mut *mut C_GError) -> *mut C_GdkPixbufAnimation;
+    pub fn gdk_pixbuf_animation_new_from_resource(resource_path: *const c_char, error: *mut *mut C_GError) -> *mut C_GdkPixbufAnimation;
+    pub fn gdk_pixbuf_animation_get_width     (animation: *mut C_GdkPixbufAnimation) -> c_int;
+    pub fn gdk_pixbuf_animation_get_height    (animation: *mut C_GdkPixbufAnimation) -> c_int;
+    pub fn gdk_pixbuf_animation_get_iter      (animation: *mut C_GdkPixbufAnimation, start_time: *const C_GTimeVal) -> *mut C_GdkPixbufAnimationIter;
+    pub fn gdk_pixbuf_animation_is_static_image(animation: *mut C_GdkPixbufAnimation) -> Gboolean;
+    pub fn gdk_pixbuf_animation_get_static_image(animation: *mut C_GdkPixbufAnimation) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_animation_iter_advance  (iter: *mut C_GdkPixbufAnimationIter, current_time: *const C_GTimeVal) -> Gboolean;
+    pub fn gdk_pixbuf_animation_iter_get_delay_time(iter: *mut C_GdkPixbufAnimationIter) -> c_int;
+    pub fn gdk_pixbuf_animation_iter_on_currently_loading_frame(iter: *mut C_GdkPixbufAnimationIter) -> Gboolean;
+    pub fn gdk_pixbuf_animation_iter_get_pixbuf(iter: *mut C_GdkPixbufAnimationIter) -> *mut C_GdkPixbuf;
+
+    //=========================================================================
+    // Pixbufs                                                           NOT OK
+    //=========================================================================
+    pub fn gdk_pixbuf_get_from_window         (window: *mut C_GdkWindow, src_x: c_int, src_y: c_int, width: c_int,
+        height: c_int) -> *mut C_GdkPixbuf;
+    pub fn gdk_pixbuf_get_from_surface        (surface: *mut cairo_surface_t, src_x: c_int, src_y: c_int, width: c_int,
+        height: c_int) -> *mut C_GdkPixbuf;
+
+    //=========================================================================
+    // Cairo Interaction                                                 NOT OK
+    //=========================================================================
+    pub fn gdk_cairo_get_clip_rectangle       (cr: *mut cairo_t, rect: *mut C_GdkRectangle) -> Gboolean;
+    pub fn gdk_cairo_set_source_rgba          (cr: *mut cairo_t, rgba: *const C_GdkRGBA);
+    pub fn gdk_cairo_set_source_pixbuf        (cr: *mut cairo_t, pixbuf: *const C_GdkPixbuf, pixbuf_x: c_double, pixbuf_y: c_double);
+    pub fn gdk_cairo_set_source_window        (cr: *mut cairo_t, window: *mut C_GdkWindow, x: c_double, y: c_double);
+    pub fn gdk_cairo_rectangle                (cr: *mut cairo_t, rectangle: *const C_GdkRectangle);
+    pub fn gdk_cairo_region                   (cr: *mut cairo_t, region: *const cairo_region_t);
+    pub fn gdk_cairo_region_create_from_surface(surface: *mut cairo_surface_t) -> *mut cairo_region_t;
+    pub fn gdk_cairo_surface_create_from_pixbuf(pixbuf: *const C_GdkPixbuf, scale: c_int,
+        for_window: *mut C_GdkWindow) -> *mut cairo_surface_t;
 
     //=========================================================================
     // GdkRectangle                                                      NOT OK
@@ -541,6 +760,14 @@ extern "C" {
     pub fn gdk_rgba_hash                        (p: *const C_GdkRGBA) -> c_uint;
     pub fn gdk_rgba_to_string                   (rgba: *const C_GdkRGBA) -> *mut c_char;
 
+    //=========================================================================
+    // GdkColor                                                          NOT OK
+    //=========================================================================
+    pub fn gdk_color_parse                      (spec: *const c_char, color: *mut C_GdkColor) -> Gboolean;
+    pub fn gdk_color_equal                      (colora: *const C_GdkColor, colorb: *const C_GdkColor) -> Gboolean;
+    pub fn gdk_color_hash                       (color: *const C_GdkColor) -> c_uint;
+    pub fn gdk_color_to_string                  (color: *const C_GdkColor) -> *mut c_char;
+
     //=========================================================================
     // GdkFrameClock                                                     NOT OK
     //=========================================================================
@@ -603,15 +830,15 @@ extern "C" {
     //pub fn gdk_drag_begin_for_device               (window: *mut C_GdkWindow, device: *mut C_GdkDevice,
     //    targets: *mut GList) -> *mut C_GdkDragContext;
     pub fn gdk_drag_motion                         (context: *mut C_GdkDragContext, dest_window: *mut C_GdkWindow, protocol: enums::DragProtocol,
-        x_root: c_int, y_root: c_int, suggested_action: enums::DragAction, possible_actions: enums::DragAction,
+        x_root: c_int, y_root: c_int, suggested_action: enums::drag_action::DragAction, possible_actions: enums::drag_action::DragAction,
         time_: u32) -> Gboolean;
     pub fn gdk_drop_finish                         (context: *mut C_GdkDragContext, success: Gboolean, time_: u32);
-    pub fn gdk_drag_status                         (context: *mut C_GdkDragContext, action: enums::DragAction, time_: u32);
+    pub fn gdk_drag_status                         (context: *mut C_GdkDragContext, action: enums::drag_action::DragAction, time_: u32);
     pub fn gdk_drag_drop_succeeded                 (context: *mut C_GdkDragContext) -> Gboolean;
     pub fn gdk_window_get_drag_protocol            (window: *mut C_GdkWindow, target: *mut *mut C_GdkWindow) -> enums::DragProtocol;
-    pub fn gdk_drag_context_get_actions            (context: *mut C_GdkDragContext) -> enums::DragAction;
-    pub fn gdk_drag_context_get_suggested_action   (context: *mut C_GdkDragContext) -> enums::DragAction;
-    pub fn gdk_drag_context_get_selected_action    (context: *mut C_GdkDragContext) -> enums::DragAction;
+    pub fn gdk_drag_context_get_actions            (context: *mut C_GdkDragContext) -> enums::drag_action::DragAction;
+    pub fn gdk_drag_context_get_suggested_action   (context: *mut C_GdkDragContext) -> enums::drag_action::DragAction;
+    pub fn gdk_drag_context_get_selected_action    (context: *mut C_GdkDragContext) -> enums::drag_action::DragAction;
     //pub fn gdk_drag_context_list_targets           (context: *mut C_GdkDragContext) -> *mut GList;
     pub fn gdk_drag_context_get_device             (context: *mut C_GdkDragContext) -> *mut C_GdkDevice;
     pub fn gdk_drag_context_set_device             (context: *mut C_GdkDragContext, device: *mut C_GdkDevice);
@@ -632,4 +859,34 @@ extern "C" {
     // Gdk Key Handling                                                  NOT OK
     //=========================================================================
     pub fn gdk_keyval_name                         (keyval:c_uint) -> *mut c_char;
+    pub fn gdk_keyval_from_name                    (keyval_name: *const c_char) -> c_uint;
+    pub fn gdk_keyval_convert_case                 (symbol: c_uint, lower: *mut c_uint, upper: *mut c_uint);
+    pub fn gdk_keyval_to_upper                     (keyval: c_uint) -> c_uint;
+    pub fn gdk_keyval_to_lower                     (keyval: c_uint) -> c_uint;
+    pub fn gdk_keyval_is_upper                     (keyval: c_uint) -> Gboolean;
+    pub fn gdk_keyval_is_lower                     (keyval: c_uint) -> Gboolean;
+    pub fn gdk_keyval_to_unicode                   (keyval: c_uint) -> u32;
+    pub fn gdk_unicode_to_keyval                   (wc: u32) -> c_uint;
+
+    //=========================================================================
+    // GdkKeymap                                                         NOT OK
+    //=========================================================================
+    pub fn gdk_keymap_get_default                  () -> *mut C_GdkKeymap;
+    pub fn gdk_keymap_get_for_display              (display: *mut C_GdkDisplay) -> *mut C_GdkKeymap;
+    pub fn gdk_keymap_lookup_key                   (keymap: *mut C_GdkKeymap, key: *const C_GdkKeymapKey) -> c_uint;
+    pub fn gdk_keymap_translate_keyboard_state     (keymap: *mut C_GdkKeymap, hardware_keycode: c_uint, state: enums::modifier_type::ModifierType,
+        group: c_int, keyval: *mut c_uint, effective_group: *mut c_int, level: *mut c_int,
+        consumed_modifiers: *mut enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gdk_keymap_get_entries_for_keyval       (keymap: *mut C_GdkKeymap, keyval: c_uint, keys: *mut *mut C_GdkKeymapKey,
+        n_keys: *mut c_int) -> Gboolean;
+    pub fn gdk_keymap_get_entries_for_keycode      (keymap: *mut C_GdkKeymap, hardware_keycode: c_uint, keys: *mut *mut C_GdkKeymapKey,
+        keyvals: *mut *mut c_uint, n_entries: *mut c_int) -> Gboolean;
+    pub fn gdk_keymap_have_bidi_layouts            (keymap: *mut C_GdkKeymap) -> Gboolean;
+    pub fn gdk_keymap_get_caps_lock_state          (keymap: *mut C_GdkKeymap) -> Gboolean;
+    pub fn gdk_keymap_get_num_lock_state           (keymap: *mut C_GdkKeymap) -> Gboolean;
+    pub fn gdk_keymap_get_modifier_state           (keymap: *mut C_GdkKeymap) -> c_uint;
+    pub fn gdk_keymap_add_virtual_modifiers        (keymap: *mut C_GdkKeymap, state: *mut enums::modifier_type::ModifierType);
+    pub fn gdk_keymap_map_virtual_modifiers        (keymap: *mut C_GdkKeymap, state: *mut enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gdk_keymap_get_modifier_mask            (keymap: *mut C_GdkKeymap, intent: enums::modifier_intent::ModifierIntent)
+        -> enums::modifier_type::ModifierType;
 }
@@ -0,0 +1,55 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! FFI bindings to the X11 backend of GDK (gdk/gdkx.h)
+
+use libc::{c_int, c_char, c_ulong};
+use glib_ffi::GType;
+use {C_GdkWindow, C_GdkDisplay};
+
+/// An X resource ID, the X11 `Window` type
+pub type XID = c_ulong;
+
+/// The Xlib `Display`
+#[repr(C)]
+pub struct C_XDisplay;
+
+extern "C" {
+
+    //=========================================================================
+    // GdkX11Window                                                      NOT OK
+    //=========================================================================
+    pub fn gdk_x11_window_get_type           () -> GType;
+    pub fn gdk_x11_window_get_xid            (window: *mut C_GdkWindow) -> XID;
+    pub fn gdk_x11_window_foreign_new_for_display(display: *mut C_GdkDisplay, window: XID) -> *mut C_GdkWindow;
+    pub fn gdk_x11_window_lookup_for_display (display: *mut C_GdkDisplay, window: XID) -> *mut C_GdkWindow;
+    pub fn gdk_x11_window_set_utf8_property  (window: *mut C_GdkWindow, name: *const c_char, value: *const c_char);
+    pub fn gdk_x11_window_set_user_time      (window: *mut C_GdkWindow, timestamp: u32);
+    pub fn gdk_x11_get_server_time           (window: *mut C_GdkWindow) -> u32;
+
+    //=========================================================================
+    // GdkX11Display                                                     NOT OK
+    //=========================================================================
+    pub fn gdk_x11_display_get_type          () -> GType;
+    pub fn gdk_x11_display_get_xdisplay      (display: *mut C_GdkDisplay) -> *mut C_XDisplay;
+    pub fn gdk_x11_display_get_user_time     (display: *mut C_GdkDisplay) -> u32;
+    pub fn gdk_x11_display_get_startup_notification_id(display: *mut C_GdkDisplay) -> *const c_char;
+    pub fn gdk_x11_display_set_startup_notification_id(display: *mut C_GdkDisplay, startup_id: *const c_char);
+    pub fn gdk_x11_display_error_trap_push   (display: *mut C_GdkDisplay);
+    pub fn gdk_x11_display_error_trap_pop    (display: *mut C_GdkDisplay) -> c_int;
+    pub fn gdk_x11_display_error_trap_pop_ignored(display: *mut C_GdkDisplay);
+    pub fn gdk_x11_display_grab              (display: *mut C_GdkDisplay);
+    pub fn gdk_x11_display_ungrab            (display: *mut C_GdkDisplay);
+}
@@ -49,6 +49,13 @@ pub struct C_GError {
     pub message: *mut c_char
 }
 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct C_GTimeVal {
+    pub tv_sec : c_long,
+    pub tv_usec: c_long
+}
+
 #[repr(C)]
 pub struct C_GPermission;
 
@@ -58,6 +65,8 @@ pub struct C_GObject;
 #[repr(C)]
 pub struct C_GValue;
 
+pub const G_PRIORITY_DEFAULT: c_int = 0;
+
 //=========================================================================
 // GType constants
 //=========================================================================
@@ -89,6 +98,13 @@ pub const G_TYPE_VARIANT: GType = 21 << G_TYPE_FUNDAMENTAL_SHIFT;
 extern "C" {
 
     pub fn g_free                          (ptr: gpointer);
+    pub fn g_strfreev                      (str_array: *mut *mut c_char);
+    //=========================================================================
+    // Main loop sources
+    //=========================================================================
+    pub fn g_timeout_add_full              (priority: c_int, interval: c_uint, function: extern "C" fn(gpointer) -> Gboolean,
+        data: gpointer, notify: extern "C" fn(gpointer)) -> c_uint;
+    pub fn g_source_remove                 (tag: c_uint) -> Gboolean;
     //=========================================================================
     // GSList
     //=========================================================================
@@ -189,6 +205,8 @@ extern "C" {
     //=========================================================================
     pub fn g_object_ref(object: *mut C_GObject) -> *mut C_GObject;
     pub fn g_object_unref(object: *mut C_GObject);
+    pub fn g_object_weak_ref(object: *mut C_GObject, notify: extern "C" fn(gpointer, *mut C_GObject), data: gpointer);
+    pub fn g_type_check_instance_is_a(instance: *mut C_GObject, iface_type: GType) -> Gboolean;
 
     pub fn glue_signal_connect(g_object: *mut C_GObject,
                                signal: *const c_char,
@@ -15,7 +15,7 @@
 
 use ffi::{self, GQuark};
 use glib_container::GlibContainer;
-use translate::ToGlibPtr;
+use translate::{FromGlibPtr, ToGlibPtr};
 
 pub struct Error {
     pointer: *mut ffi::C_GError
@@ -41,6 +41,18 @@ impl Error {
         }
     }
 
+    pub fn get_domain(&self) -> GQuark {
+        unsafe { (*self.pointer).domain }
+    }
+
+    pub fn get_code(&self) -> i32 {
+        unsafe { (*self.pointer).code }
+    }
+
+    pub fn get_message(&self) -> Option<String> {
+        unsafe { FromGlibPtr::borrow((*self.pointer).message as *const _) }
+    }
+
     pub fn matches(&self, domain: GQuark, code: i32) -> bool {
         match unsafe { ffi::g_error_matches(self.pointer, domain, code) } {
             ffi::GFALSE => false,
@@ -947,18 +947,22 @@ pub enum ScrollablePolicy {
     Natural
 }
 
-/// Flags used when creating a GAppInfo.
-#[repr(C)]
-#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
-pub enum AppInfoCreateFlags {
-    /// No flags.
-    None,
-    /// Application opens in a terminal window.
-    NeedsTerminal,
-    /// Application supports URI arguments.
-    SupportsUris,
-    /// Application supports startup notification.
-    SupportsStartupNotification
+pub mod app_info_create_flags {
+    #![allow(non_upper_case_globals)]
+
+    bitflags! {
+        #[derive(Debug)]
+        #[repr(C)]
+        /// Flags used when creating a GAppInfo, `AppInfoCreateFlags::empty()` for no flags.
+        flags AppInfoCreateFlags: u32 {
+            /// Application opens in a terminal window.
+            const NeedsTerminal               = 1 << 0,
+            /// Application supports URI arguments.
+            const SupportsUris                = 1 << 1,
+            /// Application supports startup notification.
+            const SupportsStartupNotification = 1 << 2
+        }
+    }
 }
 
 /// Specifies a preference for height-for-width or width-for-height geometry management.
@@ -1375,23 +1379,50 @@ pub enum ToolPaletteDragTargets {
     Groups
 }
 
-/// The GtkDestDefaults enumeration specifies the various types of action that will be taken on behalf of the user for a drag destination site.
-#[repr(C)]
-#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
-pub enum DestDefaults {
-    /// If set for a widget, GTK+, during a drag over this widget will check if the drag matches this widget’s list of possible targets
-    /// and actions. GTK+ will then call gdk_drag_status() as appropriate.
-    Motion,
-    /// If set for a widget, GTK+ will draw a highlight on this widget as long as a drag is over this widget and the widget drag format
-    /// and action are acceptable.
-    Highlight,
-    /// If set for a widget, when a drop occurs, GTK+ will will check if the drag matches this widget’s list of possible targets and
-    /// actions. If so, GTK+ will call gtk_drag_get_data() on behalf of the widget. Whether or not the drop is successful, GTK+ will
-    /// call gtk_drag_finish(). If the action was a move, then if the drag was successful, then TRUE will be passed for the delete
-    /// parameter to gtk_drag_finish().
-    Drop,
-    /// If set, specifies that all default actions should be taken.
-    All
+pub mod dest_defaults {
+    #![allow(non_upper_case_globals)]
+
+    bitflags! {
+        #[derive(Debug)]
+        #[repr(C)]
+        /// The GtkDestDefaults specify the various types of action that will be taken on behalf of the user for a drag destination site.
+        flags DestDefaults: u32 {
+            /// If set for a widget, GTK+, during a drag over this widget will check if the drag matches this widget’s list of possible targets
+            /// and actions. GTK+ will then call gdk_drag_status() as appropriate.
+            const Motion    = 1 << 0,
+            /// If set for a widget, GTK+ will draw a highlight on this widget as long as a drag is over this widget and the widget drag format
+            /// and action are acceptable.
+            const Highlight = 1 << 1,
+            /// If set for a widget, when a drop occurs, GTK+ will will check if the drag matches this widget’s list of possible targets and
+            /// actions. If so, GTK+ will call gtk_drag_get_data() on behalf of the widget. Whether or not the drop is successful, GTK+ will
+            /// call gtk_drag_finish(). If the action was a move, then if the drag was successful, then TRUE will be passed for the delete
+            /// parameter to gtk_drag_finish().
+            const Drop      = 1 << 2,
+            /// If set, specifies that all default actions should be taken.
+            const All       = Motion.bits | Highlight.bits | Drop.bits
+        }
+    }
+}
+
+pub mod target_flags {
+    #![allow(non_upper_case_globals)]
+
+    bitflags! {
+        #[derive(Debug)]
+        #[repr(C)]
+        /// The GtkTargetFlags are used to specify constraints on a GtkTargetEntry, no flag means
+        /// no constraint.
+        flags TargetFlags: u32 {
+            /// If this is set, the target will only be selected for drags within a single application.
+            const SameApp     = 1 << 0,
+            /// If this is set, the target will only be selected for drags within a single widget.
+            const SameWidget  = 1 << 1,
+            /// If this is set, the target will not be selected for drags within a single application.
+            const OtherApp    = 1 << 2,
+            /// If this is set, the target will not be selected for drags within a single widget.
+            const OtherWidget = 1 << 3
+        }
+    }
 }
 
 /// The mode of the size group determines the directions in which the size group affects the requested sizes of its component widgets.
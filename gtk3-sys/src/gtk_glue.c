@@ -588,6 +588,10 @@ GtkTextMark* cast_GtkTextMark(GObject* obj) {
     return GTK_TEXT_MARK(obj);
 }
 
+GtkClipboard* cast_GtkClipboard(GObject* obj) {
+    return GTK_CLIPBOARD(obj);
+}
+
 GtkFileChooserWidget* cast_GtkFileChooserWidget(GtkWidget* widget) {
     return GTK_FILE_CHOOSER_WIDGET(widget);
 }
@@ -604,12 +608,20 @@ GtkFontChooserWidget* cast_GtkFontChooserWidget(GtkWidget* widget) {
 GtkSocket* cast_GtkSocket(GtkWidget* widget) {
     return GTK_SOCKET(widget);
 }
+
+GtkPlug* cast_GtkPlug(GtkWidget* widget) {
+    return GTK_PLUG(widget);
+}
 #endif
 
 GtkEventBox* cast_GtkEventBox(GtkWidget* widget) {
     return GTK_EVENT_BOX(widget);
 }
 
+GtkOffscreenWindow* cast_GtkOffscreenWindow(GtkWidget* widget) {
+    return GTK_OFFSCREEN_WINDOW(widget);
+}
+
 /* MAC OS dylib
 gcc -I/usr/local/include/gtk-3.0 -I/usr/local/include/glib-2.0 -I/usr/local/include/gobject-introspection-1.0 -I/usr/local/Cellar/glib/2.38.1/lib/glib-2.0/include/ -I/usr/local/Cellar/pango/1.36.0/include/pango-1.0/ -I/usr/local/Cellar/cairo/1.12.16/include/cairo/ -I/usr/local/Cellar/gdk-pixbuf/2.30.0/include/gdk-pixbuf-2.0/ -I/usr/local/Cellar/atk/2.10.0/include/atk-1.0/ -lglib-2.0 -lgtk-3.0 -lgobject-2.0 -dynamiclib -o libgtk_glue.dylib -dy gtk_glue.c
 */
@@ -17,8 +17,10 @@
 #![allow(dead_code)]
 
 extern crate libc;
+#[macro_use] extern crate bitflags;
 extern crate glib_sys as glib_ffi;
 extern crate gdk3_sys as gdk_ffi;
+extern crate cairo_sys as cairo_ffi;
 
 pub mod enums;
 
@@ -27,6 +29,7 @@ use libc::{c_int, c_char, c_float, c_uint, c_double, c_long, c_short, c_void, c_
 pub use glib_ffi::{
     Gboolean, GFALSE, GTRUE, gpointer, GType, C_GObject, C_GPermission,
     C_GList, C_GSList, C_GError, C_GValue};
+use cairo_ffi::{cairo_t, cairo_surface_t, cairo_region_t};
 
 //pub type C_GtkAllocation = C_GdkRectangle;
 
@@ -165,6 +168,8 @@ pub struct C_GAppLaunchContext;
 #[repr(C)]
 pub struct C_GAppInfo;
 #[repr(C)]
+pub struct C_GFile;
+#[repr(C)]
 pub struct C_GtkFontChooser;
 #[repr(C)]
 pub struct C_GtkFontChooserDialog;
@@ -332,6 +337,22 @@ pub struct C_GtkIconView;
 #[repr(C)]
 pub struct C_GtkTreeSelection;
 
+#[repr(C)]
+pub struct C_GtkClipboard;
+
+#[repr(C)]
+pub struct C_GtkSelectionData;
+
+#[repr(C)]
+pub struct C_GtkTargetList;
+
+#[repr(C)]
+pub struct C_GtkTargetEntry {
+    pub target: *mut c_char,
+    pub flags:  enums::target_flags::TargetFlags,
+    pub info:   c_uint
+}
+
 #[repr(C)]
 pub struct C_GtkRecentChooserWidget;
 
@@ -365,8 +386,13 @@ pub struct C_GtkMovementStep;
 #[repr(C)]
 pub struct C_GtkSocket;
 
+#[repr(C)]
+pub struct C_GtkPlug;
+
 #[repr(C)]
 pub struct C_GtkEventBox;
+#[repr(C)]
+pub struct C_GtkOffscreenWindow;
 
 extern "C" {
 
@@ -380,6 +406,30 @@ extern "C" {
     pub fn gtk_main_iteration                  () -> Gboolean;
     pub fn gtk_main_iteration_do               (blocking: Gboolean) -> Gboolean;
     pub fn gtk_events_pending                  () -> Gboolean;
+    pub fn gtk_get_current_event_time          () -> u32;
+    pub fn gtk_get_current_event_device        () -> *mut gdk_ffi::C_GdkDevice;
+    pub fn gtk_grab_add                        (widget: *mut C_GtkWidget);
+    pub fn gtk_grab_get_current                () -> *mut C_GtkWidget;
+    pub fn gtk_grab_remove                     (widget: *mut C_GtkWidget);
+    pub fn gtk_device_grab_add                 (widget: *mut C_GtkWidget, device: *mut gdk_ffi::C_GdkDevice, block_others: Gboolean);
+    pub fn gtk_device_grab_remove              (widget: *mut C_GtkWidget, device: *mut gdk_ffi::C_GdkDevice);
+
+    //=========================================================================
+    // Gtk HSV conversions
+    //=========================================================================
+    pub fn gtk_hsv_to_rgb                      (h: c_double, s: c_double, v: c_double, r: *mut c_double, g: *mut c_double, b: *mut c_double);
+    pub fn gtk_rgb_to_hsv                      (r: c_double, g: c_double, b: c_double, h: *mut c_double, s: *mut c_double, v: *mut c_double);
+
+    //=========================================================================
+    // Gtk Accelerators                                                  NOT OK
+    //=========================================================================
+    pub fn gtk_accelerator_valid               (keyval: c_uint, modifiers: gdk_ffi::enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gtk_accelerator_parse               (accelerator: *const c_char, accelerator_key: *mut c_uint,
+        accelerator_mods: *mut gdk_ffi::enums::modifier_type::ModifierType);
+    pub fn gtk_accelerator_name                (accelerator_key: c_uint, accelerator_mods: gdk_ffi::enums::modifier_type::ModifierType) -> *mut c_char;
+    pub fn gtk_accelerator_get_label           (accelerator_key: c_uint, accelerator_mods: gdk_ffi::enums::modifier_type::ModifierType) -> *mut c_char;
+    pub fn gtk_accelerator_set_default_mod_mask(default_mod_mask: gdk_ffi::enums::modifier_type::ModifierType);
+    pub fn gtk_accelerator_get_default_mod_mask() -> gdk_ffi::enums::modifier_type::ModifierType;
 
     //=========================================================================
     // GtkWindow
@@ -391,6 +441,10 @@ extern "C" {
     pub fn gtk_window_set_position             (window: *mut C_GtkWindow, position: enums::WindowPosition) -> ();
     pub fn gtk_window_set_decorated            (window: *mut C_GtkWindow, setting: Gboolean) -> ();
     pub fn gtk_window_set_titlebar             (window: *mut C_GtkWindow, titlebar: *mut C_GtkWidget) -> ();
+    pub fn gtk_window_set_geometry_hints       (window: *mut C_GtkWindow, geometry_widget: *mut C_GtkWidget,
+        geometry: *const gdk_ffi::C_GdkGeometry, geom_mask: gdk_ffi::enums::window_hints::WindowHints);
+    pub fn gtk_window_set_gravity              (window: *mut C_GtkWindow, gravity: gdk_ffi::enums::Gravity);
+    pub fn gtk_window_get_gravity              (window: *mut C_GtkWindow) -> gdk_ffi::enums::Gravity;
 
     // pub fn gtk_window_set_role(window: *const const C_GtkWindow, role: *const c_char) -> ();
     // pub fn gtk_window_set_startup_id(window: *const const C_GtkWindow, startup_id: *const c_char) -> ();
@@ -436,13 +490,15 @@ extern "C" {
     pub fn gtk_widget_unmap                    (widget: *mut C_GtkWidget);
     pub fn gtk_widget_realize                  (widget: *mut C_GtkWidget);
     pub fn gtk_widget_unrealize                (widget: *mut C_GtkWidget);
-    //pub fn gtk_widget_draw                     (widget: *mut C_GtkWidget, cr: *mut cairo_t);
+    pub fn gtk_widget_draw                     (widget: *mut C_GtkWidget, cr: *mut cairo_t);
     pub fn gtk_widget_queue_draw               (widget: *mut C_GtkWidget);
     pub fn gtk_widget_queue_resize             (widget: *mut C_GtkWidget);
     pub fn gtk_widget_queue_resize_no_redraw   (widget: *mut C_GtkWidget);
-    //pub fn gtk_widget_get_frame_clock          (widget: *mut C_GtkWidget) -> *mut C_GdkFrameClock;
-    //pub fn gtk_widget_add_tick_callback        (widget: *mut C_GtkWidget, callback: C_GtkTickCallback, user_data: gpointer, notify: C_GDestroyNotify) -> c_uint;
-    //pub fn gtk_widget_remove_tick_callback     (widget: *mut C_GtkWidget, id: c_uint);
+    pub fn gtk_widget_get_frame_clock          (widget: *mut C_GtkWidget) -> *mut gdk_ffi::C_GdkFrameClock;
+    pub fn gtk_widget_add_tick_callback        (widget: *mut C_GtkWidget,
+        callback: extern "C" fn(*mut C_GtkWidget, *mut gdk_ffi::C_GdkFrameClock, gpointer) -> Gboolean, user_data: gpointer,
+        notify: extern "C" fn(gpointer)) -> c_uint;
+    pub fn gtk_widget_remove_tick_callback     (widget: *mut C_GtkWidget, id: c_uint);
     pub fn gtk_widget_get_scale_factor         (widget: *mut C_GtkWidget) -> c_int;
     //pub fn gtk_widget_size_request             (widget: *mut C_GtkWidget, requisition: *mut C_GtkRequisition);
     //pub fn gtk_widget_get_child_requisition    (widget: *mut C_GtkWidget, requisition: *mut C_GtkRequisition);
@@ -454,7 +510,7 @@ extern "C" {
     //pub fn gtk_widget_set_accel_path           (widget: *mut C_GtkWidget, accel_path: *const c_char, accel_group: *mut C_GtkAccelGroup);
     //pub fn gtk_widget_list_accel_closures      (widget: *mut C_GtkWidget) -> *mut glib::GList;
     //pub fn gtk_widget_can_activate_accel       (widget: *mut C_GtkWidget, signal_id: c_uint) -> Gboolean;
-    //pub fn gtk_widget_event                    (widget: *mut C_GtkWidget, event: *mut gdk::Event) -> Gboolean;
+    pub fn gtk_widget_event                    (widget: *mut C_GtkWidget, event: *mut gdk_ffi::C_GdkEvent) -> Gboolean;
     pub fn gtk_widget_activate                 (widget: *mut C_GtkWidget) -> Gboolean;
     pub fn gtk_widget_reparent                 (widget: *mut C_GtkWidget, new_parent: *mut C_GtkWidget);
     //pub fn gtk_widget_intersect                (widget: *mut C_GtkWidget, area: *const C_GdkRectangle, intersection: *mut C_GdkRectangle) -> Gboolean;
@@ -467,9 +523,9 @@ extern "C" {
     pub fn gtk_widget_set_parent               (widget: *mut C_GtkWidget, parent: *mut C_GtkWidget);
     //pub fn gtk_widget_set_parent_window        (widget: *mut C_GtkWindow, parent_window: *mut gdk::Window);
     //pub fn gtk_widget_get_parent_window        (widget: *mut C_GtkWindow) -> *mut gdk::Window;
-    //pub fn gtk_widget_set_events               (widget: *mut C_GtkWidget, events: c_int);
-    //pub fn gtk_widget_get_events               (widget: *mut C_GtkWidget) -> c_int;
-    //pub fn gtk_widget_add_events               (widget: *mut C_GtkWidget, events: c_int);
+    pub fn gtk_widget_set_events               (widget: *mut C_GtkWidget, events: gdk_ffi::enums::event_mask::EventMask);
+    pub fn gtk_widget_get_events               (widget: *mut C_GtkWidget) -> gdk_ffi::enums::event_mask::EventMask;
+    pub fn gtk_widget_add_events               (widget: *mut C_GtkWidget, events: gdk_ffi::enums::event_mask::EventMask);
     //pub fn gtk_widget_set_device_events        (widget: *mut C_GtkWidget, device: *mut C_GdkDevice, events: gdk::EventMask);
     //pub fn gtk_widget_get_device_events        (widget: *mut C_GtkWidget, device: *mut C_GdkDevice) -> gdk::EventMask;
     //pub fn gtk_widget_add_device_events        (widget: *mut C_GtkWidget, device: *mut C_GdkDevice, events: gdk::EventMask);
@@ -487,13 +543,17 @@ extern "C" {
     pub fn gtk_widget_get_direction            (widget: *mut C_GtkWidget) -> enums::TextDirection;
     pub fn gtk_widget_set_default_direction    (dir: enums::TextDirection);
     pub fn gtk_widget_get_default_direction    () -> enums::TextDirection;
-    //pub fn gtk_widget_shape_combine_region     (widget: *mut C_GtkWidget, region: *mut cairo_region_t);
-    //pub fn gtk_widget_input_shape_combine_region(widget: *mut C_GtkWidget, region: *mut cairo_region_t);
+    pub fn gtk_widget_shape_combine_region     (widget: *mut C_GtkWidget, region: *mut cairo_region_t);
+    pub fn gtk_widget_input_shape_combine_region(widget: *mut C_GtkWidget, region: *mut cairo_region_t);
     pub fn gtk_widget_override_background_color(widget: *mut C_GtkWidget, state: enums::StateFlags, color: *const gdk_ffi::C_GdkRGBA);
     pub fn gtk_widget_override_color           (widget: *mut C_GtkWidget, state: enums::StateFlags, color: *const gdk_ffi::C_GdkRGBA);
     //pub fn gtk_widget_override_font            (widget: *mut C_GtkWidget, font_desc: *const PangoFontDescription);
     pub fn gtk_widget_override_symbolic_color  (widget: *mut C_GtkWidget, name: *const c_char, color: *const gdk_ffi::C_GdkRGBA);
     pub fn gtk_widget_override_cursor          (widget: *mut C_GtkWidget, cursor: *const gdk_ffi::C_GdkRGBA, secondary_cursor: *const gdk_ffi::C_GdkRGBA);
+    pub fn gtk_widget_modify_fg                (widget: *mut C_GtkWidget, state: enums::StateType, color: *const gdk_ffi::C_GdkColor);
+    pub fn gtk_widget_modify_bg                (widget: *mut C_GtkWidget, state: enums::StateType, color: *const gdk_ffi::C_GdkColor);
+    pub fn gtk_widget_modify_text              (widget: *mut C_GtkWidget, state: enums::StateType, color: *const gdk_ffi::C_GdkColor);
+    pub fn gtk_widget_modify_base              (widget: *mut C_GtkWidget, state: enums::StateType, color: *const gdk_ffi::C_GdkColor);
     //pub fn gtk_widget_create_pango_context     (widget: *mut C_GtkWidget) -> *mut PangoContext;
     //pub fn gtk_widget_get_pango_context        (widget: *mut C_GtkWidget) -> *mut PangoContext;
     //pub fn gtk_widget_create_pango_layout      (widget: *mut C_GtkWidget, name: *const c_char) -> *mut PangoLayout;
@@ -508,8 +568,8 @@ extern "C" {
     //pub fn gtk_widget_class_find_style_property(klass: *mut C_GtkWidgetClass, property_name: *const c_char) -> *mut C_GParamSpec;
     //pub fn gtk_widget_class_list_style_properties(klass: *mut C_GtkWidgetClass, n_properties: c_uint) -> *mut *mut C_GParamSpec;
     //pub fn gtk_widget_region_intersect         (widget: *mut C_GtkWidget, region: *const cairo_region_t) -> *mut cairo_region_t;
-    //pub fn gtk_widget_send_expose              (widget: *mut C_GtkWidget, event: *mut gdk::Event) -> c_int;
-    //pub fn gtk_widget_send_focus_change        (widget: *mut C_GtkWidget, event: *mut gdk::Event) -> Gboolean;
+    pub fn gtk_widget_send_expose              (widget: *mut C_GtkWidget, event: *mut gdk_ffi::C_GdkEvent) -> c_int;
+    pub fn gtk_widget_send_focus_change        (widget: *mut C_GtkWidget, event: *mut gdk_ffi::C_GdkEvent) -> Gboolean;
     //pub fn gtk_widget_style_get                (widget: *mut C_GtkWidget, first_property_name: *const c_char, ...);
     //pub fn gtk_widget_style_get_property       (widget: *mut C_GtkWidget, property_name: *const c_char, value: *mut GValue);
     //pub fn gtk_widget_style_get_valist         (widget: *mut C_GtkWidget, first_property_name: *const c_char, va_args: va_list);
@@ -522,7 +582,7 @@ extern "C" {
     pub fn gtk_widget_get_child_visible        (widget: *mut C_GtkWidget) -> Gboolean;
     pub fn gtk_widget_get_parent               (widget: *mut C_GtkWidget) -> *mut C_GtkWidget;
     //pub fn gtk_widget_get_settings             (widget: *mut C_GtkWidget) -> *mut C_GtkSettings;
-    //pub fn gtk_widget_get_clipboard            (widget: *mut C_GtkWidget, selection: gdk::Atom) -> *mut C_GtkClipboard;
+    pub fn gtk_widget_get_clipboard            (widget: *mut C_GtkWidget, selection: gdk_ffi::C_GdkAtom) -> *mut C_GtkClipboard;
     //pub fn gtk_widget_get_display              (widget: *mut C_GtkWidget) -> *mut gdk::Display;
     //pub fn gtk_widget_get_screen               (widget: *mut C_GtkWidget) -> *mut gdk::Screen;
     pub fn gtk_widget_has_screen               (widget: *mut C_GtkWidget) -> Gboolean;
@@ -588,7 +648,7 @@ extern "C" {
     pub fn gtk_widget_get_realized             (widget: *mut C_GtkWidget) -> Gboolean;
     pub fn gtk_widget_set_mapped               (widget: *mut C_GtkWidget, mapped: Gboolean);
     pub fn gtk_widget_get_mapped               (widget: *mut C_GtkWidget) -> Gboolean;
-    //pub fn gtk_widget_device_is_shadowed       (widget: *mut C_GtkWidget, device: *mut C_GdkDevice) -> Gboolean;
+    pub fn gtk_widget_device_is_shadowed       (widget: *mut C_GtkWidget, device: *mut gdk_ffi::C_GdkDevice) -> Gboolean;
     pub fn gtk_widget_get_modifier_mask        (widget: *mut C_GtkWidget, intent: gdk_ffi::enums::modifier_intent::ModifierIntent) -> gdk_ffi::enums::modifier_type::ModifierType;
     //pub fn gtk_widget_insert_action_group      (widget: *mut C_GtkWidget, name: *mut c_char, group: *mut GActionGroup);
     pub fn gtk_widget_get_opacity              (widget: *mut C_GtkWidget) -> c_double;
@@ -829,6 +889,132 @@ extern "C" {
     pub fn gtk_tree_path_is_ancestor           (path: *mut C_GtkTreePath, descendant: *mut C_GtkTreePath) -> Gboolean;
     pub fn gtk_tree_path_is_descendant         (path: *mut C_GtkTreePath, ancestor: *mut C_GtkTreePath) -> Gboolean;
 
+    //=========================================================================
+    // GtkClipboard                                                      NOT OK
+    //=========================================================================
+    pub fn gtk_clipboard_get                   (selection: gdk_ffi::C_GdkAtom) -> *mut C_GtkClipboard;
+    pub fn gtk_clipboard_get_for_display       (display: *mut gdk_ffi::C_GdkDisplay, selection: gdk_ffi::C_GdkAtom) -> *mut C_GtkClipboard;
+    pub fn gtk_clipboard_get_display           (clipboard: *mut C_GtkClipboard) -> *mut gdk_ffi::C_GdkDisplay;
+    pub fn gtk_clipboard_set_with_data         (clipboard: *mut C_GtkClipboard, targets: *const C_GtkTargetEntry, n_targets: c_uint,
+        get_func: extern "C" fn(*mut C_GtkClipboard, *mut C_GtkSelectionData, c_uint, gpointer),
+        clear_func: extern "C" fn(*mut C_GtkClipboard, gpointer), user_data: gpointer) -> Gboolean;
+    pub fn gtk_clipboard_clear                 (clipboard: *mut C_GtkClipboard);
+    pub fn gtk_clipboard_set_text              (clipboard: *mut C_GtkClipboard, text: *const c_char, len: c_int);
+    pub fn gtk_clipboard_set_image             (clipboard: *mut C_GtkClipboard, pixbuf: *mut gdk_ffi::C_GdkPixbuf);
+    pub fn gtk_clipboard_request_contents      (clipboard: *mut C_GtkClipboard, target: gdk_ffi::C_GdkAtom,
+        callback: extern "C" fn(*mut C_GtkClipboard, *mut C_GtkSelectionData, gpointer), user_data: gpointer);
+    pub fn gtk_clipboard_request_text          (clipboard: *mut C_GtkClipboard,
+        callback: extern "C" fn(*mut C_GtkClipboard, *const c_char, gpointer), user_data: gpointer);
+    pub fn gtk_clipboard_request_image         (clipboard: *mut C_GtkClipboard,
+        callback: extern "C" fn(*mut C_GtkClipboard, *mut gdk_ffi::C_GdkPixbuf, gpointer), user_data: gpointer);
+    pub fn gtk_clipboard_request_targets       (clipboard: *mut C_GtkClipboard,
+        callback: extern "C" fn(*mut C_GtkClipboard, *mut gdk_ffi::C_GdkAtom, c_int, gpointer), user_data: gpointer);
+    pub fn gtk_clipboard_request_uris          (clipboard: *mut C_GtkClipboard,
+        callback: extern "C" fn(*mut C_GtkClipboard, *mut *mut c_char, gpointer), user_data: gpointer);
+    pub fn gtk_clipboard_wait_for_contents     (clipboard: *mut C_GtkClipboard, target: gdk_ffi::C_GdkAtom) -> *mut C_GtkSelectionData;
+    pub fn gtk_clipboard_wait_for_text         (clipboard: *mut C_GtkClipboard) -> *mut c_char;
+    pub fn gtk_clipboard_wait_for_image        (clipboard: *mut C_GtkClipboard) -> *mut gdk_ffi::C_GdkPixbuf;
+    pub fn gtk_clipboard_wait_for_uris         (clipboard: *mut C_GtkClipboard) -> *mut *mut c_char;
+    pub fn gtk_clipboard_wait_for_targets      (clipboard: *mut C_GtkClipboard, targets: *mut *mut gdk_ffi::C_GdkAtom,
+        n_targets: *mut c_int) -> Gboolean;
+    pub fn gtk_clipboard_wait_is_text_available(clipboard: *mut C_GtkClipboard) -> Gboolean;
+    pub fn gtk_clipboard_wait_is_image_available(clipboard: *mut C_GtkClipboard) -> Gboolean;
+    pub fn gtk_clipboard_wait_is_uris_available(clipboard: *mut C_GtkClipboard) -> Gboolean;
+    pub fn gtk_clipboard_wait_is_target_available(clipboard: *mut C_GtkClipboard, target: gdk_ffi::C_GdkAtom) -> Gboolean;
+    pub fn gtk_clipboard_set_can_store         (clipboard: *mut C_GtkClipboard, targets: *const C_GtkTargetEntry, n_targets: c_int);
+    pub fn gtk_clipboard_store                 (clipboard: *mut C_GtkClipboard);
+
+    //=========================================================================
+    // GtkTargetList                                                     NOT OK
+    //=========================================================================
+    pub fn gtk_target_list_new                 (targets: *const C_GtkTargetEntry, ntargets: c_uint) -> *mut C_GtkTargetList;
+    pub fn gtk_target_list_ref                 (list: *mut C_GtkTargetList) -> *mut C_GtkTargetList;
+    pub fn gtk_target_list_unref               (list: *mut C_GtkTargetList);
+    pub fn gtk_target_list_add                 (list: *mut C_GtkTargetList, target: gdk_ffi::C_GdkAtom, flags: c_uint, info: c_uint);
+    pub fn gtk_target_list_add_table           (list: *mut C_GtkTargetList, targets: *const C_GtkTargetEntry, ntargets: c_uint);
+    pub fn gtk_target_list_add_text_targets    (list: *mut C_GtkTargetList, info: c_uint);
+    pub fn gtk_target_list_add_image_targets   (list: *mut C_GtkTargetList, info: c_uint, writable: Gboolean);
+    pub fn gtk_target_list_add_uri_targets     (list: *mut C_GtkTargetList, info: c_uint);
+    pub fn gtk_target_list_remove              (list: *mut C_GtkTargetList, target: gdk_ffi::C_GdkAtom);
+    pub fn gtk_target_list_find                (list: *mut C_GtkTargetList, target: gdk_ffi::C_GdkAtom, info: *mut c_uint) -> Gboolean;
+
+    //=========================================================================
+    // Drag and Drop                                                     NOT OK
+    //=========================================================================
+    pub fn gtk_drag_dest_set                   (widget: *mut C_GtkWidget, flags: enums::dest_defaults::DestDefaults, targets: *const C_GtkTargetEntry,
+        n_targets: c_int, actions: gdk_ffi::enums::drag_action::DragAction);
+    pub fn gtk_drag_dest_unset                 (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_dest_find_target           (widget: *mut C_GtkWidget, context: *mut gdk_ffi::C_GdkDragContext,
+        target_list: *mut C_GtkTargetList) -> gdk_ffi::C_GdkAtom;
+    pub fn gtk_drag_dest_get_target_list       (widget: *mut C_GtkWidget) -> *mut C_GtkTargetList;
+    pub fn gtk_drag_dest_set_target_list       (widget: *mut C_GtkWidget, target_list: *mut C_GtkTargetList);
+    pub fn gtk_drag_dest_add_text_targets      (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_dest_add_image_targets     (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_dest_add_uri_targets       (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_finish                     (context: *mut gdk_ffi::C_GdkDragContext, success: Gboolean, del: Gboolean, time_: u32);
+    pub fn gtk_drag_get_data                   (widget: *mut C_GtkWidget, context: *mut gdk_ffi::C_GdkDragContext, target: gdk_ffi::C_GdkAtom,
+        time_: u32);
+    pub fn gtk_drag_get_source_widget          (context: *mut gdk_ffi::C_GdkDragContext) -> *mut C_GtkWidget;
+    pub fn gtk_drag_highlight                  (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_unhighlight                (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_set_icon_default           (context: *mut gdk_ffi::C_GdkDragContext);
+    pub fn gtk_drag_set_icon_pixbuf            (context: *mut gdk_ffi::C_GdkDragContext, pixbuf: *mut gdk_ffi::C_GdkPixbuf, hot_x: c_int,
+        hot_y: c_int);
+    pub fn gtk_drag_set_icon_name              (context: *mut gdk_ffi::C_GdkDragContext, icon_name: *const c_char, hot_x: c_int, hot_y: c_int);
+    pub fn gtk_drag_check_threshold            (widget: *mut C_GtkWidget, start_x: c_int, start_y: c_int, current_x: c_int,
+        current_y: c_int) -> Gboolean;
+    pub fn gtk_drag_source_set                 (widget: *mut C_GtkWidget, start_button_mask: gdk_ffi::enums::modifier_type::ModifierType,
+        targets: *const C_GtkTargetEntry, n_targets: c_int, actions: gdk_ffi::enums::drag_action::DragAction);
+    pub fn gtk_drag_source_unset               (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_source_set_target_list     (widget: *mut C_GtkWidget, target_list: *mut C_GtkTargetList);
+    pub fn gtk_drag_source_get_target_list     (widget: *mut C_GtkWidget) -> *mut C_GtkTargetList;
+    pub fn gtk_drag_source_add_text_targets    (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_source_add_image_targets   (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_source_add_uri_targets     (widget: *mut C_GtkWidget);
+    pub fn gtk_drag_source_set_icon_pixbuf     (widget: *mut C_GtkWidget, pixbuf: *mut gdk_ffi::C_GdkPixbuf);
+    pub fn gtk_drag_source_set_icon_name       (widget: *mut C_GtkWidget, icon_name: *const c_char);
+
+    //=========================================================================
+    // Selections                                                        NOT OK
+    //=========================================================================
+    pub fn gtk_selection_owner_set             (widget: *mut C_GtkWidget, selection: gdk_ffi::C_GdkAtom, time_: u32) -> Gboolean;
+    pub fn gtk_selection_owner_set_for_display (display: *mut gdk_ffi::C_GdkDisplay, widget: *mut C_GtkWidget,
+        selection: gdk_ffi::C_GdkAtom, time_: u32) -> Gboolean;
+    pub fn gtk_selection_add_target            (widget: *mut C_GtkWidget, selection: gdk_ffi::C_GdkAtom, target: gdk_ffi::C_GdkAtom,
+        info: c_uint);
+    pub fn gtk_selection_add_targets           (widget: *mut C_GtkWidget, selection: gdk_ffi::C_GdkAtom, targets: *const C_GtkTargetEntry,
+        ntargets: c_uint);
+    pub fn gtk_selection_clear_targets         (widget: *mut C_GtkWidget, selection: gdk_ffi::C_GdkAtom);
+    pub fn gtk_selection_convert               (widget: *mut C_GtkWidget, selection: gdk_ffi::C_GdkAtom, target: gdk_ffi::C_GdkAtom,
+        time_: u32) -> Gboolean;
+    pub fn gtk_selection_remove_all            (widget: *mut C_GtkWidget);
+
+    //=========================================================================
+    // GtkSelectionData                                                  NOT OK
+    //=========================================================================
+    pub fn gtk_selection_data_set              (selection_data: *mut C_GtkSelectionData, type_: gdk_ffi::C_GdkAtom, format: c_int,
+        data: *const u8, length: c_int);
+    pub fn gtk_selection_data_set_text         (selection_data: *mut C_GtkSelectionData, str_: *const c_char, len: c_int) -> Gboolean;
+    pub fn gtk_selection_data_get_text         (selection_data: *const C_GtkSelectionData) -> *mut c_char;
+    pub fn gtk_selection_data_set_pixbuf       (selection_data: *mut C_GtkSelectionData, pixbuf: *mut gdk_ffi::C_GdkPixbuf) -> Gboolean;
+    pub fn gtk_selection_data_get_pixbuf       (selection_data: *const C_GtkSelectionData) -> *mut gdk_ffi::C_GdkPixbuf;
+    pub fn gtk_selection_data_set_uris         (selection_data: *mut C_GtkSelectionData, uris: *const *const c_char) -> Gboolean;
+    pub fn gtk_selection_data_get_uris         (selection_data: *const C_GtkSelectionData) -> *mut *mut c_char;
+    pub fn gtk_selection_data_get_targets      (selection_data: *const C_GtkSelectionData, targets: *mut *mut gdk_ffi::C_GdkAtom,
+        n_atoms: *mut c_int) -> Gboolean;
+    pub fn gtk_selection_data_targets_include_image(selection_data: *const C_GtkSelectionData, writable: Gboolean) -> Gboolean;
+    pub fn gtk_selection_data_targets_include_text(selection_data: *const C_GtkSelectionData) -> Gboolean;
+    pub fn gtk_selection_data_targets_include_uri(selection_data: *const C_GtkSelectionData) -> Gboolean;
+    pub fn gtk_selection_data_get_selection    (selection_data: *const C_GtkSelectionData) -> gdk_ffi::C_GdkAtom;
+    pub fn gtk_selection_data_get_data_with_length(selection_data: *const C_GtkSelectionData, length: *mut c_int) -> *const u8;
+    pub fn gtk_selection_data_get_length       (selection_data: *const C_GtkSelectionData) -> c_int;
+    pub fn gtk_selection_data_get_data_type    (selection_data: *const C_GtkSelectionData) -> gdk_ffi::C_GdkAtom;
+    pub fn gtk_selection_data_get_display      (selection_data: *const C_GtkSelectionData) -> *mut gdk_ffi::C_GdkDisplay;
+    pub fn gtk_selection_data_get_format       (selection_data: *const C_GtkSelectionData) -> c_int;
+    pub fn gtk_selection_data_get_target       (selection_data: *const C_GtkSelectionData) -> gdk_ffi::C_GdkAtom;
+    pub fn gtk_selection_data_copy             (data: *const C_GtkSelectionData) -> *mut C_GtkSelectionData;
+    pub fn gtk_selection_data_free             (data: *mut C_GtkSelectionData);
+
     //=========================================================================
     // GtkTreeSelection                                                  NOT OK
     //=========================================================================
@@ -1564,39 +1750,40 @@ extern "C" {
     pub fn gtk_combo_box_text_get_active_text  (combo_box: *mut C_GtkComboBoxText) -> *mut c_char; // to free
 
     //=========================================================================
-    // GtkAppLaunchContext                                               NOT OK
+    // GAppLaunchContext                                                 NOT OK
     //=========================================================================
-    /// Error : /usr/lib/x86_64-linux-gnu/libgio-2.0.so.0: error adding symbols: DSO missing from command line
-    /*pub fn g_app_launch_context_new            () -> *mut C_GAppLaunchContext;
-    pub fn g_app_launch_context_setenv         (launch_context: *mut C_GAppLaunchContext, variable: *c_char, value: *c_char) -> ();
-    pub fn g_app_launch_context_unsetenv       (launch_context: *mut C_GAppLaunchContext, variable: *c_char) -> ();
-    pub fn g_app_launch_context_get_environment(launch_context: *mut C_GAppLaunchContext) -> **c_char;
-    //pub fn g_app_launch_context_get_display    (launch_context: *mut C_GAppLaunchContext, app_info: *mut C_GAppInfo, files: *mut C_GList) -> *c_char;
-    //pub fn g_app_launch_context_get_startup_notify_id(launch_context: *mut C_GAppLaunchContext, app_info: *mut C_GAppInfo, files: *mut C_GList) -> *c_char;
-    pub fn g_app_launch_context_launch_failed  (launch_context: *mut C_GAppLaunchContext, startup_notify_id: *c_char) -> ();*/
+    pub fn g_app_launch_context_new            () -> *mut C_GAppLaunchContext;
+    pub fn g_app_launch_context_setenv         (launch_context: *mut C_GAppLaunchContext, variable: *const c_char, value: *const c_char);
+    pub fn g_app_launch_context_unsetenv       (launch_context: *mut C_GAppLaunchContext, variable: *const c_char);
+    pub fn g_app_launch_context_get_environment(launch_context: *mut C_GAppLaunchContext) -> *mut *mut c_char;
+    pub fn g_app_launch_context_get_display    (launch_context: *mut C_GAppLaunchContext, app_info: *mut C_GAppInfo, files: *mut C_GList) -> *mut c_char;
+    pub fn g_app_launch_context_get_startup_notify_id(launch_context: *mut C_GAppLaunchContext, app_info: *mut C_GAppInfo, files: *mut C_GList) -> *mut c_char;
+    pub fn g_app_launch_context_launch_failed  (launch_context: *mut C_GAppLaunchContext, startup_notify_id: *const c_char);
 
     //=========================================================================
-    // GtkAppInfo                                                        NOT OK
+    // GAppInfo                                                          NOT OK
     //=========================================================================
-    /// Error : /usr/lib/x86_64-linux-gnu/libgio-2.0.so.0: error adding symbols: DSO missing from command line
-    /*pub fn g_app_info_create_from_commandline  (commande_line: *c_char, application_name: *c_char, flag: enums::AppInfoCreateFlags, error: *mut *mut C_GError) -> *mut C_GAppInfo;
+    pub fn g_app_info_create_from_commandline  (commande_line: *const c_char, application_name: *const c_char, flag: enums::app_info_create_flags::AppInfoCreateFlags, error: *mut *mut C_GError) -> *mut C_GAppInfo;
     pub fn g_app_info_dup                      (app_info: *mut C_GAppInfo) -> *mut C_GAppInfo;
     pub fn g_app_info_equal                    (app_info1: *mut C_GAppInfo, app_info2: *mut C_GAppInfo) -> Gboolean;
-    pub fn g_app_info_get_id                   (app_info: *mut C_GAppInfo) -> *c_char;
-    pub fn g_app_info_get_name                 (app_info: *mut C_GAppInfo) -> *c_char;
-    pub fn g_app_info_get_display_name         (app_info: *mut C_GAppInfo) -> *c_char;
-    pub fn g_app_info_get_description          (app_info: *mut C_GAppInfo) -> *c_char;
-    pub fn g_app_info_get_executable           (app_info: *mut C_GAppInfo) -> *c_char;
-    pub fn g_app_info_get_commandline          (app_info: *mut C_GAppInfo) -> *c_char;
+    pub fn g_app_info_get_id                   (app_info: *mut C_GAppInfo) -> *const c_char;
+    pub fn g_app_info_get_name                 (app_info: *mut C_GAppInfo) -> *const c_char;
+    pub fn g_app_info_get_display_name         (app_info: *mut C_GAppInfo) -> *const c_char;
+    pub fn g_app_info_get_description          (app_info: *mut C_GAppInfo) -> *const c_char;
+    pub fn g_app_info_get_executable           (app_info: *mut C_GAppInfo) -> *const c_char;
+    pub fn g_app_info_get_commandline          (app_info: *mut C_GAppInfo) -> *const c_char;
     //pub fn g_app_info_get_icon                 (app_info: *mut C_GAppInfo) -> *mut C_GIcon;
-    //pub fn g_app_info_launch                   (app_info: *mut C_GAppInfo, files: *mut C_GList, launch_context: *mut C_GAppLaunchContext, error: *mut *mut C_GError) -> Gboolean;
+    pub fn g_app_info_launch                   (app_info: *mut C_GAppInfo, files: *mut C_GList, launch_context: *mut C_GAppLaunchContext, error: *mut *mut C_GError) -> Gboolean;
     pub fn g_app_info_supports_files           (app_info: *mut C_GAppInfo) -> Gboolean;
     pub fn g_app_info_supports_uris            (app_info: *mut C_GAppInfo) -> Gboolean;
-    //pub fn g_app_info_launch_uris              (app_info: *mut C_GAppInfo, uris: *mut C_GList, launch_context: *mut C_GAppLaunchContext, error: *mut *mut C_GError) -> Gboolean;
+    pub fn g_app_info_launch_uris              (app_info: *mut C_GAppInfo, uris: *mut C_GList, launch_context: *mut C_GAppLaunchContext, error: *mut *mut C_GError) -> Gboolean;
     pub fn g_app_info_should_show              (app_info: *mut C_GAppInfo) -> Gboolean;
     pub fn g_app_info_can_delete               (app_info: *mut C_GAppInfo) -> Gboolean;
     pub fn g_app_info_delete                   (app_info: *mut C_GAppInfo) -> Gboolean;
-    pub fn g_app_info_reset_type_associations  (content_type: *c_char) -> ();
+    pub fn g_app_info_get_default_for_type     (content_type: *const c_char, must_support_uris: Gboolean) -> *mut C_GAppInfo;
+    pub fn g_app_info_get_default_for_uri_scheme(uri_scheme: *const c_char) -> *mut C_GAppInfo;
+    pub fn g_app_info_launch_default_for_uri   (uri: *const c_char, launch_context: *mut C_GAppLaunchContext, error: *mut *mut C_GError) -> Gboolean;
+    /*pub fn g_app_info_reset_type_associations  (content_type: *c_char) -> ();
     pub fn g_app_info_set_as_default_for_type  (app_info: *mut C_GAppInfo, content_type: *c_char, error: *mut *mut C_GError) -> Gboolean;
     pub fn g_app_info_set_as_default_for_extension(app_info: *mut C_GAppInfo, extension: *c_char, error: *mut *mut C_GError) -> Gboolean;
     pub fn g_app_info_set_as_last_used_for_type(app_info: *mut C_GAppInfo, content_type: *c_char, error: *mut *mut C_GError) -> Gboolean;
@@ -1606,11 +1793,14 @@ extern "C" {
     pub fn g_app_info_get_supported_types      (app_info: *mut C_GAppInfo) -> **c_char;
     //pub fn g_app_info_get_all                  () -> *mut C_GList;
     //pub fn g_app_info_get_all_for_type         (content_type: *c_char) -> *mut C_GList;
-    pub fn g_app_info_get_default_for_type     (content_type: *c_char, must_support_uris: Gboolean) -> *mut C_GAppInfo;
-    pub fn g_app_info_get_default_for_uri_scheme(uri_scheme: *c_char) -> *mut C_GAppInfo;
     //pub fn g_app_info_get_fallback_for_type    (content_type: *c_char) -> *mut C_GList;
-    //pub fn g_app_info_get_recommended_for_type (content_type: *c_char) -> *mut C_GList;
-    pub fn g_app_info_launch_default_for_uri   (uri: *c_char, launch_context: *mut C_GAppLaunchContext, error: *mut *mut C_GError) -> Gboolean;*/
+    //pub fn g_app_info_get_recommended_for_type (content_type: *c_char) -> *mut C_GList;*/
+
+    //=========================================================================
+    // GFile                                                             NOT OK
+    //=========================================================================
+    pub fn g_file_new_for_path                 (path: *const c_char) -> *mut C_GFile;
+    pub fn g_file_new_for_uri                  (uri: *const c_char) -> *mut C_GFile;
 
     //=========================================================================
     // GtkBuildable                                                      NOT OK
@@ -2153,7 +2343,18 @@ extern "C" {
     pub fn gtk_image_new_from_file              (filename: *const c_char) -> *mut C_GtkWidget;
     pub fn gtk_image_new_from_icon_name         (icon_name: *const c_char, size: enums::IconSize) -> *mut C_GtkWidget;
     pub fn gtk_image_set_from_file              (image: *mut C_GtkImage, filename: *const c_char);
+    pub fn gtk_image_new_from_pixbuf            (pixbuf: *mut gdk_ffi::C_GdkPixbuf) -> *mut C_GtkWidget;
+    pub fn gtk_image_new_from_animation         (animation: *mut gdk_ffi::C_GdkPixbufAnimation) -> *mut C_GtkWidget;
+    pub fn gtk_image_new_from_resource          (resource_path: *const c_char) -> *mut C_GtkWidget;
     pub fn gtk_image_set_from_icon_name         (image: *mut C_GtkImage, icon_name: *const c_char, size: enums::IconSize);
+    pub fn gtk_image_set_from_pixbuf            (image: *mut C_GtkImage, pixbuf: *mut gdk_ffi::C_GdkPixbuf);
+    pub fn gtk_image_set_from_animation         (image: *mut C_GtkImage, animation: *mut gdk_ffi::C_GdkPixbufAnimation);
+    pub fn gtk_image_set_from_resource          (image: *mut C_GtkImage, resource_path: *const c_char);
+    pub fn gtk_image_get_pixbuf                 (image: *mut C_GtkImage) -> *mut gdk_ffi::C_GdkPixbuf;
+    pub fn gtk_image_get_animation              (image: *mut C_GtkImage) -> *mut gdk_ffi::C_GdkPixbufAnimation;
+    pub fn gtk_image_clear                      (image: *mut C_GtkImage);
+    pub fn gtk_image_get_pixel_size             (image: *mut C_GtkImage) -> c_int;
+    pub fn gtk_image_set_pixel_size             (image: *mut C_GtkImage, pixel_size: c_int);
 
     //=========================================================================
     // GtkProgressBar
@@ -2362,7 +2563,7 @@ extern "C" {
     pub fn gtk_tool_palette_get_style          (palette: *mut C_GtkToolPalette) -> enums::ToolbarStyle;
     pub fn gtk_tool_palette_set_style          (palette: *mut C_GtkToolPalette, style: enums::ToolbarStyle);
     pub fn gtk_tool_palette_unset_style        (palette: *mut C_GtkToolPalette);
-    //pub fn gtk_tool_palette_add_drag_dest      (palette: *mut C_GtkToolPalette, widget: *mut C_GtkWidget, flags: enums::DestDefaults,
+    //pub fn gtk_tool_palette_add_drag_dest      (palette: *mut C_GtkToolPalette, widget: *mut C_GtkWidget, flags: enums::dest_defaults::DestDefaults,
     //    targets: enums::ToolPaletteDragTargets, actions: GdkDragAction);
     //pub fn gtk_tool_palette_get_drag_item      (palette: *mut C_GtkToolPalette, selection: *const C_GtkSelectionData) -> *mut C_GtkWidget;
     //pub fn gtk_tool_palette_get_drag_target_group() -> *const C_GtkTargetEntry;
@@ -3200,9 +3401,23 @@ extern "C" {
     // GtkSocket                                                         NOT OK
     //=========================================================================
     pub fn gtk_socket_new                  () -> *mut C_GtkWidget;
-    //pub fn gtk_socket_add_id               (socket: *mut C_GtkSocket, Window window);
-    //pub fn gtk_socket_get_id               (socket: *mut C_GtkSocket) -> Window;
-    //pub fn gtk_socket_get_plug_window      (socket: *mut C_GtkSocket) -> *mut C_GdkWindow;
+    #[cfg(feature = "X11")]
+    pub fn gtk_socket_add_id               (socket: *mut C_GtkSocket, window: gdk_ffi::x11::XID);
+    #[cfg(feature = "X11")]
+    pub fn gtk_socket_get_id               (socket: *mut C_GtkSocket) -> gdk_ffi::x11::XID;
+    pub fn gtk_socket_get_plug_window      (socket: *mut C_GtkSocket) -> *mut gdk_ffi::C_GdkWindow;
+
+    //=========================================================================
+    // GtkPlug                                                           NOT OK
+    //=========================================================================
+    #[cfg(feature = "X11")]
+    pub fn gtk_plug_new                    (socket_id: gdk_ffi::x11::XID) -> *mut C_GtkWidget;
+    #[cfg(feature = "X11")]
+    pub fn gtk_plug_new_for_display        (display: *mut gdk_ffi::C_GdkDisplay, socket_id: gdk_ffi::x11::XID) -> *mut C_GtkWidget;
+    #[cfg(feature = "X11")]
+    pub fn gtk_plug_get_id                 (plug: *mut C_GtkPlug) -> gdk_ffi::x11::XID;
+    pub fn gtk_plug_get_embedded           (plug: *mut C_GtkPlug) -> Gboolean;
+    pub fn gtk_plug_get_socket_window      (plug: *mut C_GtkPlug) -> *mut gdk_ffi::C_GdkWindow;
 
     //=========================================================================
     // GtkEventBox                                                       NOT OK
@@ -3213,6 +3428,13 @@ extern "C" {
     pub fn gtk_event_box_set_visible_window(event_box: *mut C_GtkEventBox, visible_window: Gboolean);
     pub fn gtk_event_box_get_visible_window(event_box: *mut C_GtkEventBox) -> Gboolean;
 
+    //=========================================================================
+    // GtkOffscreenWindow                                                NOT OK
+    //=========================================================================
+    pub fn gtk_offscreen_window_new        () -> *mut C_GtkWidget;
+    pub fn gtk_offscreen_window_get_surface(offscreen: *mut C_GtkOffscreenWindow) -> *mut cairo_surface_t;
+    pub fn gtk_offscreen_window_get_pixbuf (offscreen: *mut C_GtkOffscreenWindow) -> *mut gdk_ffi::C_GdkPixbuf;
+
     //=========================================================================
     // Glue fixe code
     //=========================================================================
@@ -3384,6 +3606,7 @@ extern "C" {
     pub fn cast_GtkTreeStore(obj: *mut C_GObject) -> *mut C_GtkTreeStore;
     pub fn cast_GtkTreeModel(obj: *mut C_GObject) -> *mut C_GtkTreeModel;
     pub fn cast_GtkTextMark(widget: *mut C_GObject) -> *mut C_GtkTextMark;
+    pub fn cast_GtkClipboard(obj: *mut C_GObject) -> *mut C_GtkClipboard;
     pub fn cast_GtkPlacesSidebar(widget: *mut C_GtkWidget) -> *mut C_GtkPlacesSidebar;
     pub fn cast_GtkToolPalette(widget: *mut C_GtkWidget) -> *mut C_GtkToolPalette;
     pub fn cast_GtkToolItemGroup(widget: *mut C_GtkWidget) -> *mut C_GtkToolItemGroup;
@@ -3392,5 +3615,7 @@ extern "C" {
     pub fn cast_GtkColorChooserWidget(widget: *mut C_GtkWidget) -> *mut C_GtkColorChooserWidget;
     pub fn cast_GtkFontChooserWidget(widget: *mut C_GtkWidget) -> *mut C_GtkFontChooserWidget;
     pub fn cast_GtkSocket(widget: *mut C_GtkWidget) -> *mut C_GtkSocket;
+    pub fn cast_GtkPlug(widget: *mut C_GtkWidget) -> *mut C_GtkPlug;
     pub fn cast_GtkEventBox(widget: *mut C_GtkWidget) -> *mut C_GtkEventBox;
+    pub fn cast_GtkOffscreenWindow(widget: *mut C_GtkWidget) -> *mut C_GtkOffscreenWindow;
 }
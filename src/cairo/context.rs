@@ -21,6 +21,7 @@ use libc::{c_double, c_int};
 use cairo::paths::Path;
 use cairo::fonts::{TextExtents, TextCluster, FontExtents, ScaledFont, FontOptions, FontFace, Glyph};
 use cairo::matrices::{Matrix, MatrixTrait};
+use cairo::surface::Surface;
 use cairo::enums::{
     FontSlant,
     FontWeight,
@@ -112,7 +113,11 @@ impl Context {
         self.ensure_status()
     }
 
-    //fn ffi::cairo_get_target (cr: *mut cairo_t) -> *mut cairo_surface_t;
+    pub fn get_target(&self) -> Surface {
+        unsafe {
+            Surface::wrap(ffi::cairo_surface_reference(ffi::cairo_get_target(self.get_ptr())))
+        }
+    }
 
     pub fn push_group(&self){
         unsafe {
@@ -166,7 +171,12 @@ impl Context {
         }
     }
 
-    //fn ffi::cairo_set_source_surface (cr: *mut cairo_t, surface: *mut cairo_surface_t, x: c_double, y: c_double);
+    pub fn set_source_surface(&self, surface: &Surface, x: f64, y: f64) {
+        unsafe {
+            ffi::cairo_set_source_surface(self.get_ptr(), surface.get_ptr(), x, y);
+        }
+        self.ensure_status();
+    }
 
     pub fn set_antialias(&self, antialias : Antialias){
         unsafe {
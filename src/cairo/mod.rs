@@ -39,7 +39,16 @@ pub use self::enums::{
     LineCap,
     LineJoin,
     Operator,
-    PathDataType
+    PathDataType,
+    RegionOverlap,
+    Format
+};
+
+pub use self::surface::{Surface, ImageSurface};
+
+pub use self::region::{
+    Region,
+    RectangleInt
 };
 
 pub use self::patterns::{
@@ -80,4 +89,6 @@ mod fonts;
 mod context;
 mod paths;
 mod patterns;
-mod matrices;
\ No newline at end of file
+mod matrices;
+mod surface;
+mod region;
\ No newline at end of file
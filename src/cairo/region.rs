@@ -0,0 +1,215 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use libc::c_int;
+use cairo::enums::RegionOverlap;
+use cairo::ffi;
+use cairo::ffi::cairo_region_t;
+
+pub use cairo::ffi::cairo_rectangle_int_t as RectangleInt;
+
+/// A Region represents a set of integer-aligned rectangles. It allows set-theoretical operations
+/// like union and intersection to be performed on them.
+pub struct Region {
+    pointer: *mut cairo_region_t
+}
+
+impl Region {
+    /// Takes ownership of a reference to a cairo_region_t.
+    pub fn wrap(pointer: *mut cairo_region_t) -> Region {
+        Region {
+            pointer: pointer
+        }
+    }
+
+    /// Allocates a new empty region object.
+    pub fn new() -> Region {
+        Region::wrap(unsafe {
+            ffi::cairo_region_create()
+        })
+    }
+
+    /// Allocates a new region object containing `rectangle`.
+    pub fn create_rectangle(rectangle: &RectangleInt) -> Region {
+        let mut rectangle = *rectangle;
+
+        Region::wrap(unsafe {
+            ffi::cairo_region_create_rectangle(&mut rectangle)
+        })
+    }
+
+    /// Allocates a new region object containing the union of all given `rects`.
+    pub fn create_rectangles(rects: &[RectangleInt]) -> Region {
+        let mut rects = rects.to_vec();
+
+        Region::wrap(unsafe {
+            ffi::cairo_region_create_rectangles(rects.as_mut_ptr(), rects.len() as c_int)
+        })
+    }
+
+    /// Allocates a new region object copying the area from `self`.
+    pub fn copy(&self) -> Region {
+        Region::wrap(unsafe {
+            ffi::cairo_region_copy(self.pointer)
+        })
+    }
+
+    pub fn reference(&self) -> Region {
+        Region::wrap(unsafe {
+            ffi::cairo_region_reference(self.pointer)
+        })
+    }
+
+    pub fn get_ptr(&self) -> *mut cairo_region_t {
+        self.pointer
+    }
+
+    pub fn ensure_status(&self) {
+        unsafe {
+            ffi::cairo_region_status(self.pointer).ensure_valid()
+        }
+    }
+
+    /// Gets the bounding rectangle of the region.
+    pub fn get_extents(&self) -> RectangleInt {
+        let mut extents = RectangleInt { x: 0, y: 0, width: 0, height: 0 };
+
+        unsafe {
+            ffi::cairo_region_get_extents(self.pointer, &mut extents)
+        }
+        extents
+    }
+
+    pub fn num_rectangles(&self) -> i32 {
+        unsafe {
+            ffi::cairo_region_num_rectangles(self.pointer) as i32
+        }
+    }
+
+    pub fn get_rectangle(&self, nth: i32) -> RectangleInt {
+        let mut rectangle = RectangleInt { x: 0, y: 0, width: 0, height: 0 };
+
+        unsafe {
+            ffi::cairo_region_get_rectangle(self.pointer, nth as c_int, &mut rectangle)
+        }
+        rectangle
+    }
+
+    /// Returns the rectangles the region is made of.
+    pub fn get_rectangles(&self) -> Vec<RectangleInt> {
+        (0..self.num_rectangles()).map(|i| self.get_rectangle(i)).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe {
+            ffi::cairo_region_is_empty(self.pointer).as_bool()
+        }
+    }
+
+    pub fn contains_point(&self, x: i32, y: i32) -> bool {
+        unsafe {
+            ffi::cairo_region_contains_point(self.pointer, x as c_int, y as c_int).as_bool()
+        }
+    }
+
+    /// Checks whether `rectangle` is inside, outside or partially contained in the region.
+    pub fn contains_rectangle(&self, rectangle: &RectangleInt) -> RegionOverlap {
+        let mut rectangle = *rectangle;
+
+        unsafe {
+            ffi::cairo_region_contains_rectangle(self.pointer, &mut rectangle)
+        }
+    }
+
+    pub fn translate(&self, dx: i32, dy: i32) {
+        unsafe {
+            ffi::cairo_region_translate(self.pointer, dx as c_int, dy as c_int)
+        }
+    }
+
+    pub fn intersect(&self, other: &Region) {
+        unsafe {
+            ffi::cairo_region_intersect(self.pointer, other.pointer).ensure_valid()
+        }
+    }
+
+    pub fn intersect_rectangle(&self, rectangle: &RectangleInt) {
+        let mut rectangle = *rectangle;
+
+        unsafe {
+            ffi::cairo_region_intersect_rectangle(self.pointer, &mut rectangle).ensure_valid()
+        }
+    }
+
+    pub fn subtract(&self, other: &Region) {
+        unsafe {
+            ffi::cairo_region_subtract(self.pointer, other.pointer).ensure_valid()
+        }
+    }
+
+    pub fn subtract_rectangle(&self, rectangle: &RectangleInt) {
+        let mut rectangle = *rectangle;
+
+        unsafe {
+            ffi::cairo_region_subtract_rectangle(self.pointer, &mut rectangle).ensure_valid()
+        }
+    }
+
+    pub fn union(&self, other: &Region) {
+        unsafe {
+            ffi::cairo_region_union(self.pointer, other.pointer).ensure_valid()
+        }
+    }
+
+    pub fn union_rectangle(&self, rectangle: &RectangleInt) {
+        let mut rectangle = *rectangle;
+
+        unsafe {
+            ffi::cairo_region_union_rectangle(self.pointer, &mut rectangle).ensure_valid()
+        }
+    }
+
+    pub fn xor(&self, other: &Region) {
+        unsafe {
+            ffi::cairo_region_xor(self.pointer, other.pointer).ensure_valid()
+        }
+    }
+
+    pub fn xor_rectangle(&self, rectangle: &RectangleInt) {
+        let mut rectangle = *rectangle;
+
+        unsafe {
+            ffi::cairo_region_xor_rectangle(self.pointer, &mut rectangle).ensure_valid()
+        }
+    }
+}
+
+impl PartialEq for Region {
+    fn eq(&self, other: &Region) -> bool {
+        unsafe {
+            ffi::cairo_region_equal(self.pointer, other.pointer).as_bool()
+        }
+    }
+}
+
+impl Eq for Region {}
+
+impl Drop for Region {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::cairo_region_destroy(self.pointer)
+        }
+    }
+}
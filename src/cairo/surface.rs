@@ -0,0 +1,221 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::ops::Deref;
+use std::slice;
+use libc::{c_double, c_int};
+use glib::translate::ToGlibPtr;
+use cairo::enums::{Status, Format};
+use cairo::ffi;
+use cairo::ffi::cairo_surface_t;
+
+/// A Surface represents an image, either as the destination of a drawing operation or as source
+/// when drawing onto another surface.
+pub struct Surface {
+    pointer: *mut cairo_surface_t
+}
+
+impl Surface {
+    /// Takes ownership of a reference to a cairo_surface_t.
+    pub fn wrap(pointer: *mut cairo_surface_t) -> Surface {
+        Surface {
+            pointer: pointer
+        }
+    }
+
+    pub fn reference(&self) -> Surface {
+        Surface {
+            pointer: unsafe {
+                ffi::cairo_surface_reference(self.pointer)
+            }
+        }
+    }
+
+    pub fn get_ptr(&self) -> *mut cairo_surface_t {
+        self.pointer
+    }
+
+    pub fn ensure_status(&self) {
+        self.status().ensure_valid();
+    }
+
+    pub fn status(&self) -> Status {
+        unsafe {
+            ffi::cairo_surface_status(self.pointer)
+        }
+    }
+
+    pub fn get_reference_count(&self) -> isize {
+        unsafe {
+            ffi::cairo_surface_get_reference_count(self.pointer) as isize
+        }
+    }
+
+    /// Finishes the surface and drops all references to external resources. No further drawing
+    /// to the surface is possible afterwards.
+    pub fn finish(&self) {
+        unsafe {
+            ffi::cairo_surface_finish(self.pointer)
+        }
+    }
+
+    /// Does any pending drawing for the surface and restores any temporary modifications cairo
+    /// has made to the surface's state. Must be called before switching from drawing on the
+    /// surface with cairo to drawing on it directly.
+    pub fn flush(&self) {
+        unsafe {
+            ffi::cairo_surface_flush(self.pointer)
+        }
+    }
+
+    /// Tells cairo that drawing has been done to the surface using means other than cairo.
+    pub fn mark_dirty(&self) {
+        unsafe {
+            ffi::cairo_surface_mark_dirty(self.pointer)
+        }
+    }
+
+    pub fn mark_dirty_rectangle(&self, x: i32, y: i32, width: i32, height: i32) {
+        unsafe {
+            ffi::cairo_surface_mark_dirty_rectangle(self.pointer, x as c_int, y as c_int, width as c_int, height as c_int)
+        }
+    }
+
+    pub fn set_device_offset(&self, x_offset: f64, y_offset: f64) {
+        unsafe {
+            ffi::cairo_surface_set_device_offset(self.pointer, x_offset as c_double, y_offset as c_double)
+        }
+    }
+
+    pub fn get_device_offset(&self) -> (f64, f64) {
+        let mut x_offset = 0.;
+        let mut y_offset = 0.;
+
+        unsafe {
+            ffi::cairo_surface_get_device_offset(self.pointer, &mut x_offset, &mut y_offset)
+        }
+        (x_offset, y_offset)
+    }
+
+    /// Writes the contents of the surface to a new PNG file.
+    pub fn write_to_png(&self, filename: &str) -> Status {
+        unsafe {
+            ffi::cairo_surface_write_to_png(self.pointer, filename.borrow_to_glib().0)
+        }
+    }
+}
+
+impl Drop for Surface {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::cairo_surface_destroy(self.pointer)
+        }
+    }
+}
+
+/// An image surface, drawing into a buffer of pixels in memory.
+pub struct ImageSurface {
+    surface: Surface
+}
+
+impl ImageSurface {
+    /// Creates an image surface of the given format and size, initially cleared (transparent
+    /// black).
+    pub fn create(format: Format, width: i32, height: i32) -> ImageSurface {
+        ImageSurface {
+            surface: Surface::wrap(unsafe {
+                ffi::cairo_image_surface_create(format, width as c_int, height as c_int)
+            })
+        }
+    }
+
+    /// Creates an image surface from a PNG file, the status of the surface tells if the file
+    /// could be read.
+    pub fn create_from_png(filename: &str) -> ImageSurface {
+        ImageSurface {
+            surface: Surface::wrap(unsafe {
+                ffi::cairo_image_surface_create_from_png(filename.borrow_to_glib().0)
+            })
+        }
+    }
+
+    /// Views `surface` as an image surface, None if it's of another type.
+    pub fn from_surface(surface: Surface) -> Option<ImageSurface> {
+        let surface_type = unsafe { ffi::cairo_surface_get_type(surface.get_ptr()) };
+
+        if surface_type != ffi::CAIRO_SURFACE_TYPE_IMAGE {
+            None
+        } else {
+            Some(ImageSurface {
+                surface: surface
+            })
+        }
+    }
+
+    /// Returns the stride to use for an image of the given format and width.
+    pub fn stride_for_width(format: Format, width: i32) -> i32 {
+        unsafe {
+            ffi::cairo_format_stride_for_width(format, width as c_int) as i32
+        }
+    }
+
+    pub fn get_format(&self) -> Format {
+        unsafe {
+            ffi::cairo_image_surface_get_format(self.surface.get_ptr())
+        }
+    }
+
+    pub fn get_width(&self) -> i32 {
+        unsafe {
+            ffi::cairo_image_surface_get_width(self.surface.get_ptr()) as i32
+        }
+    }
+
+    pub fn get_height(&self) -> i32 {
+        unsafe {
+            ffi::cairo_image_surface_get_height(self.surface.get_ptr()) as i32
+        }
+    }
+
+    /// Returns the number of bytes between the starts of two rows of the image.
+    pub fn get_stride(&self) -> i32 {
+        unsafe {
+            ffi::cairo_image_surface_get_stride(self.surface.get_ptr()) as i32
+        }
+    }
+
+    /// Returns a copy of the pixels of the image, `get_stride() * get_height()` bytes in the
+    /// native endianness of the format. Pending drawing is flushed first.
+    pub fn get_data(&self) -> Vec<u8> {
+        self.surface.flush();
+
+        let len = (self.get_stride() * self.get_height()) as usize;
+        let data = unsafe { ffi::cairo_image_surface_get_data(self.surface.get_ptr()) };
+
+        if data.is_null() {
+            Vec::new()
+        } else {
+            unsafe { slice::from_raw_parts(data as *const u8, len).to_vec() }
+        }
+    }
+}
+
+impl Deref for ImageSurface {
+    type Target = Surface;
+
+    fn deref(&self) -> &Surface {
+        &self.surface
+    }
+}
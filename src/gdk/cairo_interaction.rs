@@ -0,0 +1,115 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Cairo Interaction — Functions to support using cairo
+
+use gdk::{self, ffi};
+use cairo;
+
+/// Sets the given pixbuf as the source pattern for `cr`. The pattern has an extend mode of
+/// `ExtendNone` and is aligned so that the origin of the pixbuf is `pixbuf_x`, `pixbuf_y`.
+pub fn cairo_set_source_pixbuf(cr: &cairo::Context, pixbuf: &gdk::Pixbuf, pixbuf_x: f64, pixbuf_y: f64) {
+    unsafe {
+        ffi::gdk_cairo_set_source_pixbuf(cr.get_ptr(), pixbuf.unwrap_pointer() as *const ffi::C_GdkPixbuf, pixbuf_x,
+            pixbuf_y)
+    }
+}
+
+/// Sets the specified RGBA color as the source color of `cr`.
+pub fn cairo_set_source_rgba(cr: &cairo::Context, rgba: &ffi::C_GdkRGBA) {
+    unsafe { ffi::gdk_cairo_set_source_rgba(cr.get_ptr(), rgba) }
+}
+
+/// Sets the given window as the source pattern for `cr`, with its origin at `x`, `y`. The window
+/// contains all its subwindows when rendering.
+pub fn cairo_set_source_window(cr: &cairo::Context, window: &gdk::Window, x: f64, y: f64) {
+    unsafe { ffi::gdk_cairo_set_source_window(cr.get_ptr(), window.unwrap_pointer(), x, y) }
+}
+
+/// Adds the given rectangle to the current path of `cr`.
+pub fn cairo_rectangle(cr: &cairo::Context, rectangle: &ffi::C_GdkRectangle) {
+    unsafe { ffi::gdk_cairo_rectangle(cr.get_ptr(), rectangle) }
+}
+
+/// Adds the given region to the current path of `cr`.
+pub fn cairo_region(cr: &cairo::Context, region: &cairo::Region) {
+    unsafe { ffi::gdk_cairo_region(cr.get_ptr(), region.get_ptr()) }
+}
+
+/// Computes the clip rectangle of `cr` in user space. Returns false if all drawing to `cr` is
+/// clipped away.
+pub fn cairo_get_clip_rectangle(cr: &cairo::Context, rect: &mut ffi::C_GdkRectangle) -> bool {
+    unsafe { ::glib::to_bool(ffi::gdk_cairo_get_clip_rectangle(cr.get_ptr(), rect)) }
+}
+
+/// Creates a region that covers the area where the given surface is more than 50%
+/// opaque. This function takes into account device offsets that might be set with
+/// `Surface::set_device_offset`.
+pub fn cairo_region_create_from_surface(surface: &cairo::Surface) -> Option<cairo::Region> {
+    let tmp = unsafe { ffi::gdk_cairo_region_create_from_surface(surface.get_ptr()) };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(cairo::Region::wrap(tmp))
+    }
+}
+
+/// Creates an image surface with the same contents as the pixbuf, for drawing on `for_window`
+/// with the given `scale` factor.
+#[cfg(feature = "GTK_3_10")]
+pub fn cairo_surface_create_from_pixbuf(pixbuf: &gdk::Pixbuf, scale: i32,
+                                        for_window: Option<&gdk::Window>) -> Option<cairo::Surface> {
+    let tmp = unsafe {
+        ffi::gdk_cairo_surface_create_from_pixbuf(pixbuf.unwrap_pointer() as *const ffi::C_GdkPixbuf, scale,
+            match for_window {
+                Some(w) => w.unwrap_pointer(),
+                None => ::std::ptr::null_mut()
+            })
+    };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(cairo::Surface::wrap(tmp))
+    }
+}
+
+/// Transfers image data from a window and converts it to an RGB(A) representation inside a new
+/// Pixbuf. This is how a screenshot of a realized widget is taken. The window must be
+/// visible on screen.
+pub fn pixbuf_get_from_window(window: &gdk::Window, src_x: i32, src_y: i32, width: i32,
+                              height: i32) -> Option<gdk::Pixbuf> {
+    let tmp = unsafe { ffi::gdk_pixbuf_get_from_window(window.unwrap_pointer(), src_x, src_y, width, height) };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(gdk::Pixbuf::wrap_pointer(tmp))
+    }
+}
+
+/// Transfers image data from a cairo surface and converts it to an RGB(A) representation
+/// inside a new Pixbuf.
+pub fn pixbuf_get_from_surface(surface: &cairo::Surface, src_x: i32, src_y: i32, width: i32,
+                               height: i32) -> Option<gdk::Pixbuf> {
+    let tmp = unsafe { ffi::gdk_pixbuf_get_from_surface(surface.get_ptr(), src_x, src_y, width, height) };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(gdk::Pixbuf::wrap_pointer(tmp))
+    }
+}
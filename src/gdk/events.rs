@@ -14,8 +14,10 @@
 // along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
 
 use gdk::{self, ffi};
-use libc::c_void;
+use glib::to_bool;
+use libc::{c_char, c_int, c_uint, c_void};
 use std::mem;
+use std::ops::Deref;
 
 pub use self::event_type::EventType;
 pub use self::owner_change::OwnerChange;
@@ -74,13 +76,19 @@ pub mod event_type {
     }
 }
 
-pub trait Event: Sized {
+/// Common behaviour of the typed event structures.
+pub trait EventTrait: Sized {
     fn get_send_event(&self) -> bool {
         unsafe {
             let event_any : &EventAny = mem::transmute(self);
-            event_any.send_event == 0
+            event_any.send_event != 0
         }
     }
+
+    /// Views the typed event as a generic `Event`.
+    fn as_event(&self) -> &Event {
+        unsafe { mem::transmute(self) }
+    }
 }
 
 #[repr(C)]
@@ -90,8 +98,9 @@ pub struct EventAny {
     send_event : i8,
 }
 
-impl Event for EventAny {}
+impl EventTrait for EventAny {}
 
+#[repr(C)]
 pub struct EventExpose {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -99,57 +108,61 @@ pub struct EventExpose {
 
     pub area : ffi::C_GdkRectangle,
     pub region : *mut c_void, //TODO cairo_region_t
-    pub count : i8 /* If non-zero, how many more events follow. */
+    pub count : i32 /* If non-zero, how many more events follow. */
 }
 
-impl Event for EventExpose {}
+impl EventTrait for EventExpose {}
 
+#[repr(C)]
 pub struct EventVisibility{
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
     send_event : i8,
 
-    state : gdk::VisibilityState
+    pub state : gdk::VisibilityState
 }
 
-impl Event for EventVisibility {}
+impl EventTrait for EventVisibility {}
 
+#[repr(C)]
 pub struct EventMotion {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
     send_event : i8,
 
-    time : u32,
-    x : f64,
-    y : f64,
-    axes : *mut f64,
-    state : gdk::enums::modifier_type::ModifierType,
-    is_hint : i16,
-    device : *mut gdk::Device,
-    x_root : f64,
-    y_root : f64
+    pub time : u32,
+    pub x : f64,
+    pub y : f64,
+    pub axes : *mut f64,
+    pub state : gdk::enums::modifier_type::ModifierType,
+    pub is_hint : i16,
+    pub device : *mut gdk::Device,
+    pub x_root : f64,
+    pub y_root : f64
 }
 
-impl Event for EventMotion {}
+impl EventTrait for EventMotion {}
 
+#[repr(C)]
 pub struct EventButton {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
     send_event : i8,
 
-    time : u32,
-    x : f64,
-    y : f64,
-    axes : *mut f64,
-    state : gdk::enums::modifier_type::ModifierType,
-    button : u32,
-    device : *mut gdk::Device,
-    x_root : f64,
-    y_root : f64
+    pub time : u32,
+    pub x : f64,
+    pub y : f64,
+    pub axes : *mut f64,
+    pub state : gdk::enums::modifier_type::ModifierType,
+    pub button : u32,
+    pub device : *mut gdk::Device,
+    pub x_root : f64,
+    pub y_root : f64
 }
 
-impl Event for EventButton {}
+impl EventTrait for EventButton {}
 
+#[repr(C)]
 pub struct EventTouch {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -167,8 +180,9 @@ pub struct EventTouch {
     pub y_root : f64
 }
 
-impl Event for EventTouch {}
+impl EventTrait for EventTouch {}
 
+#[repr(C)]
 pub struct EventScroll {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -186,8 +200,9 @@ pub struct EventScroll {
     pub delta_y : f64
 }
 
-impl Event for EventScroll {}
+impl EventTrait for EventScroll {}
 
+#[repr(C)]
 pub struct EventKey {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -197,20 +212,21 @@ pub struct EventKey {
     pub state : gdk::enums::modifier_type::ModifierType,
     pub keyval : u32,
     pub length : i32,
-    pub string : *mut char,
+    pub string : *mut c_char,
     pub hardware_keycode : u16,
     pub group : u8,
     pub is_modifier: u32
 }
 
-impl Event for EventKey {}
+impl EventTrait for EventKey {}
 
+#[repr(C)]
 pub struct EventCrossing {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
     send_event : i8,
 
-    pub subwindow : gdk::Window,
+    pub subwindow : *mut gdk::Window,
     pub time : u32,
     pub x : f64,
     pub y : f64,
@@ -222,18 +238,20 @@ pub struct EventCrossing {
     pub state : gdk::enums::modifier_type::ModifierType
 }
 
-impl Event for EventCrossing {}
+impl EventTrait for EventCrossing {}
 
+#[repr(C)]
 pub struct EventFocus {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
     send_event : i8,
 
-    _in : i16
+    pub _in : i16
 }
 
-impl Event for EventFocus {}
+impl EventTrait for EventFocus {}
 
+#[repr(C)]
 pub struct EventConfigure {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -245,20 +263,22 @@ pub struct EventConfigure {
     pub height : i32
 }
 
-impl Event for EventConfigure {}
+impl EventTrait for EventConfigure {}
 
+#[repr(C)]
 pub struct EventProperty {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
     send_event : i8,
 
-    atom : gdk::Atom,
-    time : u32,
-    state : u32 //FIXME
+    pub atom : gdk::Atom,
+    pub time : u32,
+    pub state : u32 //FIXME
 }
 
-impl Event for EventProperty {}
+impl EventTrait for EventProperty {}
 
+#[repr(C)]
 pub struct EventSelection {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -271,8 +291,9 @@ pub struct EventSelection {
     pub requestor : *mut gdk::Window
 }
 
-impl Event for EventSelection {}
+impl EventTrait for EventSelection {}
 
+#[repr(C)]
 pub struct EventOwnerChange {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -285,8 +306,9 @@ pub struct EventOwnerChange {
     pub selection_time : u32
 }
 
-impl Event for EventOwnerChange {}
+impl EventTrait for EventOwnerChange {}
 
+#[repr(C)]
 pub struct EventProximity {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -296,8 +318,9 @@ pub struct EventProximity {
     pub device : *mut gdk::Device
 }
 
-impl Event for EventProximity {}
+impl EventTrait for EventProximity {}
 
+#[repr(C)]
 pub struct EventSetting {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -307,8 +330,9 @@ pub struct EventSetting {
     pub name : *mut char
 }
 
-impl Event for EventSetting {}
+impl EventTrait for EventSetting {}
 
+#[repr(C)]
 pub struct EventWindowState {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -318,8 +342,9 @@ pub struct EventWindowState {
     pub new_window_state : gdk::WindowState
 }
 
-impl Event for EventWindowState {}
+impl EventTrait for EventWindowState {}
 
+#[repr(C)]
 pub struct EventGrabBroken {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -330,8 +355,9 @@ pub struct EventGrabBroken {
     pub grab_window : *mut gdk::Window
 }
 
-impl Event for EventGrabBroken  {}
+impl EventTrait for EventGrabBroken  {}
 
+#[repr(C)]
 pub struct EventDND {
     pub _type : gdk::EventType,
     pub window : *mut gdk::Window,
@@ -343,7 +369,381 @@ pub struct EventDND {
     pub y_root : i16  //short
 }
 
-impl Event for EventDND  {}
+impl EventTrait for EventDND  {}
+
+/// A GdkEvent of any type, borrowed from GDK. The concrete event can be retrieved with
+/// `get_kind`, the accessors work on every event type that carries the requested information.
+#[repr(C)]
+pub struct Event {
+    _type : c_int
+}
+
+impl Event {
+    /// Returns the type of the event, `EventType::Nothing` for types unknown to these bindings.
+    pub fn get_event_type(&self) -> gdk::EventType {
+        match self._type {
+            -1 ... 29 | 31 ... 40 => unsafe { mem::transmute(self._type) },
+            _ => gdk::EventType::Nothing
+        }
+    }
+
+    /// Returns the typed event structure matching the type of the event.
+    pub fn get_kind<'a>(&'a self) -> EventKind<'a> {
+        unsafe {
+            match self.get_event_type() {
+                gdk::EventType::Expose | gdk::EventType::Damage => EventKind::Expose(mem::transmute(self)),
+                gdk::EventType::VisibilityNotify => EventKind::Visibility(mem::transmute(self)),
+                gdk::EventType::MotionNotify => EventKind::Motion(mem::transmute(self)),
+                gdk::EventType::ButtonPress | gdk::EventType::DoubleButtonPress | gdk::EventType::TripleButtonPress
+                    | gdk::EventType::ButtonRelease => EventKind::Button(mem::transmute(self)),
+                gdk::EventType::TouchBegin | gdk::EventType::TouchUpdate | gdk::EventType::TouchEnd
+                    | gdk::EventType::TouchCancel => EventKind::Touch(mem::transmute(self)),
+                gdk::EventType::Scroll => EventKind::Scroll(mem::transmute(self)),
+                gdk::EventType::KeyPress | gdk::EventType::KeyRelease => EventKind::Key(mem::transmute(self)),
+                gdk::EventType::EnterNotify | gdk::EventType::LeaveNotify => EventKind::Crossing(mem::transmute(self)),
+                gdk::EventType::FocusChange => EventKind::Focus(mem::transmute(self)),
+                gdk::EventType::Configure => EventKind::Configure(mem::transmute(self)),
+                gdk::EventType::PropertyNotify => EventKind::Property(mem::transmute(self)),
+                gdk::EventType::SelectionClear | gdk::EventType::SelectionRequest
+                    | gdk::EventType::SelectionNotify => EventKind::Selection(mem::transmute(self)),
+                gdk::EventType::OwnerChange => EventKind::OwnerChange(mem::transmute(self)),
+                gdk::EventType::ProximityIn | gdk::EventType::ProximityOut => EventKind::Proximity(mem::transmute(self)),
+                gdk::EventType::Setting => EventKind::Setting(mem::transmute(self)),
+                gdk::EventType::WindowState => EventKind::WindowState(mem::transmute(self)),
+                gdk::EventType::GrabBroken => EventKind::GrabBroken(mem::transmute(self)),
+                gdk::EventType::DragEnter | gdk::EventType::DragLeave | gdk::EventType::DragMotion
+                    | gdk::EventType::DragStatus | gdk::EventType::DropStart
+                    | gdk::EventType::DropFinished => EventKind::DND(mem::transmute(self)),
+                _ => EventKind::Any(mem::transmute(self))
+            }
+        }
+    }
+
+    pub fn get_send_event(&self) -> bool {
+        self.get_any().get_send_event()
+    }
+
+    /// Views the event as its common header.
+    pub fn get_any(&self) -> &EventAny {
+        unsafe { mem::transmute(self) }
+    }
+
+    /// Returns the time stamp of the event, 0 (`CURRENT_TIME`) if the event has none.
+    pub fn get_time(&self) -> u32 {
+        unsafe { ffi::gdk_event_get_time(self.unwrap_pointer()) }
+    }
+
+    /// Returns the window the event was delivered to.
+    pub fn get_window(&self) -> Option<gdk::WindowRef> {
+        let tmp = unsafe { ffi::gdk_event_get_window(self.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::WindowRef::wrap_pointer(tmp))
+        }
+    }
+
+    /// Returns the coordinates of the event relative to its window, if it has any.
+    pub fn get_coords(&self) -> Option<(f64, f64)> {
+        let mut x = 0.;
+        let mut y = 0.;
+
+        if unsafe { to_bool(ffi::gdk_event_get_coords(self.unwrap_pointer(), &mut x, &mut y)) } {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the coordinates of the event relative to the root window, if it has any.
+    pub fn get_root_coords(&self) -> Option<(f64, f64)> {
+        let mut x = 0.;
+        let mut y = 0.;
+
+        if unsafe { to_bool(ffi::gdk_event_get_root_coords(self.unwrap_pointer(), &mut x, &mut y)) } {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the modifier state of the event, if it has one.
+    pub fn get_state(&self) -> Option<gdk::ModifierType> {
+        let mut state = gdk::ModifierType::empty();
+
+        if unsafe { to_bool(ffi::gdk_event_get_state(self.unwrap_pointer(), &mut state)) } {
+            Some(state)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the mouse button of a button event.
+    pub fn get_button(&self) -> Option<u32> {
+        let mut button : c_uint = 0;
+
+        if unsafe { to_bool(ffi::gdk_event_get_button(self.unwrap_pointer(), &mut button)) } {
+            Some(button)
+        } else {
+            None
+        }
+    }
+
+    /// Returns 1 for a single click, 2 for a double click and 3 for a triple click of a button
+    /// press event.
+    pub fn get_click_count(&self) -> Option<u32> {
+        let mut click_count : c_uint = 0;
+
+        if unsafe { to_bool(ffi::gdk_event_get_click_count(self.unwrap_pointer(), &mut click_count)) } {
+            Some(click_count)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the keyval of a key event.
+    pub fn get_keyval(&self) -> Option<u32> {
+        let mut keyval : c_uint = 0;
+
+        if unsafe { to_bool(ffi::gdk_event_get_keyval(self.unwrap_pointer(), &mut keyval)) } {
+            Some(keyval)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the hardware keycode of a key event.
+    pub fn get_keycode(&self) -> Option<u16> {
+        let mut keycode = 0u16;
+
+        if unsafe { to_bool(ffi::gdk_event_get_keycode(self.unwrap_pointer(), &mut keycode)) } {
+            Some(keycode)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the direction of a discrete scroll event. Smooth scroll events have no direction,
+    /// use `get_scroll_deltas` for them.
+    pub fn get_scroll_direction(&self) -> Option<gdk::ScrollDirection> {
+        let mut direction : c_int = 0;
+
+        if unsafe { to_bool(ffi::gdk_event_get_scroll_direction(self.unwrap_pointer(), &mut direction)) }
+            && direction >= 0 && direction <= gdk::ScrollDirection::ScrollSmooth as c_int {
+            Some(unsafe { mem::transmute(direction) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the deltas of a smooth scroll event.
+    pub fn get_scroll_deltas(&self) -> Option<(f64, f64)> {
+        let mut delta_x = 0.;
+        let mut delta_y = 0.;
+
+        if unsafe { to_bool(ffi::gdk_event_get_scroll_deltas(self.unwrap_pointer(), &mut delta_x, &mut delta_y)) } {
+            Some((delta_x, delta_y))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of the axis `axis_use` of a motion, button or touch event, None if the
+    /// device of the event has no such axis.
+    pub fn get_axis(&self, axis_use: gdk::AxisUse) -> Option<f64> {
+        let mut value = 0.;
+
+        if unsafe { to_bool(ffi::gdk_event_get_axis(self.unwrap_pointer(), axis_use, &mut value)) } {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the pressure of a stylus event, between 0 and 1.
+    pub fn get_pressure(&self) -> Option<f64> {
+        self.get_axis(gdk::AxisUse::Pressure)
+    }
+
+    /// Returns the tilt of a stylus event along the x and y axes, between -1 and 1.
+    pub fn get_tilt(&self) -> Option<(f64, f64)> {
+        match (self.get_axis(gdk::AxisUse::XTilt), self.get_axis(gdk::AxisUse::YTilt)) {
+            (Some(x_tilt), Some(y_tilt)) => Some((x_tilt, y_tilt)),
+            _ => None
+        }
+    }
+
+    /// Returns the touch sequence of a touch event, which identifies the touch point across the
+    /// events of a same touch.
+    pub fn get_event_sequence(&self) -> Option<EventSequence> {
+        let tmp = unsafe { ffi::gdk_event_get_event_sequence(self.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(EventSequence { pointer: tmp })
+        }
+    }
+
+    /// Returns the device the event originates from. For events with a master device, see
+    /// `get_source_device` for the physical device.
+    pub fn get_device(&self) -> Option<gdk::Device> {
+        let tmp = unsafe { ffi::gdk_event_get_device(self.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Device::wrap_pointer(tmp))
+        }
+    }
+
+    /// Returns the hardware (slave) device that triggered the event.
+    pub fn get_source_device(&self) -> Option<gdk::Device> {
+        let tmp = unsafe { ffi::gdk_event_get_source_device(self.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Device::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn get_screen(&self) -> Option<gdk::Screen> {
+        let tmp = unsafe { ffi::gdk_event_get_screen(self.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Screen::wrap_pointer(tmp))
+        }
+    }
+
+    /// Returns whether the event should trigger a context menu, according to platform
+    /// conventions.
+    pub fn triggers_context_menu(&self) -> bool {
+        unsafe { to_bool(ffi::gdk_event_triggers_context_menu(self.unwrap_pointer())) }
+    }
+
+    /// Appends a copy of the event onto the front of the event queue for its window's display.
+    pub fn put(&self) {
+        unsafe { ffi::gdk_event_put(self.unwrap_pointer()) }
+    }
+
+    /// Copies the event, the copy is owned by the caller.
+    pub fn copy(&self) -> Option<OwnedEvent> {
+        OwnedEvent::wrap_pointer(unsafe { ffi::gdk_event_copy(self.unwrap_pointer()) })
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GdkEvent {
+        self as *const Event as *mut ffi::C_GdkEvent
+    }
+}
+
+/// The typed view of an `Event`, as returned by `Event::get_kind`.
+pub enum EventKind<'a> {
+    Any(&'a EventAny),
+    Expose(&'a EventExpose),
+    Visibility(&'a EventVisibility),
+    Motion(&'a EventMotion),
+    Button(&'a EventButton),
+    Touch(&'a EventTouch),
+    Scroll(&'a EventScroll),
+    Key(&'a EventKey),
+    Crossing(&'a EventCrossing),
+    Focus(&'a EventFocus),
+    Configure(&'a EventConfigure),
+    Property(&'a EventProperty),
+    Selection(&'a EventSelection),
+    OwnerChange(&'a EventOwnerChange),
+    Proximity(&'a EventProximity),
+    Setting(&'a EventSetting),
+    WindowState(&'a EventWindowState),
+    GrabBroken(&'a EventGrabBroken),
+    DND(&'a EventDND)
+}
+
+/// An event owned by Rust, freed when dropped. It dereferences to `Event`.
+pub struct OwnedEvent {
+    pointer: *mut ffi::C_GdkEvent
+}
+
+impl OwnedEvent {
+    /// Creates a new event of the given type. All fields are set to 0.
+    pub fn new(event_type: gdk::EventType) -> Option<OwnedEvent> {
+        OwnedEvent::wrap_pointer(unsafe { ffi::gdk_event_new(event_type as c_int) })
+    }
+
+    /// Checks all open displays for an event to process, fetching events from the windowing
+    /// system if necessary. Returns None if no events are pending.
+    pub fn get() -> Option<OwnedEvent> {
+        OwnedEvent::wrap_pointer(unsafe { ffi::gdk_event_get() })
+    }
+
+    /// Returns a copy of the first event on the event queue, without removing it. Returns None
+    /// if no events are in the queue.
+    pub fn peek() -> Option<OwnedEvent> {
+        OwnedEvent::wrap_pointer(unsafe { ffi::gdk_event_peek() })
+    }
+
+    /// Takes ownership of a GdkEvent.
+    pub fn wrap_pointer(pointer: *mut ffi::C_GdkEvent) -> Option<OwnedEvent> {
+        if pointer.is_null() {
+            None
+        } else {
+            Some(OwnedEvent { pointer: pointer })
+        }
+    }
+}
+
+impl Deref for OwnedEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Event {
+        unsafe { &*(self.pointer as *const Event) }
+    }
+}
+
+impl Clone for OwnedEvent {
+    fn clone(&self) -> OwnedEvent {
+        OwnedEvent {
+            pointer: unsafe { ffi::gdk_event_copy(self.pointer) }
+        }
+    }
+}
+
+impl Drop for OwnedEvent {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::gdk_event_free(self.pointer) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
+
+/// An opaque identifier of a touch sequence, the same for all the events of a touch.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct EventSequence {
+    pointer: *mut ffi::C_GdkEventSequence
+}
+
+impl EventSequence {
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GdkEventSequence {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GdkEventSequence) -> EventSequence {
+        EventSequence {
+            pointer: pointer
+        }
+    }
+}
+
+/// Checks if any events are ready to be processed for any display.
+pub fn events_pending() -> bool {
+    unsafe { to_bool(ffi::gdk_events_pending()) }
+}
 
 
 //Supporting types
@@ -0,0 +1,2309 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Keyval constants, the equivalent of the `GDK_KEY_*` macros of gdkkeysyms.h
+//!
+//! Generated from the X11 keysymdef.h and XF86keysym.h headers. Names that are not valid Rust
+//! identifiers are prefixed with an underscore (`GDK_KEY_3270_Enter` is `_3270_Enter`).
+
+#![allow(non_upper_case_globals)]
+
+pub const VoidSymbol: u32 = 0xffffff;
+pub const BackSpace: u32 = 0xff08;
+pub const Tab: u32 = 0xff09;
+pub const Linefeed: u32 = 0xff0a;
+pub const Clear: u32 = 0xff0b;
+pub const Return: u32 = 0xff0d;
+pub const Pause: u32 = 0xff13;
+pub const Scroll_Lock: u32 = 0xff14;
+pub const Sys_Req: u32 = 0xff15;
+pub const Escape: u32 = 0xff1b;
+pub const Delete: u32 = 0xffff;
+pub const Multi_key: u32 = 0xff20;
+pub const Codeinput: u32 = 0xff37;
+pub const SingleCandidate: u32 = 0xff3c;
+pub const MultipleCandidate: u32 = 0xff3d;
+pub const PreviousCandidate: u32 = 0xff3e;
+pub const Kanji: u32 = 0xff21;
+pub const Muhenkan: u32 = 0xff22;
+pub const Henkan_Mode: u32 = 0xff23;
+pub const Henkan: u32 = 0xff23;
+pub const Romaji: u32 = 0xff24;
+pub const Hiragana: u32 = 0xff25;
+pub const Katakana: u32 = 0xff26;
+pub const Hiragana_Katakana: u32 = 0xff27;
+pub const Zenkaku: u32 = 0xff28;
+pub const Hankaku: u32 = 0xff29;
+pub const Zenkaku_Hankaku: u32 = 0xff2a;
+pub const Touroku: u32 = 0xff2b;
+pub const Massyo: u32 = 0xff2c;
+pub const Kana_Lock: u32 = 0xff2d;
+pub const Kana_Shift: u32 = 0xff2e;
+pub const Eisu_Shift: u32 = 0xff2f;
+pub const Eisu_toggle: u32 = 0xff30;
+pub const Kanji_Bangou: u32 = 0xff37;
+pub const Zen_Koho: u32 = 0xff3d;
+pub const Mae_Koho: u32 = 0xff3e;
+pub const Home: u32 = 0xff50;
+pub const Left: u32 = 0xff51;
+pub const Up: u32 = 0xff52;
+pub const Right: u32 = 0xff53;
+pub const Down: u32 = 0xff54;
+pub const Prior: u32 = 0xff55;
+pub const Page_Up: u32 = 0xff55;
+pub const Next: u32 = 0xff56;
+pub const Page_Down: u32 = 0xff56;
+pub const End: u32 = 0xff57;
+pub const Begin: u32 = 0xff58;
+pub const Select: u32 = 0xff60;
+pub const Print: u32 = 0xff61;
+pub const Execute: u32 = 0xff62;
+pub const Insert: u32 = 0xff63;
+pub const Undo: u32 = 0xff65;
+pub const Redo: u32 = 0xff66;
+pub const Menu: u32 = 0xff67;
+pub const Find: u32 = 0xff68;
+pub const Cancel: u32 = 0xff69;
+pub const Help: u32 = 0xff6a;
+pub const Break: u32 = 0xff6b;
+pub const Mode_switch: u32 = 0xff7e;
+pub const script_switch: u32 = 0xff7e;
+pub const Num_Lock: u32 = 0xff7f;
+pub const KP_Space: u32 = 0xff80;
+pub const KP_Tab: u32 = 0xff89;
+pub const KP_Enter: u32 = 0xff8d;
+pub const KP_F1: u32 = 0xff91;
+pub const KP_F2: u32 = 0xff92;
+pub const KP_F3: u32 = 0xff93;
+pub const KP_F4: u32 = 0xff94;
+pub const KP_Home: u32 = 0xff95;
+pub const KP_Left: u32 = 0xff96;
+pub const KP_Up: u32 = 0xff97;
+pub const KP_Right: u32 = 0xff98;
+pub const KP_Down: u32 = 0xff99;
+pub const KP_Prior: u32 = 0xff9a;
+pub const KP_Page_Up: u32 = 0xff9a;
+pub const KP_Next: u32 = 0xff9b;
+pub const KP_Page_Down: u32 = 0xff9b;
+pub const KP_End: u32 = 0xff9c;
+pub const KP_Begin: u32 = 0xff9d;
+pub const KP_Insert: u32 = 0xff9e;
+pub const KP_Delete: u32 = 0xff9f;
+pub const KP_Equal: u32 = 0xffbd;
+pub const KP_Multiply: u32 = 0xffaa;
+pub const KP_Add: u32 = 0xffab;
+pub const KP_Separator: u32 = 0xffac;
+pub const KP_Subtract: u32 = 0xffad;
+pub const KP_Decimal: u32 = 0xffae;
+pub const KP_Divide: u32 = 0xffaf;
+pub const KP_0: u32 = 0xffb0;
+pub const KP_1: u32 = 0xffb1;
+pub const KP_2: u32 = 0xffb2;
+pub const KP_3: u32 = 0xffb3;
+pub const KP_4: u32 = 0xffb4;
+pub const KP_5: u32 = 0xffb5;
+pub const KP_6: u32 = 0xffb6;
+pub const KP_7: u32 = 0xffb7;
+pub const KP_8: u32 = 0xffb8;
+pub const KP_9: u32 = 0xffb9;
+pub const F1: u32 = 0xffbe;
+pub const F2: u32 = 0xffbf;
+pub const F3: u32 = 0xffc0;
+pub const F4: u32 = 0xffc1;
+pub const F5: u32 = 0xffc2;
+pub const F6: u32 = 0xffc3;
+pub const F7: u32 = 0xffc4;
+pub const F8: u32 = 0xffc5;
+pub const F9: u32 = 0xffc6;
+pub const F10: u32 = 0xffc7;
+pub const F11: u32 = 0xffc8;
+pub const L1: u32 = 0xffc8;
+pub const F12: u32 = 0xffc9;
+pub const L2: u32 = 0xffc9;
+pub const F13: u32 = 0xffca;
+pub const L3: u32 = 0xffca;
+pub const F14: u32 = 0xffcb;
+pub const L4: u32 = 0xffcb;
+pub const F15: u32 = 0xffcc;
+pub const L5: u32 = 0xffcc;
+pub const F16: u32 = 0xffcd;
+pub const L6: u32 = 0xffcd;
+pub const F17: u32 = 0xffce;
+pub const L7: u32 = 0xffce;
+pub const F18: u32 = 0xffcf;
+pub const L8: u32 = 0xffcf;
+pub const F19: u32 = 0xffd0;
+pub const L9: u32 = 0xffd0;
+pub const F20: u32 = 0xffd1;
+pub const L10: u32 = 0xffd1;
+pub const F21: u32 = 0xffd2;
+pub const R1: u32 = 0xffd2;
+pub const F22: u32 = 0xffd3;
+pub const R2: u32 = 0xffd3;
+pub const F23: u32 = 0xffd4;
+pub const R3: u32 = 0xffd4;
+pub const F24: u32 = 0xffd5;
+pub const R4: u32 = 0xffd5;
+pub const F25: u32 = 0xffd6;
+pub const R5: u32 = 0xffd6;
+pub const F26: u32 = 0xffd7;
+pub const R6: u32 = 0xffd7;
+pub const F27: u32 = 0xffd8;
+pub const R7: u32 = 0xffd8;
+pub const F28: u32 = 0xffd9;
+pub const R8: u32 = 0xffd9;
+pub const F29: u32 = 0xffda;
+pub const R9: u32 = 0xffda;
+pub const F30: u32 = 0xffdb;
+pub const R10: u32 = 0xffdb;
+pub const F31: u32 = 0xffdc;
+pub const R11: u32 = 0xffdc;
+pub const F32: u32 = 0xffdd;
+pub const R12: u32 = 0xffdd;
+pub const F33: u32 = 0xffde;
+pub const R13: u32 = 0xffde;
+pub const F34: u32 = 0xffdf;
+pub const R14: u32 = 0xffdf;
+pub const F35: u32 = 0xffe0;
+pub const R15: u32 = 0xffe0;
+pub const Shift_L: u32 = 0xffe1;
+pub const Shift_R: u32 = 0xffe2;
+pub const Control_L: u32 = 0xffe3;
+pub const Control_R: u32 = 0xffe4;
+pub const Caps_Lock: u32 = 0xffe5;
+pub const Shift_Lock: u32 = 0xffe6;
+pub const Meta_L: u32 = 0xffe7;
+pub const Meta_R: u32 = 0xffe8;
+pub const Alt_L: u32 = 0xffe9;
+pub const Alt_R: u32 = 0xffea;
+pub const Super_L: u32 = 0xffeb;
+pub const Super_R: u32 = 0xffec;
+pub const Hyper_L: u32 = 0xffed;
+pub const Hyper_R: u32 = 0xffee;
+pub const ISO_Lock: u32 = 0xfe01;
+pub const ISO_Level2_Latch: u32 = 0xfe02;
+pub const ISO_Level3_Shift: u32 = 0xfe03;
+pub const ISO_Level3_Latch: u32 = 0xfe04;
+pub const ISO_Level3_Lock: u32 = 0xfe05;
+pub const ISO_Level5_Shift: u32 = 0xfe11;
+pub const ISO_Level5_Latch: u32 = 0xfe12;
+pub const ISO_Level5_Lock: u32 = 0xfe13;
+pub const ISO_Group_Shift: u32 = 0xff7e;
+pub const ISO_Group_Latch: u32 = 0xfe06;
+pub const ISO_Group_Lock: u32 = 0xfe07;
+pub const ISO_Next_Group: u32 = 0xfe08;
+pub const ISO_Next_Group_Lock: u32 = 0xfe09;
+pub const ISO_Prev_Group: u32 = 0xfe0a;
+pub const ISO_Prev_Group_Lock: u32 = 0xfe0b;
+pub const ISO_First_Group: u32 = 0xfe0c;
+pub const ISO_First_Group_Lock: u32 = 0xfe0d;
+pub const ISO_Last_Group: u32 = 0xfe0e;
+pub const ISO_Last_Group_Lock: u32 = 0xfe0f;
+pub const ISO_Left_Tab: u32 = 0xfe20;
+pub const ISO_Move_Line_Up: u32 = 0xfe21;
+pub const ISO_Move_Line_Down: u32 = 0xfe22;
+pub const ISO_Partial_Line_Up: u32 = 0xfe23;
+pub const ISO_Partial_Line_Down: u32 = 0xfe24;
+pub const ISO_Partial_Space_Left: u32 = 0xfe25;
+pub const ISO_Partial_Space_Right: u32 = 0xfe26;
+pub const ISO_Set_Margin_Left: u32 = 0xfe27;
+pub const ISO_Set_Margin_Right: u32 = 0xfe28;
+pub const ISO_Release_Margin_Left: u32 = 0xfe29;
+pub const ISO_Release_Margin_Right: u32 = 0xfe2a;
+pub const ISO_Release_Both_Margins: u32 = 0xfe2b;
+pub const ISO_Fast_Cursor_Left: u32 = 0xfe2c;
+pub const ISO_Fast_Cursor_Right: u32 = 0xfe2d;
+pub const ISO_Fast_Cursor_Up: u32 = 0xfe2e;
+pub const ISO_Fast_Cursor_Down: u32 = 0xfe2f;
+pub const ISO_Continuous_Underline: u32 = 0xfe30;
+pub const ISO_Discontinuous_Underline: u32 = 0xfe31;
+pub const ISO_Emphasize: u32 = 0xfe32;
+pub const ISO_Center_Object: u32 = 0xfe33;
+pub const ISO_Enter: u32 = 0xfe34;
+pub const dead_grave: u32 = 0xfe50;
+pub const dead_acute: u32 = 0xfe51;
+pub const dead_circumflex: u32 = 0xfe52;
+pub const dead_tilde: u32 = 0xfe53;
+pub const dead_perispomeni: u32 = 0xfe53;
+pub const dead_macron: u32 = 0xfe54;
+pub const dead_breve: u32 = 0xfe55;
+pub const dead_abovedot: u32 = 0xfe56;
+pub const dead_diaeresis: u32 = 0xfe57;
+pub const dead_abovering: u32 = 0xfe58;
+pub const dead_doubleacute: u32 = 0xfe59;
+pub const dead_caron: u32 = 0xfe5a;
+pub const dead_cedilla: u32 = 0xfe5b;
+pub const dead_ogonek: u32 = 0xfe5c;
+pub const dead_iota: u32 = 0xfe5d;
+pub const dead_voiced_sound: u32 = 0xfe5e;
+pub const dead_semivoiced_sound: u32 = 0xfe5f;
+pub const dead_belowdot: u32 = 0xfe60;
+pub const dead_hook: u32 = 0xfe61;
+pub const dead_horn: u32 = 0xfe62;
+pub const dead_stroke: u32 = 0xfe63;
+pub const dead_abovecomma: u32 = 0xfe64;
+pub const dead_psili: u32 = 0xfe64;
+pub const dead_abovereversedcomma: u32 = 0xfe65;
+pub const dead_dasia: u32 = 0xfe65;
+pub const dead_doublegrave: u32 = 0xfe66;
+pub const dead_belowring: u32 = 0xfe67;
+pub const dead_belowmacron: u32 = 0xfe68;
+pub const dead_belowcircumflex: u32 = 0xfe69;
+pub const dead_belowtilde: u32 = 0xfe6a;
+pub const dead_belowbreve: u32 = 0xfe6b;
+pub const dead_belowdiaeresis: u32 = 0xfe6c;
+pub const dead_invertedbreve: u32 = 0xfe6d;
+pub const dead_belowcomma: u32 = 0xfe6e;
+pub const dead_currency: u32 = 0xfe6f;
+pub const dead_lowline: u32 = 0xfe90;
+pub const dead_aboveverticalline: u32 = 0xfe91;
+pub const dead_belowverticalline: u32 = 0xfe92;
+pub const dead_longsolidusoverlay: u32 = 0xfe93;
+pub const dead_a: u32 = 0xfe80;
+pub const dead_A: u32 = 0xfe81;
+pub const dead_e: u32 = 0xfe82;
+pub const dead_E: u32 = 0xfe83;
+pub const dead_i: u32 = 0xfe84;
+pub const dead_I: u32 = 0xfe85;
+pub const dead_o: u32 = 0xfe86;
+pub const dead_O: u32 = 0xfe87;
+pub const dead_u: u32 = 0xfe88;
+pub const dead_U: u32 = 0xfe89;
+pub const dead_small_schwa: u32 = 0xfe8a;
+pub const dead_capital_schwa: u32 = 0xfe8b;
+pub const dead_greek: u32 = 0xfe8c;
+pub const First_Virtual_Screen: u32 = 0xfed0;
+pub const Prev_Virtual_Screen: u32 = 0xfed1;
+pub const Next_Virtual_Screen: u32 = 0xfed2;
+pub const Last_Virtual_Screen: u32 = 0xfed4;
+pub const Terminate_Server: u32 = 0xfed5;
+pub const AccessX_Enable: u32 = 0xfe70;
+pub const AccessX_Feedback_Enable: u32 = 0xfe71;
+pub const RepeatKeys_Enable: u32 = 0xfe72;
+pub const SlowKeys_Enable: u32 = 0xfe73;
+pub const BounceKeys_Enable: u32 = 0xfe74;
+pub const StickyKeys_Enable: u32 = 0xfe75;
+pub const MouseKeys_Enable: u32 = 0xfe76;
+pub const MouseKeys_Accel_Enable: u32 = 0xfe77;
+pub const Overlay1_Enable: u32 = 0xfe78;
+pub const Overlay2_Enable: u32 = 0xfe79;
+pub const AudibleBell_Enable: u32 = 0xfe7a;
+pub const Pointer_Left: u32 = 0xfee0;
+pub const Pointer_Right: u32 = 0xfee1;
+pub const Pointer_Up: u32 = 0xfee2;
+pub const Pointer_Down: u32 = 0xfee3;
+pub const Pointer_UpLeft: u32 = 0xfee4;
+pub const Pointer_UpRight: u32 = 0xfee5;
+pub const Pointer_DownLeft: u32 = 0xfee6;
+pub const Pointer_DownRight: u32 = 0xfee7;
+pub const Pointer_Button_Dflt: u32 = 0xfee8;
+pub const Pointer_Button1: u32 = 0xfee9;
+pub const Pointer_Button2: u32 = 0xfeea;
+pub const Pointer_Button3: u32 = 0xfeeb;
+pub const Pointer_Button4: u32 = 0xfeec;
+pub const Pointer_Button5: u32 = 0xfeed;
+pub const Pointer_DblClick_Dflt: u32 = 0xfeee;
+pub const Pointer_DblClick1: u32 = 0xfeef;
+pub const Pointer_DblClick2: u32 = 0xfef0;
+pub const Pointer_DblClick3: u32 = 0xfef1;
+pub const Pointer_DblClick4: u32 = 0xfef2;
+pub const Pointer_DblClick5: u32 = 0xfef3;
+pub const Pointer_Drag_Dflt: u32 = 0xfef4;
+pub const Pointer_Drag1: u32 = 0xfef5;
+pub const Pointer_Drag2: u32 = 0xfef6;
+pub const Pointer_Drag3: u32 = 0xfef7;
+pub const Pointer_Drag4: u32 = 0xfef8;
+pub const Pointer_Drag5: u32 = 0xfefd;
+pub const Pointer_EnableKeys: u32 = 0xfef9;
+pub const Pointer_Accelerate: u32 = 0xfefa;
+pub const Pointer_DfltBtnNext: u32 = 0xfefb;
+pub const Pointer_DfltBtnPrev: u32 = 0xfefc;
+pub const ch: u32 = 0xfea0;
+pub const Ch: u32 = 0xfea1;
+pub const CH: u32 = 0xfea2;
+pub const c_h: u32 = 0xfea3;
+pub const C_h: u32 = 0xfea4;
+pub const C_H: u32 = 0xfea5;
+pub const _3270_Duplicate: u32 = 0xfd01;
+pub const _3270_FieldMark: u32 = 0xfd02;
+pub const _3270_Right2: u32 = 0xfd03;
+pub const _3270_Left2: u32 = 0xfd04;
+pub const _3270_BackTab: u32 = 0xfd05;
+pub const _3270_EraseEOF: u32 = 0xfd06;
+pub const _3270_EraseInput: u32 = 0xfd07;
+pub const _3270_Reset: u32 = 0xfd08;
+pub const _3270_Quit: u32 = 0xfd09;
+pub const _3270_PA1: u32 = 0xfd0a;
+pub const _3270_PA2: u32 = 0xfd0b;
+pub const _3270_PA3: u32 = 0xfd0c;
+pub const _3270_Test: u32 = 0xfd0d;
+pub const _3270_Attn: u32 = 0xfd0e;
+pub const _3270_CursorBlink: u32 = 0xfd0f;
+pub const _3270_AltCursor: u32 = 0xfd10;
+pub const _3270_KeyClick: u32 = 0xfd11;
+pub const _3270_Jump: u32 = 0xfd12;
+pub const _3270_Ident: u32 = 0xfd13;
+pub const _3270_Rule: u32 = 0xfd14;
+pub const _3270_Copy: u32 = 0xfd15;
+pub const _3270_Play: u32 = 0xfd16;
+pub const _3270_Setup: u32 = 0xfd17;
+pub const _3270_Record: u32 = 0xfd18;
+pub const _3270_ChangeScreen: u32 = 0xfd19;
+pub const _3270_DeleteWord: u32 = 0xfd1a;
+pub const _3270_ExSelect: u32 = 0xfd1b;
+pub const _3270_CursorSelect: u32 = 0xfd1c;
+pub const _3270_PrintScreen: u32 = 0xfd1d;
+pub const _3270_Enter: u32 = 0xfd1e;
+pub const space: u32 = 0x20;
+pub const exclam: u32 = 0x21;
+pub const quotedbl: u32 = 0x22;
+pub const numbersign: u32 = 0x23;
+pub const dollar: u32 = 0x24;
+pub const percent: u32 = 0x25;
+pub const ampersand: u32 = 0x26;
+pub const apostrophe: u32 = 0x27;
+pub const quoteright: u32 = 0x27;
+pub const parenleft: u32 = 0x28;
+pub const parenright: u32 = 0x29;
+pub const asterisk: u32 = 0x2a;
+pub const plus: u32 = 0x2b;
+pub const comma: u32 = 0x2c;
+pub const minus: u32 = 0x2d;
+pub const period: u32 = 0x2e;
+pub const slash: u32 = 0x2f;
+pub const _0: u32 = 0x30;
+pub const _1: u32 = 0x31;
+pub const _2: u32 = 0x32;
+pub const _3: u32 = 0x33;
+pub const _4: u32 = 0x34;
+pub const _5: u32 = 0x35;
+pub const _6: u32 = 0x36;
+pub const _7: u32 = 0x37;
+pub const _8: u32 = 0x38;
+pub const _9: u32 = 0x39;
+pub const colon: u32 = 0x3a;
+pub const semicolon: u32 = 0x3b;
+pub const less: u32 = 0x3c;
+pub const equal: u32 = 0x3d;
+pub const greater: u32 = 0x3e;
+pub const question: u32 = 0x3f;
+pub const at: u32 = 0x40;
+pub const A: u32 = 0x41;
+pub const B: u32 = 0x42;
+pub const C: u32 = 0x43;
+pub const D: u32 = 0x44;
+pub const E: u32 = 0x45;
+pub const F: u32 = 0x46;
+pub const G: u32 = 0x47;
+pub const H: u32 = 0x48;
+pub const I: u32 = 0x49;
+pub const J: u32 = 0x4a;
+pub const K: u32 = 0x4b;
+pub const L: u32 = 0x4c;
+pub const M: u32 = 0x4d;
+pub const N: u32 = 0x4e;
+pub const O: u32 = 0x4f;
+pub const P: u32 = 0x50;
+pub const Q: u32 = 0x51;
+pub const R: u32 = 0x52;
+pub const S: u32 = 0x53;
+pub const T: u32 = 0x54;
+pub const U: u32 = 0x55;
+pub const V: u32 = 0x56;
+pub const W: u32 = 0x57;
+pub const X: u32 = 0x58;
+pub const Y: u32 = 0x59;
+pub const Z: u32 = 0x5a;
+pub const bracketleft: u32 = 0x5b;
+pub const backslash: u32 = 0x5c;
+pub const bracketright: u32 = 0x5d;
+pub const asciicircum: u32 = 0x5e;
+pub const underscore: u32 = 0x5f;
+pub const grave: u32 = 0x60;
+pub const quoteleft: u32 = 0x60;
+pub const a: u32 = 0x61;
+pub const b: u32 = 0x62;
+pub const c: u32 = 0x63;
+pub const d: u32 = 0x64;
+pub const e: u32 = 0x65;
+pub const f: u32 = 0x66;
+pub const g: u32 = 0x67;
+pub const h: u32 = 0x68;
+pub const i: u32 = 0x69;
+pub const j: u32 = 0x6a;
+pub const k: u32 = 0x6b;
+pub const l: u32 = 0x6c;
+pub const m: u32 = 0x6d;
+pub const n: u32 = 0x6e;
+pub const o: u32 = 0x6f;
+pub const p: u32 = 0x70;
+pub const q: u32 = 0x71;
+pub const r: u32 = 0x72;
+pub const s: u32 = 0x73;
+pub const t: u32 = 0x74;
+pub const u: u32 = 0x75;
+pub const v: u32 = 0x76;
+pub const w: u32 = 0x77;
+pub const x: u32 = 0x78;
+pub const y: u32 = 0x79;
+pub const z: u32 = 0x7a;
+pub const braceleft: u32 = 0x7b;
+pub const bar: u32 = 0x7c;
+pub const braceright: u32 = 0x7d;
+pub const asciitilde: u32 = 0x7e;
+pub const nobreakspace: u32 = 0xa0;
+pub const exclamdown: u32 = 0xa1;
+pub const cent: u32 = 0xa2;
+pub const sterling: u32 = 0xa3;
+pub const currency: u32 = 0xa4;
+pub const yen: u32 = 0xa5;
+pub const brokenbar: u32 = 0xa6;
+pub const section: u32 = 0xa7;
+pub const diaeresis: u32 = 0xa8;
+pub const copyright: u32 = 0xa9;
+pub const ordfeminine: u32 = 0xaa;
+pub const guillemotleft: u32 = 0xab;
+pub const notsign: u32 = 0xac;
+pub const hyphen: u32 = 0xad;
+pub const registered: u32 = 0xae;
+pub const macron: u32 = 0xaf;
+pub const degree: u32 = 0xb0;
+pub const plusminus: u32 = 0xb1;
+pub const twosuperior: u32 = 0xb2;
+pub const threesuperior: u32 = 0xb3;
+pub const acute: u32 = 0xb4;
+pub const mu: u32 = 0xb5;
+pub const paragraph: u32 = 0xb6;
+pub const periodcentered: u32 = 0xb7;
+pub const cedilla: u32 = 0xb8;
+pub const onesuperior: u32 = 0xb9;
+pub const masculine: u32 = 0xba;
+pub const guillemotright: u32 = 0xbb;
+pub const onequarter: u32 = 0xbc;
+pub const onehalf: u32 = 0xbd;
+pub const threequarters: u32 = 0xbe;
+pub const questiondown: u32 = 0xbf;
+pub const Agrave: u32 = 0xc0;
+pub const Aacute: u32 = 0xc1;
+pub const Acircumflex: u32 = 0xc2;
+pub const Atilde: u32 = 0xc3;
+pub const Adiaeresis: u32 = 0xc4;
+pub const Aring: u32 = 0xc5;
+pub const AE: u32 = 0xc6;
+pub const Ccedilla: u32 = 0xc7;
+pub const Egrave: u32 = 0xc8;
+pub const Eacute: u32 = 0xc9;
+pub const Ecircumflex: u32 = 0xca;
+pub const Ediaeresis: u32 = 0xcb;
+pub const Igrave: u32 = 0xcc;
+pub const Iacute: u32 = 0xcd;
+pub const Icircumflex: u32 = 0xce;
+pub const Idiaeresis: u32 = 0xcf;
+pub const ETH: u32 = 0xd0;
+pub const Eth: u32 = 0xd0;
+pub const Ntilde: u32 = 0xd1;
+pub const Ograve: u32 = 0xd2;
+pub const Oacute: u32 = 0xd3;
+pub const Ocircumflex: u32 = 0xd4;
+pub const Otilde: u32 = 0xd5;
+pub const Odiaeresis: u32 = 0xd6;
+pub const multiply: u32 = 0xd7;
+pub const Oslash: u32 = 0xd8;
+pub const Ooblique: u32 = 0xd8;
+pub const Ugrave: u32 = 0xd9;
+pub const Uacute: u32 = 0xda;
+pub const Ucircumflex: u32 = 0xdb;
+pub const Udiaeresis: u32 = 0xdc;
+pub const Yacute: u32 = 0xdd;
+pub const THORN: u32 = 0xde;
+pub const Thorn: u32 = 0xde;
+pub const ssharp: u32 = 0xdf;
+pub const agrave: u32 = 0xe0;
+pub const aacute: u32 = 0xe1;
+pub const acircumflex: u32 = 0xe2;
+pub const atilde: u32 = 0xe3;
+pub const adiaeresis: u32 = 0xe4;
+pub const aring: u32 = 0xe5;
+pub const ae: u32 = 0xe6;
+pub const ccedilla: u32 = 0xe7;
+pub const egrave: u32 = 0xe8;
+pub const eacute: u32 = 0xe9;
+pub const ecircumflex: u32 = 0xea;
+pub const ediaeresis: u32 = 0xeb;
+pub const igrave: u32 = 0xec;
+pub const iacute: u32 = 0xed;
+pub const icircumflex: u32 = 0xee;
+pub const idiaeresis: u32 = 0xef;
+pub const eth: u32 = 0xf0;
+pub const ntilde: u32 = 0xf1;
+pub const ograve: u32 = 0xf2;
+pub const oacute: u32 = 0xf3;
+pub const ocircumflex: u32 = 0xf4;
+pub const otilde: u32 = 0xf5;
+pub const odiaeresis: u32 = 0xf6;
+pub const division: u32 = 0xf7;
+pub const oslash: u32 = 0xf8;
+pub const ooblique: u32 = 0xf8;
+pub const ugrave: u32 = 0xf9;
+pub const uacute: u32 = 0xfa;
+pub const ucircumflex: u32 = 0xfb;
+pub const udiaeresis: u32 = 0xfc;
+pub const yacute: u32 = 0xfd;
+pub const thorn: u32 = 0xfe;
+pub const ydiaeresis: u32 = 0xff;
+pub const Aogonek: u32 = 0x1a1;
+pub const breve: u32 = 0x1a2;
+pub const Lstroke: u32 = 0x1a3;
+pub const Lcaron: u32 = 0x1a5;
+pub const Sacute: u32 = 0x1a6;
+pub const Scaron: u32 = 0x1a9;
+pub const Scedilla: u32 = 0x1aa;
+pub const Tcaron: u32 = 0x1ab;
+pub const Zacute: u32 = 0x1ac;
+pub const Zcaron: u32 = 0x1ae;
+pub const Zabovedot: u32 = 0x1af;
+pub const aogonek: u32 = 0x1b1;
+pub const ogonek: u32 = 0x1b2;
+pub const lstroke: u32 = 0x1b3;
+pub const lcaron: u32 = 0x1b5;
+pub const sacute: u32 = 0x1b6;
+pub const caron: u32 = 0x1b7;
+pub const scaron: u32 = 0x1b9;
+pub const scedilla: u32 = 0x1ba;
+pub const tcaron: u32 = 0x1bb;
+pub const zacute: u32 = 0x1bc;
+pub const doubleacute: u32 = 0x1bd;
+pub const zcaron: u32 = 0x1be;
+pub const zabovedot: u32 = 0x1bf;
+pub const Racute: u32 = 0x1c0;
+pub const Abreve: u32 = 0x1c3;
+pub const Lacute: u32 = 0x1c5;
+pub const Cacute: u32 = 0x1c6;
+pub const Ccaron: u32 = 0x1c8;
+pub const Eogonek: u32 = 0x1ca;
+pub const Ecaron: u32 = 0x1cc;
+pub const Dcaron: u32 = 0x1cf;
+pub const Dstroke: u32 = 0x1d0;
+pub const Nacute: u32 = 0x1d1;
+pub const Ncaron: u32 = 0x1d2;
+pub const Odoubleacute: u32 = 0x1d5;
+pub const Rcaron: u32 = 0x1d8;
+pub const Uring: u32 = 0x1d9;
+pub const Udoubleacute: u32 = 0x1db;
+pub const Tcedilla: u32 = 0x1de;
+pub const racute: u32 = 0x1e0;
+pub const abreve: u32 = 0x1e3;
+pub const lacute: u32 = 0x1e5;
+pub const cacute: u32 = 0x1e6;
+pub const ccaron: u32 = 0x1e8;
+pub const eogonek: u32 = 0x1ea;
+pub const ecaron: u32 = 0x1ec;
+pub const dcaron: u32 = 0x1ef;
+pub const dstroke: u32 = 0x1f0;
+pub const nacute: u32 = 0x1f1;
+pub const ncaron: u32 = 0x1f2;
+pub const odoubleacute: u32 = 0x1f5;
+pub const rcaron: u32 = 0x1f8;
+pub const uring: u32 = 0x1f9;
+pub const udoubleacute: u32 = 0x1fb;
+pub const tcedilla: u32 = 0x1fe;
+pub const abovedot: u32 = 0x1ff;
+pub const Hstroke: u32 = 0x2a1;
+pub const Hcircumflex: u32 = 0x2a6;
+pub const Iabovedot: u32 = 0x2a9;
+pub const Gbreve: u32 = 0x2ab;
+pub const Jcircumflex: u32 = 0x2ac;
+pub const hstroke: u32 = 0x2b1;
+pub const hcircumflex: u32 = 0x2b6;
+pub const idotless: u32 = 0x2b9;
+pub const gbreve: u32 = 0x2bb;
+pub const jcircumflex: u32 = 0x2bc;
+pub const Cabovedot: u32 = 0x2c5;
+pub const Ccircumflex: u32 = 0x2c6;
+pub const Gabovedot: u32 = 0x2d5;
+pub const Gcircumflex: u32 = 0x2d8;
+pub const Ubreve: u32 = 0x2dd;
+pub const Scircumflex: u32 = 0x2de;
+pub const cabovedot: u32 = 0x2e5;
+pub const ccircumflex: u32 = 0x2e6;
+pub const gabovedot: u32 = 0x2f5;
+pub const gcircumflex: u32 = 0x2f8;
+pub const ubreve: u32 = 0x2fd;
+pub const scircumflex: u32 = 0x2fe;
+pub const kra: u32 = 0x3a2;
+pub const kappa: u32 = 0x3a2;
+pub const Rcedilla: u32 = 0x3a3;
+pub const Itilde: u32 = 0x3a5;
+pub const Lcedilla: u32 = 0x3a6;
+pub const Emacron: u32 = 0x3aa;
+pub const Gcedilla: u32 = 0x3ab;
+pub const Tslash: u32 = 0x3ac;
+pub const rcedilla: u32 = 0x3b3;
+pub const itilde: u32 = 0x3b5;
+pub const lcedilla: u32 = 0x3b6;
+pub const emacron: u32 = 0x3ba;
+pub const gcedilla: u32 = 0x3bb;
+pub const tslash: u32 = 0x3bc;
+pub const ENG: u32 = 0x3bd;
+pub const eng: u32 = 0x3bf;
+pub const Amacron: u32 = 0x3c0;
+pub const Iogonek: u32 = 0x3c7;
+pub const Eabovedot: u32 = 0x3cc;
+pub const Imacron: u32 = 0x3cf;
+pub const Ncedilla: u32 = 0x3d1;
+pub const Omacron: u32 = 0x3d2;
+pub const Kcedilla: u32 = 0x3d3;
+pub const Uogonek: u32 = 0x3d9;
+pub const Utilde: u32 = 0x3dd;
+pub const Umacron: u32 = 0x3de;
+pub const amacron: u32 = 0x3e0;
+pub const iogonek: u32 = 0x3e7;
+pub const eabovedot: u32 = 0x3ec;
+pub const imacron: u32 = 0x3ef;
+pub const ncedilla: u32 = 0x3f1;
+pub const omacron: u32 = 0x3f2;
+pub const kcedilla: u32 = 0x3f3;
+pub const uogonek: u32 = 0x3f9;
+pub const utilde: u32 = 0x3fd;
+pub const umacron: u32 = 0x3fe;
+pub const Wcircumflex: u32 = 0x1000174;
+pub const wcircumflex: u32 = 0x1000175;
+pub const Ycircumflex: u32 = 0x1000176;
+pub const ycircumflex: u32 = 0x1000177;
+pub const Babovedot: u32 = 0x1001e02;
+pub const babovedot: u32 = 0x1001e03;
+pub const Dabovedot: u32 = 0x1001e0a;
+pub const dabovedot: u32 = 0x1001e0b;
+pub const Fabovedot: u32 = 0x1001e1e;
+pub const fabovedot: u32 = 0x1001e1f;
+pub const Mabovedot: u32 = 0x1001e40;
+pub const mabovedot: u32 = 0x1001e41;
+pub const Pabovedot: u32 = 0x1001e56;
+pub const pabovedot: u32 = 0x1001e57;
+pub const Sabovedot: u32 = 0x1001e60;
+pub const sabovedot: u32 = 0x1001e61;
+pub const Tabovedot: u32 = 0x1001e6a;
+pub const tabovedot: u32 = 0x1001e6b;
+pub const Wgrave: u32 = 0x1001e80;
+pub const wgrave: u32 = 0x1001e81;
+pub const Wacute: u32 = 0x1001e82;
+pub const wacute: u32 = 0x1001e83;
+pub const Wdiaeresis: u32 = 0x1001e84;
+pub const wdiaeresis: u32 = 0x1001e85;
+pub const Ygrave: u32 = 0x1001ef2;
+pub const ygrave: u32 = 0x1001ef3;
+pub const OE: u32 = 0x13bc;
+pub const oe: u32 = 0x13bd;
+pub const Ydiaeresis: u32 = 0x13be;
+pub const overline: u32 = 0x47e;
+pub const kana_fullstop: u32 = 0x4a1;
+pub const kana_openingbracket: u32 = 0x4a2;
+pub const kana_closingbracket: u32 = 0x4a3;
+pub const kana_comma: u32 = 0x4a4;
+pub const kana_conjunctive: u32 = 0x4a5;
+pub const kana_middledot: u32 = 0x4a5;
+pub const kana_WO: u32 = 0x4a6;
+pub const kana_a: u32 = 0x4a7;
+pub const kana_i: u32 = 0x4a8;
+pub const kana_u: u32 = 0x4a9;
+pub const kana_e: u32 = 0x4aa;
+pub const kana_o: u32 = 0x4ab;
+pub const kana_ya: u32 = 0x4ac;
+pub const kana_yu: u32 = 0x4ad;
+pub const kana_yo: u32 = 0x4ae;
+pub const kana_tsu: u32 = 0x4af;
+pub const kana_tu: u32 = 0x4af;
+pub const prolongedsound: u32 = 0x4b0;
+pub const kana_A: u32 = 0x4b1;
+pub const kana_I: u32 = 0x4b2;
+pub const kana_U: u32 = 0x4b3;
+pub const kana_E: u32 = 0x4b4;
+pub const kana_O: u32 = 0x4b5;
+pub const kana_KA: u32 = 0x4b6;
+pub const kana_KI: u32 = 0x4b7;
+pub const kana_KU: u32 = 0x4b8;
+pub const kana_KE: u32 = 0x4b9;
+pub const kana_KO: u32 = 0x4ba;
+pub const kana_SA: u32 = 0x4bb;
+pub const kana_SHI: u32 = 0x4bc;
+pub const kana_SU: u32 = 0x4bd;
+pub const kana_SE: u32 = 0x4be;
+pub const kana_SO: u32 = 0x4bf;
+pub const kana_TA: u32 = 0x4c0;
+pub const kana_CHI: u32 = 0x4c1;
+pub const kana_TI: u32 = 0x4c1;
+pub const kana_TSU: u32 = 0x4c2;
+pub const kana_TU: u32 = 0x4c2;
+pub const kana_TE: u32 = 0x4c3;
+pub const kana_TO: u32 = 0x4c4;
+pub const kana_NA: u32 = 0x4c5;
+pub const kana_NI: u32 = 0x4c6;
+pub const kana_NU: u32 = 0x4c7;
+pub const kana_NE: u32 = 0x4c8;
+pub const kana_NO: u32 = 0x4c9;
+pub const kana_HA: u32 = 0x4ca;
+pub const kana_HI: u32 = 0x4cb;
+pub const kana_FU: u32 = 0x4cc;
+pub const kana_HU: u32 = 0x4cc;
+pub const kana_HE: u32 = 0x4cd;
+pub const kana_HO: u32 = 0x4ce;
+pub const kana_MA: u32 = 0x4cf;
+pub const kana_MI: u32 = 0x4d0;
+pub const kana_MU: u32 = 0x4d1;
+pub const kana_ME: u32 = 0x4d2;
+pub const kana_MO: u32 = 0x4d3;
+pub const kana_YA: u32 = 0x4d4;
+pub const kana_YU: u32 = 0x4d5;
+pub const kana_YO: u32 = 0x4d6;
+pub const kana_RA: u32 = 0x4d7;
+pub const kana_RI: u32 = 0x4d8;
+pub const kana_RU: u32 = 0x4d9;
+pub const kana_RE: u32 = 0x4da;
+pub const kana_RO: u32 = 0x4db;
+pub const kana_WA: u32 = 0x4dc;
+pub const kana_N: u32 = 0x4dd;
+pub const voicedsound: u32 = 0x4de;
+pub const semivoicedsound: u32 = 0x4df;
+pub const kana_switch: u32 = 0xff7e;
+pub const Farsi_0: u32 = 0x10006f0;
+pub const Farsi_1: u32 = 0x10006f1;
+pub const Farsi_2: u32 = 0x10006f2;
+pub const Farsi_3: u32 = 0x10006f3;
+pub const Farsi_4: u32 = 0x10006f4;
+pub const Farsi_5: u32 = 0x10006f5;
+pub const Farsi_6: u32 = 0x10006f6;
+pub const Farsi_7: u32 = 0x10006f7;
+pub const Farsi_8: u32 = 0x10006f8;
+pub const Farsi_9: u32 = 0x10006f9;
+pub const Arabic_percent: u32 = 0x100066a;
+pub const Arabic_superscript_alef: u32 = 0x1000670;
+pub const Arabic_tteh: u32 = 0x1000679;
+pub const Arabic_peh: u32 = 0x100067e;
+pub const Arabic_tcheh: u32 = 0x1000686;
+pub const Arabic_ddal: u32 = 0x1000688;
+pub const Arabic_rreh: u32 = 0x1000691;
+pub const Arabic_comma: u32 = 0x5ac;
+pub const Arabic_fullstop: u32 = 0x10006d4;
+pub const Arabic_0: u32 = 0x1000660;
+pub const Arabic_1: u32 = 0x1000661;
+pub const Arabic_2: u32 = 0x1000662;
+pub const Arabic_3: u32 = 0x1000663;
+pub const Arabic_4: u32 = 0x1000664;
+pub const Arabic_5: u32 = 0x1000665;
+pub const Arabic_6: u32 = 0x1000666;
+pub const Arabic_7: u32 = 0x1000667;
+pub const Arabic_8: u32 = 0x1000668;
+pub const Arabic_9: u32 = 0x1000669;
+pub const Arabic_semicolon: u32 = 0x5bb;
+pub const Arabic_question_mark: u32 = 0x5bf;
+pub const Arabic_hamza: u32 = 0x5c1;
+pub const Arabic_maddaonalef: u32 = 0x5c2;
+pub const Arabic_hamzaonalef: u32 = 0x5c3;
+pub const Arabic_hamzaonwaw: u32 = 0x5c4;
+pub const Arabic_hamzaunderalef: u32 = 0x5c5;
+pub const Arabic_hamzaonyeh: u32 = 0x5c6;
+pub const Arabic_alef: u32 = 0x5c7;
+pub const Arabic_beh: u32 = 0x5c8;
+pub const Arabic_tehmarbuta: u32 = 0x5c9;
+pub const Arabic_teh: u32 = 0x5ca;
+pub const Arabic_theh: u32 = 0x5cb;
+pub const Arabic_jeem: u32 = 0x5cc;
+pub const Arabic_hah: u32 = 0x5cd;
+pub const Arabic_khah: u32 = 0x5ce;
+pub const Arabic_dal: u32 = 0x5cf;
+pub const Arabic_thal: u32 = 0x5d0;
+pub const Arabic_ra: u32 = 0x5d1;
+pub const Arabic_zain: u32 = 0x5d2;
+pub const Arabic_seen: u32 = 0x5d3;
+pub const Arabic_sheen: u32 = 0x5d4;
+pub const Arabic_sad: u32 = 0x5d5;
+pub const Arabic_dad: u32 = 0x5d6;
+pub const Arabic_tah: u32 = 0x5d7;
+pub const Arabic_zah: u32 = 0x5d8;
+pub const Arabic_ain: u32 = 0x5d9;
+pub const Arabic_ghain: u32 = 0x5da;
+pub const Arabic_tatweel: u32 = 0x5e0;
+pub const Arabic_feh: u32 = 0x5e1;
+pub const Arabic_qaf: u32 = 0x5e2;
+pub const Arabic_kaf: u32 = 0x5e3;
+pub const Arabic_lam: u32 = 0x5e4;
+pub const Arabic_meem: u32 = 0x5e5;
+pub const Arabic_noon: u32 = 0x5e6;
+pub const Arabic_ha: u32 = 0x5e7;
+pub const Arabic_heh: u32 = 0x5e7;
+pub const Arabic_waw: u32 = 0x5e8;
+pub const Arabic_alefmaksura: u32 = 0x5e9;
+pub const Arabic_yeh: u32 = 0x5ea;
+pub const Arabic_fathatan: u32 = 0x5eb;
+pub const Arabic_dammatan: u32 = 0x5ec;
+pub const Arabic_kasratan: u32 = 0x5ed;
+pub const Arabic_fatha: u32 = 0x5ee;
+pub const Arabic_damma: u32 = 0x5ef;
+pub const Arabic_kasra: u32 = 0x5f0;
+pub const Arabic_shadda: u32 = 0x5f1;
+pub const Arabic_sukun: u32 = 0x5f2;
+pub const Arabic_madda_above: u32 = 0x1000653;
+pub const Arabic_hamza_above: u32 = 0x1000654;
+pub const Arabic_hamza_below: u32 = 0x1000655;
+pub const Arabic_jeh: u32 = 0x1000698;
+pub const Arabic_veh: u32 = 0x10006a4;
+pub const Arabic_keheh: u32 = 0x10006a9;
+pub const Arabic_gaf: u32 = 0x10006af;
+pub const Arabic_noon_ghunna: u32 = 0x10006ba;
+pub const Arabic_heh_doachashmee: u32 = 0x10006be;
+pub const Farsi_yeh: u32 = 0x10006cc;
+pub const Arabic_farsi_yeh: u32 = 0x10006cc;
+pub const Arabic_yeh_baree: u32 = 0x10006d2;
+pub const Arabic_heh_goal: u32 = 0x10006c1;
+pub const Arabic_switch: u32 = 0xff7e;
+pub const Cyrillic_GHE_bar: u32 = 0x1000492;
+pub const Cyrillic_ghe_bar: u32 = 0x1000493;
+pub const Cyrillic_ZHE_descender: u32 = 0x1000496;
+pub const Cyrillic_zhe_descender: u32 = 0x1000497;
+pub const Cyrillic_KA_descender: u32 = 0x100049a;
+pub const Cyrillic_ka_descender: u32 = 0x100049b;
+pub const Cyrillic_KA_vertstroke: u32 = 0x100049c;
+pub const Cyrillic_ka_vertstroke: u32 = 0x100049d;
+pub const Cyrillic_EN_descender: u32 = 0x10004a2;
+pub const Cyrillic_en_descender: u32 = 0x10004a3;
+pub const Cyrillic_U_straight: u32 = 0x10004ae;
+pub const Cyrillic_u_straight: u32 = 0x10004af;
+pub const Cyrillic_U_straight_bar: u32 = 0x10004b0;
+pub const Cyrillic_u_straight_bar: u32 = 0x10004b1;
+pub const Cyrillic_HA_descender: u32 = 0x10004b2;
+pub const Cyrillic_ha_descender: u32 = 0x10004b3;
+pub const Cyrillic_CHE_descender: u32 = 0x10004b6;
+pub const Cyrillic_che_descender: u32 = 0x10004b7;
+pub const Cyrillic_CHE_vertstroke: u32 = 0x10004b8;
+pub const Cyrillic_che_vertstroke: u32 = 0x10004b9;
+pub const Cyrillic_SHHA: u32 = 0x10004ba;
+pub const Cyrillic_shha: u32 = 0x10004bb;
+pub const Cyrillic_SCHWA: u32 = 0x10004d8;
+pub const Cyrillic_schwa: u32 = 0x10004d9;
+pub const Cyrillic_I_macron: u32 = 0x10004e2;
+pub const Cyrillic_i_macron: u32 = 0x10004e3;
+pub const Cyrillic_O_bar: u32 = 0x10004e8;
+pub const Cyrillic_o_bar: u32 = 0x10004e9;
+pub const Cyrillic_U_macron: u32 = 0x10004ee;
+pub const Cyrillic_u_macron: u32 = 0x10004ef;
+pub const Serbian_dje: u32 = 0x6a1;
+pub const Macedonia_gje: u32 = 0x6a2;
+pub const Cyrillic_io: u32 = 0x6a3;
+pub const Ukrainian_ie: u32 = 0x6a4;
+pub const Ukranian_je: u32 = 0x6a4;
+pub const Macedonia_dse: u32 = 0x6a5;
+pub const Ukrainian_i: u32 = 0x6a6;
+pub const Ukranian_i: u32 = 0x6a6;
+pub const Ukrainian_yi: u32 = 0x6a7;
+pub const Ukranian_yi: u32 = 0x6a7;
+pub const Cyrillic_je: u32 = 0x6a8;
+pub const Serbian_je: u32 = 0x6a8;
+pub const Cyrillic_lje: u32 = 0x6a9;
+pub const Serbian_lje: u32 = 0x6a9;
+pub const Cyrillic_nje: u32 = 0x6aa;
+pub const Serbian_nje: u32 = 0x6aa;
+pub const Serbian_tshe: u32 = 0x6ab;
+pub const Macedonia_kje: u32 = 0x6ac;
+pub const Ukrainian_ghe_with_upturn: u32 = 0x6ad;
+pub const Byelorussian_shortu: u32 = 0x6ae;
+pub const Cyrillic_dzhe: u32 = 0x6af;
+pub const Serbian_dze: u32 = 0x6af;
+pub const numerosign: u32 = 0x6b0;
+pub const Serbian_DJE: u32 = 0x6b1;
+pub const Macedonia_GJE: u32 = 0x6b2;
+pub const Cyrillic_IO: u32 = 0x6b3;
+pub const Ukrainian_IE: u32 = 0x6b4;
+pub const Ukranian_JE: u32 = 0x6b4;
+pub const Macedonia_DSE: u32 = 0x6b5;
+pub const Ukrainian_I: u32 = 0x6b6;
+pub const Ukranian_I: u32 = 0x6b6;
+pub const Ukrainian_YI: u32 = 0x6b7;
+pub const Ukranian_YI: u32 = 0x6b7;
+pub const Cyrillic_JE: u32 = 0x6b8;
+pub const Serbian_JE: u32 = 0x6b8;
+pub const Cyrillic_LJE: u32 = 0x6b9;
+pub const Serbian_LJE: u32 = 0x6b9;
+pub const Cyrillic_NJE: u32 = 0x6ba;
+pub const Serbian_NJE: u32 = 0x6ba;
+pub const Serbian_TSHE: u32 = 0x6bb;
+pub const Macedonia_KJE: u32 = 0x6bc;
+pub const Ukrainian_GHE_WITH_UPTURN: u32 = 0x6bd;
+pub const Byelorussian_SHORTU: u32 = 0x6be;
+pub const Cyrillic_DZHE: u32 = 0x6bf;
+pub const Serbian_DZE: u32 = 0x6bf;
+pub const Cyrillic_yu: u32 = 0x6c0;
+pub const Cyrillic_a: u32 = 0x6c1;
+pub const Cyrillic_be: u32 = 0x6c2;
+pub const Cyrillic_tse: u32 = 0x6c3;
+pub const Cyrillic_de: u32 = 0x6c4;
+pub const Cyrillic_ie: u32 = 0x6c5;
+pub const Cyrillic_ef: u32 = 0x6c6;
+pub const Cyrillic_ghe: u32 = 0x6c7;
+pub const Cyrillic_ha: u32 = 0x6c8;
+pub const Cyrillic_i: u32 = 0x6c9;
+pub const Cyrillic_shorti: u32 = 0x6ca;
+pub const Cyrillic_ka: u32 = 0x6cb;
+pub const Cyrillic_el: u32 = 0x6cc;
+pub const Cyrillic_em: u32 = 0x6cd;
+pub const Cyrillic_en: u32 = 0x6ce;
+pub const Cyrillic_o: u32 = 0x6cf;
+pub const Cyrillic_pe: u32 = 0x6d0;
+pub const Cyrillic_ya: u32 = 0x6d1;
+pub const Cyrillic_er: u32 = 0x6d2;
+pub const Cyrillic_es: u32 = 0x6d3;
+pub const Cyrillic_te: u32 = 0x6d4;
+pub const Cyrillic_u: u32 = 0x6d5;
+pub const Cyrillic_zhe: u32 = 0x6d6;
+pub const Cyrillic_ve: u32 = 0x6d7;
+pub const Cyrillic_softsign: u32 = 0x6d8;
+pub const Cyrillic_yeru: u32 = 0x6d9;
+pub const Cyrillic_ze: u32 = 0x6da;
+pub const Cyrillic_sha: u32 = 0x6db;
+pub const Cyrillic_e: u32 = 0x6dc;
+pub const Cyrillic_shcha: u32 = 0x6dd;
+pub const Cyrillic_che: u32 = 0x6de;
+pub const Cyrillic_hardsign: u32 = 0x6df;
+pub const Cyrillic_YU: u32 = 0x6e0;
+pub const Cyrillic_A: u32 = 0x6e1;
+pub const Cyrillic_BE: u32 = 0x6e2;
+pub const Cyrillic_TSE: u32 = 0x6e3;
+pub const Cyrillic_DE: u32 = 0x6e4;
+pub const Cyrillic_IE: u32 = 0x6e5;
+pub const Cyrillic_EF: u32 = 0x6e6;
+pub const Cyrillic_GHE: u32 = 0x6e7;
+pub const Cyrillic_HA: u32 = 0x6e8;
+pub const Cyrillic_I: u32 = 0x6e9;
+pub const Cyrillic_SHORTI: u32 = 0x6ea;
+pub const Cyrillic_KA: u32 = 0x6eb;
+pub const Cyrillic_EL: u32 = 0x6ec;
+pub const Cyrillic_EM: u32 = 0x6ed;
+pub const Cyrillic_EN: u32 = 0x6ee;
+pub const Cyrillic_O: u32 = 0x6ef;
+pub const Cyrillic_PE: u32 = 0x6f0;
+pub const Cyrillic_YA: u32 = 0x6f1;
+pub const Cyrillic_ER: u32 = 0x6f2;
+pub const Cyrillic_ES: u32 = 0x6f3;
+pub const Cyrillic_TE: u32 = 0x6f4;
+pub const Cyrillic_U: u32 = 0x6f5;
+pub const Cyrillic_ZHE: u32 = 0x6f6;
+pub const Cyrillic_VE: u32 = 0x6f7;
+pub const Cyrillic_SOFTSIGN: u32 = 0x6f8;
+pub const Cyrillic_YERU: u32 = 0x6f9;
+pub const Cyrillic_ZE: u32 = 0x6fa;
+pub const Cyrillic_SHA: u32 = 0x6fb;
+pub const Cyrillic_E: u32 = 0x6fc;
+pub const Cyrillic_SHCHA: u32 = 0x6fd;
+pub const Cyrillic_CHE: u32 = 0x6fe;
+pub const Cyrillic_HARDSIGN: u32 = 0x6ff;
+pub const Greek_ALPHAaccent: u32 = 0x7a1;
+pub const Greek_EPSILONaccent: u32 = 0x7a2;
+pub const Greek_ETAaccent: u32 = 0x7a3;
+pub const Greek_IOTAaccent: u32 = 0x7a4;
+pub const Greek_IOTAdieresis: u32 = 0x7a5;
+pub const Greek_IOTAdiaeresis: u32 = 0x7a5;
+pub const Greek_OMICRONaccent: u32 = 0x7a7;
+pub const Greek_UPSILONaccent: u32 = 0x7a8;
+pub const Greek_UPSILONdieresis: u32 = 0x7a9;
+pub const Greek_OMEGAaccent: u32 = 0x7ab;
+pub const Greek_accentdieresis: u32 = 0x7ae;
+pub const Greek_horizbar: u32 = 0x7af;
+pub const Greek_alphaaccent: u32 = 0x7b1;
+pub const Greek_epsilonaccent: u32 = 0x7b2;
+pub const Greek_etaaccent: u32 = 0x7b3;
+pub const Greek_iotaaccent: u32 = 0x7b4;
+pub const Greek_iotadieresis: u32 = 0x7b5;
+pub const Greek_iotaaccentdieresis: u32 = 0x7b6;
+pub const Greek_omicronaccent: u32 = 0x7b7;
+pub const Greek_upsilonaccent: u32 = 0x7b8;
+pub const Greek_upsilondieresis: u32 = 0x7b9;
+pub const Greek_upsilonaccentdieresis: u32 = 0x7ba;
+pub const Greek_omegaaccent: u32 = 0x7bb;
+pub const Greek_ALPHA: u32 = 0x7c1;
+pub const Greek_BETA: u32 = 0x7c2;
+pub const Greek_GAMMA: u32 = 0x7c3;
+pub const Greek_DELTA: u32 = 0x7c4;
+pub const Greek_EPSILON: u32 = 0x7c5;
+pub const Greek_ZETA: u32 = 0x7c6;
+pub const Greek_ETA: u32 = 0x7c7;
+pub const Greek_THETA: u32 = 0x7c8;
+pub const Greek_IOTA: u32 = 0x7c9;
+pub const Greek_KAPPA: u32 = 0x7ca;
+pub const Greek_LAMDA: u32 = 0x7cb;
+pub const Greek_LAMBDA: u32 = 0x7cb;
+pub const Greek_MU: u32 = 0x7cc;
+pub const Greek_NU: u32 = 0x7cd;
+pub const Greek_XI: u32 = 0x7ce;
+pub const Greek_OMICRON: u32 = 0x7cf;
+pub const Greek_PI: u32 = 0x7d0;
+pub const Greek_RHO: u32 = 0x7d1;
+pub const Greek_SIGMA: u32 = 0x7d2;
+pub const Greek_TAU: u32 = 0x7d4;
+pub const Greek_UPSILON: u32 = 0x7d5;
+pub const Greek_PHI: u32 = 0x7d6;
+pub const Greek_CHI: u32 = 0x7d7;
+pub const Greek_PSI: u32 = 0x7d8;
+pub const Greek_OMEGA: u32 = 0x7d9;
+pub const Greek_alpha: u32 = 0x7e1;
+pub const Greek_beta: u32 = 0x7e2;
+pub const Greek_gamma: u32 = 0x7e3;
+pub const Greek_delta: u32 = 0x7e4;
+pub const Greek_epsilon: u32 = 0x7e5;
+pub const Greek_zeta: u32 = 0x7e6;
+pub const Greek_eta: u32 = 0x7e7;
+pub const Greek_theta: u32 = 0x7e8;
+pub const Greek_iota: u32 = 0x7e9;
+pub const Greek_kappa: u32 = 0x7ea;
+pub const Greek_lamda: u32 = 0x7eb;
+pub const Greek_lambda: u32 = 0x7eb;
+pub const Greek_mu: u32 = 0x7ec;
+pub const Greek_nu: u32 = 0x7ed;
+pub const Greek_xi: u32 = 0x7ee;
+pub const Greek_omicron: u32 = 0x7ef;
+pub const Greek_pi: u32 = 0x7f0;
+pub const Greek_rho: u32 = 0x7f1;
+pub const Greek_sigma: u32 = 0x7f2;
+pub const Greek_finalsmallsigma: u32 = 0x7f3;
+pub const Greek_tau: u32 = 0x7f4;
+pub const Greek_upsilon: u32 = 0x7f5;
+pub const Greek_phi: u32 = 0x7f6;
+pub const Greek_chi: u32 = 0x7f7;
+pub const Greek_psi: u32 = 0x7f8;
+pub const Greek_omega: u32 = 0x7f9;
+pub const Greek_switch: u32 = 0xff7e;
+pub const leftradical: u32 = 0x8a1;
+pub const topleftradical: u32 = 0x8a2;
+pub const horizconnector: u32 = 0x8a3;
+pub const topintegral: u32 = 0x8a4;
+pub const botintegral: u32 = 0x8a5;
+pub const vertconnector: u32 = 0x8a6;
+pub const topleftsqbracket: u32 = 0x8a7;
+pub const botleftsqbracket: u32 = 0x8a8;
+pub const toprightsqbracket: u32 = 0x8a9;
+pub const botrightsqbracket: u32 = 0x8aa;
+pub const topleftparens: u32 = 0x8ab;
+pub const botleftparens: u32 = 0x8ac;
+pub const toprightparens: u32 = 0x8ad;
+pub const botrightparens: u32 = 0x8ae;
+pub const leftmiddlecurlybrace: u32 = 0x8af;
+pub const rightmiddlecurlybrace: u32 = 0x8b0;
+pub const topleftsummation: u32 = 0x8b1;
+pub const botleftsummation: u32 = 0x8b2;
+pub const topvertsummationconnector: u32 = 0x8b3;
+pub const botvertsummationconnector: u32 = 0x8b4;
+pub const toprightsummation: u32 = 0x8b5;
+pub const botrightsummation: u32 = 0x8b6;
+pub const rightmiddlesummation: u32 = 0x8b7;
+pub const lessthanequal: u32 = 0x8bc;
+pub const notequal: u32 = 0x8bd;
+pub const greaterthanequal: u32 = 0x8be;
+pub const integral: u32 = 0x8bf;
+pub const therefore: u32 = 0x8c0;
+pub const variation: u32 = 0x8c1;
+pub const infinity: u32 = 0x8c2;
+pub const nabla: u32 = 0x8c5;
+pub const approximate: u32 = 0x8c8;
+pub const similarequal: u32 = 0x8c9;
+pub const ifonlyif: u32 = 0x8cd;
+pub const implies: u32 = 0x8ce;
+pub const identical: u32 = 0x8cf;
+pub const radical: u32 = 0x8d6;
+pub const includedin: u32 = 0x8da;
+pub const includes: u32 = 0x8db;
+pub const intersection: u32 = 0x8dc;
+pub const union: u32 = 0x8dd;
+pub const logicaland: u32 = 0x8de;
+pub const logicalor: u32 = 0x8df;
+pub const partialderivative: u32 = 0x8ef;
+pub const function: u32 = 0x8f6;
+pub const leftarrow: u32 = 0x8fb;
+pub const uparrow: u32 = 0x8fc;
+pub const rightarrow: u32 = 0x8fd;
+pub const downarrow: u32 = 0x8fe;
+pub const blank: u32 = 0x9df;
+pub const soliddiamond: u32 = 0x9e0;
+pub const checkerboard: u32 = 0x9e1;
+pub const ht: u32 = 0x9e2;
+pub const ff: u32 = 0x9e3;
+pub const cr: u32 = 0x9e4;
+pub const lf: u32 = 0x9e5;
+pub const nl: u32 = 0x9e8;
+pub const vt: u32 = 0x9e9;
+pub const lowrightcorner: u32 = 0x9ea;
+pub const uprightcorner: u32 = 0x9eb;
+pub const upleftcorner: u32 = 0x9ec;
+pub const lowleftcorner: u32 = 0x9ed;
+pub const crossinglines: u32 = 0x9ee;
+pub const horizlinescan1: u32 = 0x9ef;
+pub const horizlinescan3: u32 = 0x9f0;
+pub const horizlinescan5: u32 = 0x9f1;
+pub const horizlinescan7: u32 = 0x9f2;
+pub const horizlinescan9: u32 = 0x9f3;
+pub const leftt: u32 = 0x9f4;
+pub const rightt: u32 = 0x9f5;
+pub const bott: u32 = 0x9f6;
+pub const topt: u32 = 0x9f7;
+pub const vertbar: u32 = 0x9f8;
+pub const emspace: u32 = 0xaa1;
+pub const enspace: u32 = 0xaa2;
+pub const em3space: u32 = 0xaa3;
+pub const em4space: u32 = 0xaa4;
+pub const digitspace: u32 = 0xaa5;
+pub const punctspace: u32 = 0xaa6;
+pub const thinspace: u32 = 0xaa7;
+pub const hairspace: u32 = 0xaa8;
+pub const emdash: u32 = 0xaa9;
+pub const endash: u32 = 0xaaa;
+pub const signifblank: u32 = 0xaac;
+pub const ellipsis: u32 = 0xaae;
+pub const doubbaselinedot: u32 = 0xaaf;
+pub const onethird: u32 = 0xab0;
+pub const twothirds: u32 = 0xab1;
+pub const onefifth: u32 = 0xab2;
+pub const twofifths: u32 = 0xab3;
+pub const threefifths: u32 = 0xab4;
+pub const fourfifths: u32 = 0xab5;
+pub const onesixth: u32 = 0xab6;
+pub const fivesixths: u32 = 0xab7;
+pub const careof: u32 = 0xab8;
+pub const figdash: u32 = 0xabb;
+pub const leftanglebracket: u32 = 0xabc;
+pub const decimalpoint: u32 = 0xabd;
+pub const rightanglebracket: u32 = 0xabe;
+pub const marker: u32 = 0xabf;
+pub const oneeighth: u32 = 0xac3;
+pub const threeeighths: u32 = 0xac4;
+pub const fiveeighths: u32 = 0xac5;
+pub const seveneighths: u32 = 0xac6;
+pub const trademark: u32 = 0xac9;
+pub const signaturemark: u32 = 0xaca;
+pub const trademarkincircle: u32 = 0xacb;
+pub const leftopentriangle: u32 = 0xacc;
+pub const rightopentriangle: u32 = 0xacd;
+pub const emopencircle: u32 = 0xace;
+pub const emopenrectangle: u32 = 0xacf;
+pub const leftsinglequotemark: u32 = 0xad0;
+pub const rightsinglequotemark: u32 = 0xad1;
+pub const leftdoublequotemark: u32 = 0xad2;
+pub const rightdoublequotemark: u32 = 0xad3;
+pub const prescription: u32 = 0xad4;
+pub const permille: u32 = 0xad5;
+pub const minutes: u32 = 0xad6;
+pub const seconds: u32 = 0xad7;
+pub const latincross: u32 = 0xad9;
+pub const hexagram: u32 = 0xada;
+pub const filledrectbullet: u32 = 0xadb;
+pub const filledlefttribullet: u32 = 0xadc;
+pub const filledrighttribullet: u32 = 0xadd;
+pub const emfilledcircle: u32 = 0xade;
+pub const emfilledrect: u32 = 0xadf;
+pub const enopencircbullet: u32 = 0xae0;
+pub const enopensquarebullet: u32 = 0xae1;
+pub const openrectbullet: u32 = 0xae2;
+pub const opentribulletup: u32 = 0xae3;
+pub const opentribulletdown: u32 = 0xae4;
+pub const openstar: u32 = 0xae5;
+pub const enfilledcircbullet: u32 = 0xae6;
+pub const enfilledsqbullet: u32 = 0xae7;
+pub const filledtribulletup: u32 = 0xae8;
+pub const filledtribulletdown: u32 = 0xae9;
+pub const leftpointer: u32 = 0xaea;
+pub const rightpointer: u32 = 0xaeb;
+pub const club: u32 = 0xaec;
+pub const diamond: u32 = 0xaed;
+pub const heart: u32 = 0xaee;
+pub const maltesecross: u32 = 0xaf0;
+pub const dagger: u32 = 0xaf1;
+pub const doubledagger: u32 = 0xaf2;
+pub const checkmark: u32 = 0xaf3;
+pub const ballotcross: u32 = 0xaf4;
+pub const musicalsharp: u32 = 0xaf5;
+pub const musicalflat: u32 = 0xaf6;
+pub const malesymbol: u32 = 0xaf7;
+pub const femalesymbol: u32 = 0xaf8;
+pub const telephone: u32 = 0xaf9;
+pub const telephonerecorder: u32 = 0xafa;
+pub const phonographcopyright: u32 = 0xafb;
+pub const caret: u32 = 0xafc;
+pub const singlelowquotemark: u32 = 0xafd;
+pub const doublelowquotemark: u32 = 0xafe;
+pub const cursor: u32 = 0xaff;
+pub const leftcaret: u32 = 0xba3;
+pub const rightcaret: u32 = 0xba6;
+pub const downcaret: u32 = 0xba8;
+pub const upcaret: u32 = 0xba9;
+pub const overbar: u32 = 0xbc0;
+pub const downtack: u32 = 0xbc2;
+pub const upshoe: u32 = 0xbc3;
+pub const downstile: u32 = 0xbc4;
+pub const underbar: u32 = 0xbc6;
+pub const jot: u32 = 0xbca;
+pub const quad: u32 = 0xbcc;
+pub const uptack: u32 = 0xbce;
+pub const circle: u32 = 0xbcf;
+pub const upstile: u32 = 0xbd3;
+pub const downshoe: u32 = 0xbd6;
+pub const rightshoe: u32 = 0xbd8;
+pub const leftshoe: u32 = 0xbda;
+pub const lefttack: u32 = 0xbdc;
+pub const righttack: u32 = 0xbfc;
+pub const hebrew_doublelowline: u32 = 0xcdf;
+pub const hebrew_aleph: u32 = 0xce0;
+pub const hebrew_bet: u32 = 0xce1;
+pub const hebrew_beth: u32 = 0xce1;
+pub const hebrew_gimel: u32 = 0xce2;
+pub const hebrew_gimmel: u32 = 0xce2;
+pub const hebrew_dalet: u32 = 0xce3;
+pub const hebrew_daleth: u32 = 0xce3;
+pub const hebrew_he: u32 = 0xce4;
+pub const hebrew_waw: u32 = 0xce5;
+pub const hebrew_zain: u32 = 0xce6;
+pub const hebrew_zayin: u32 = 0xce6;
+pub const hebrew_chet: u32 = 0xce7;
+pub const hebrew_het: u32 = 0xce7;
+pub const hebrew_tet: u32 = 0xce8;
+pub const hebrew_teth: u32 = 0xce8;
+pub const hebrew_yod: u32 = 0xce9;
+pub const hebrew_finalkaph: u32 = 0xcea;
+pub const hebrew_kaph: u32 = 0xceb;
+pub const hebrew_lamed: u32 = 0xcec;
+pub const hebrew_finalmem: u32 = 0xced;
+pub const hebrew_mem: u32 = 0xcee;
+pub const hebrew_finalnun: u32 = 0xcef;
+pub const hebrew_nun: u32 = 0xcf0;
+pub const hebrew_samech: u32 = 0xcf1;
+pub const hebrew_samekh: u32 = 0xcf1;
+pub const hebrew_ayin: u32 = 0xcf2;
+pub const hebrew_finalpe: u32 = 0xcf3;
+pub const hebrew_pe: u32 = 0xcf4;
+pub const hebrew_finalzade: u32 = 0xcf5;
+pub const hebrew_finalzadi: u32 = 0xcf5;
+pub const hebrew_zade: u32 = 0xcf6;
+pub const hebrew_zadi: u32 = 0xcf6;
+pub const hebrew_qoph: u32 = 0xcf7;
+pub const hebrew_kuf: u32 = 0xcf7;
+pub const hebrew_resh: u32 = 0xcf8;
+pub const hebrew_shin: u32 = 0xcf9;
+pub const hebrew_taw: u32 = 0xcfa;
+pub const hebrew_taf: u32 = 0xcfa;
+pub const Hebrew_switch: u32 = 0xff7e;
+pub const Thai_kokai: u32 = 0xda1;
+pub const Thai_khokhai: u32 = 0xda2;
+pub const Thai_khokhuat: u32 = 0xda3;
+pub const Thai_khokhwai: u32 = 0xda4;
+pub const Thai_khokhon: u32 = 0xda5;
+pub const Thai_khorakhang: u32 = 0xda6;
+pub const Thai_ngongu: u32 = 0xda7;
+pub const Thai_chochan: u32 = 0xda8;
+pub const Thai_choching: u32 = 0xda9;
+pub const Thai_chochang: u32 = 0xdaa;
+pub const Thai_soso: u32 = 0xdab;
+pub const Thai_chochoe: u32 = 0xdac;
+pub const Thai_yoying: u32 = 0xdad;
+pub const Thai_dochada: u32 = 0xdae;
+pub const Thai_topatak: u32 = 0xdaf;
+pub const Thai_thothan: u32 = 0xdb0;
+pub const Thai_thonangmontho: u32 = 0xdb1;
+pub const Thai_thophuthao: u32 = 0xdb2;
+pub const Thai_nonen: u32 = 0xdb3;
+pub const Thai_dodek: u32 = 0xdb4;
+pub const Thai_totao: u32 = 0xdb5;
+pub const Thai_thothung: u32 = 0xdb6;
+pub const Thai_thothahan: u32 = 0xdb7;
+pub const Thai_thothong: u32 = 0xdb8;
+pub const Thai_nonu: u32 = 0xdb9;
+pub const Thai_bobaimai: u32 = 0xdba;
+pub const Thai_popla: u32 = 0xdbb;
+pub const Thai_phophung: u32 = 0xdbc;
+pub const Thai_fofa: u32 = 0xdbd;
+pub const Thai_phophan: u32 = 0xdbe;
+pub const Thai_fofan: u32 = 0xdbf;
+pub const Thai_phosamphao: u32 = 0xdc0;
+pub const Thai_moma: u32 = 0xdc1;
+pub const Thai_yoyak: u32 = 0xdc2;
+pub const Thai_rorua: u32 = 0xdc3;
+pub const Thai_ru: u32 = 0xdc4;
+pub const Thai_loling: u32 = 0xdc5;
+pub const Thai_lu: u32 = 0xdc6;
+pub const Thai_wowaen: u32 = 0xdc7;
+pub const Thai_sosala: u32 = 0xdc8;
+pub const Thai_sorusi: u32 = 0xdc9;
+pub const Thai_sosua: u32 = 0xdca;
+pub const Thai_hohip: u32 = 0xdcb;
+pub const Thai_lochula: u32 = 0xdcc;
+pub const Thai_oang: u32 = 0xdcd;
+pub const Thai_honokhuk: u32 = 0xdce;
+pub const Thai_paiyannoi: u32 = 0xdcf;
+pub const Thai_saraa: u32 = 0xdd0;
+pub const Thai_maihanakat: u32 = 0xdd1;
+pub const Thai_saraaa: u32 = 0xdd2;
+pub const Thai_saraam: u32 = 0xdd3;
+pub const Thai_sarai: u32 = 0xdd4;
+pub const Thai_saraii: u32 = 0xdd5;
+pub const Thai_saraue: u32 = 0xdd6;
+pub const Thai_sarauee: u32 = 0xdd7;
+pub const Thai_sarau: u32 = 0xdd8;
+pub const Thai_sarauu: u32 = 0xdd9;
+pub const Thai_phinthu: u32 = 0xdda;
+pub const Thai_maihanakat_maitho: u32 = 0xdde;
+pub const Thai_baht: u32 = 0xddf;
+pub const Thai_sarae: u32 = 0xde0;
+pub const Thai_saraae: u32 = 0xde1;
+pub const Thai_sarao: u32 = 0xde2;
+pub const Thai_saraaimaimuan: u32 = 0xde3;
+pub const Thai_saraaimaimalai: u32 = 0xde4;
+pub const Thai_lakkhangyao: u32 = 0xde5;
+pub const Thai_maiyamok: u32 = 0xde6;
+pub const Thai_maitaikhu: u32 = 0xde7;
+pub const Thai_maiek: u32 = 0xde8;
+pub const Thai_maitho: u32 = 0xde9;
+pub const Thai_maitri: u32 = 0xdea;
+pub const Thai_maichattawa: u32 = 0xdeb;
+pub const Thai_thanthakhat: u32 = 0xdec;
+pub const Thai_nikhahit: u32 = 0xded;
+pub const Thai_leksun: u32 = 0xdf0;
+pub const Thai_leknung: u32 = 0xdf1;
+pub const Thai_leksong: u32 = 0xdf2;
+pub const Thai_leksam: u32 = 0xdf3;
+pub const Thai_leksi: u32 = 0xdf4;
+pub const Thai_lekha: u32 = 0xdf5;
+pub const Thai_lekhok: u32 = 0xdf6;
+pub const Thai_lekchet: u32 = 0xdf7;
+pub const Thai_lekpaet: u32 = 0xdf8;
+pub const Thai_lekkao: u32 = 0xdf9;
+pub const Hangul: u32 = 0xff31;
+pub const Hangul_Start: u32 = 0xff32;
+pub const Hangul_End: u32 = 0xff33;
+pub const Hangul_Hanja: u32 = 0xff34;
+pub const Hangul_Jamo: u32 = 0xff35;
+pub const Hangul_Romaja: u32 = 0xff36;
+pub const Hangul_Codeinput: u32 = 0xff37;
+pub const Hangul_Jeonja: u32 = 0xff38;
+pub const Hangul_Banja: u32 = 0xff39;
+pub const Hangul_PreHanja: u32 = 0xff3a;
+pub const Hangul_PostHanja: u32 = 0xff3b;
+pub const Hangul_SingleCandidate: u32 = 0xff3c;
+pub const Hangul_MultipleCandidate: u32 = 0xff3d;
+pub const Hangul_PreviousCandidate: u32 = 0xff3e;
+pub const Hangul_Special: u32 = 0xff3f;
+pub const Hangul_switch: u32 = 0xff7e;
+pub const Hangul_Kiyeog: u32 = 0xea1;
+pub const Hangul_SsangKiyeog: u32 = 0xea2;
+pub const Hangul_KiyeogSios: u32 = 0xea3;
+pub const Hangul_Nieun: u32 = 0xea4;
+pub const Hangul_NieunJieuj: u32 = 0xea5;
+pub const Hangul_NieunHieuh: u32 = 0xea6;
+pub const Hangul_Dikeud: u32 = 0xea7;
+pub const Hangul_SsangDikeud: u32 = 0xea8;
+pub const Hangul_Rieul: u32 = 0xea9;
+pub const Hangul_RieulKiyeog: u32 = 0xeaa;
+pub const Hangul_RieulMieum: u32 = 0xeab;
+pub const Hangul_RieulPieub: u32 = 0xeac;
+pub const Hangul_RieulSios: u32 = 0xead;
+pub const Hangul_RieulTieut: u32 = 0xeae;
+pub const Hangul_RieulPhieuf: u32 = 0xeaf;
+pub const Hangul_RieulHieuh: u32 = 0xeb0;
+pub const Hangul_Mieum: u32 = 0xeb1;
+pub const Hangul_Pieub: u32 = 0xeb2;
+pub const Hangul_SsangPieub: u32 = 0xeb3;
+pub const Hangul_PieubSios: u32 = 0xeb4;
+pub const Hangul_Sios: u32 = 0xeb5;
+pub const Hangul_SsangSios: u32 = 0xeb6;
+pub const Hangul_Ieung: u32 = 0xeb7;
+pub const Hangul_Jieuj: u32 = 0xeb8;
+pub const Hangul_SsangJieuj: u32 = 0xeb9;
+pub const Hangul_Cieuc: u32 = 0xeba;
+pub const Hangul_Khieuq: u32 = 0xebb;
+pub const Hangul_Tieut: u32 = 0xebc;
+pub const Hangul_Phieuf: u32 = 0xebd;
+pub const Hangul_Hieuh: u32 = 0xebe;
+pub const Hangul_A: u32 = 0xebf;
+pub const Hangul_AE: u32 = 0xec0;
+pub const Hangul_YA: u32 = 0xec1;
+pub const Hangul_YAE: u32 = 0xec2;
+pub const Hangul_EO: u32 = 0xec3;
+pub const Hangul_E: u32 = 0xec4;
+pub const Hangul_YEO: u32 = 0xec5;
+pub const Hangul_YE: u32 = 0xec6;
+pub const Hangul_O: u32 = 0xec7;
+pub const Hangul_WA: u32 = 0xec8;
+pub const Hangul_WAE: u32 = 0xec9;
+pub const Hangul_OE: u32 = 0xeca;
+pub const Hangul_YO: u32 = 0xecb;
+pub const Hangul_U: u32 = 0xecc;
+pub const Hangul_WEO: u32 = 0xecd;
+pub const Hangul_WE: u32 = 0xece;
+pub const Hangul_WI: u32 = 0xecf;
+pub const Hangul_YU: u32 = 0xed0;
+pub const Hangul_EU: u32 = 0xed1;
+pub const Hangul_YI: u32 = 0xed2;
+pub const Hangul_I: u32 = 0xed3;
+pub const Hangul_J_Kiyeog: u32 = 0xed4;
+pub const Hangul_J_SsangKiyeog: u32 = 0xed5;
+pub const Hangul_J_KiyeogSios: u32 = 0xed6;
+pub const Hangul_J_Nieun: u32 = 0xed7;
+pub const Hangul_J_NieunJieuj: u32 = 0xed8;
+pub const Hangul_J_NieunHieuh: u32 = 0xed9;
+pub const Hangul_J_Dikeud: u32 = 0xeda;
+pub const Hangul_J_Rieul: u32 = 0xedb;
+pub const Hangul_J_RieulKiyeog: u32 = 0xedc;
+pub const Hangul_J_RieulMieum: u32 = 0xedd;
+pub const Hangul_J_RieulPieub: u32 = 0xede;
+pub const Hangul_J_RieulSios: u32 = 0xedf;
+pub const Hangul_J_RieulTieut: u32 = 0xee0;
+pub const Hangul_J_RieulPhieuf: u32 = 0xee1;
+pub const Hangul_J_RieulHieuh: u32 = 0xee2;
+pub const Hangul_J_Mieum: u32 = 0xee3;
+pub const Hangul_J_Pieub: u32 = 0xee4;
+pub const Hangul_J_PieubSios: u32 = 0xee5;
+pub const Hangul_J_Sios: u32 = 0xee6;
+pub const Hangul_J_SsangSios: u32 = 0xee7;
+pub const Hangul_J_Ieung: u32 = 0xee8;
+pub const Hangul_J_Jieuj: u32 = 0xee9;
+pub const Hangul_J_Cieuc: u32 = 0xeea;
+pub const Hangul_J_Khieuq: u32 = 0xeeb;
+pub const Hangul_J_Tieut: u32 = 0xeec;
+pub const Hangul_J_Phieuf: u32 = 0xeed;
+pub const Hangul_J_Hieuh: u32 = 0xeee;
+pub const Hangul_RieulYeorinHieuh: u32 = 0xeef;
+pub const Hangul_SunkyeongeumMieum: u32 = 0xef0;
+pub const Hangul_SunkyeongeumPieub: u32 = 0xef1;
+pub const Hangul_PanSios: u32 = 0xef2;
+pub const Hangul_KkogjiDalrinIeung: u32 = 0xef3;
+pub const Hangul_SunkyeongeumPhieuf: u32 = 0xef4;
+pub const Hangul_YeorinHieuh: u32 = 0xef5;
+pub const Hangul_AraeA: u32 = 0xef6;
+pub const Hangul_AraeAE: u32 = 0xef7;
+pub const Hangul_J_PanSios: u32 = 0xef8;
+pub const Hangul_J_KkogjiDalrinIeung: u32 = 0xef9;
+pub const Hangul_J_YeorinHieuh: u32 = 0xefa;
+pub const Korean_Won: u32 = 0xeff;
+pub const Armenian_ligature_ew: u32 = 0x1000587;
+pub const Armenian_full_stop: u32 = 0x1000589;
+pub const Armenian_verjaket: u32 = 0x1000589;
+pub const Armenian_separation_mark: u32 = 0x100055d;
+pub const Armenian_but: u32 = 0x100055d;
+pub const Armenian_hyphen: u32 = 0x100058a;
+pub const Armenian_yentamna: u32 = 0x100058a;
+pub const Armenian_exclam: u32 = 0x100055c;
+pub const Armenian_amanak: u32 = 0x100055c;
+pub const Armenian_accent: u32 = 0x100055b;
+pub const Armenian_shesht: u32 = 0x100055b;
+pub const Armenian_question: u32 = 0x100055e;
+pub const Armenian_paruyk: u32 = 0x100055e;
+pub const Armenian_AYB: u32 = 0x1000531;
+pub const Armenian_ayb: u32 = 0x1000561;
+pub const Armenian_BEN: u32 = 0x1000532;
+pub const Armenian_ben: u32 = 0x1000562;
+pub const Armenian_GIM: u32 = 0x1000533;
+pub const Armenian_gim: u32 = 0x1000563;
+pub const Armenian_DA: u32 = 0x1000534;
+pub const Armenian_da: u32 = 0x1000564;
+pub const Armenian_YECH: u32 = 0x1000535;
+pub const Armenian_yech: u32 = 0x1000565;
+pub const Armenian_ZA: u32 = 0x1000536;
+pub const Armenian_za: u32 = 0x1000566;
+pub const Armenian_E: u32 = 0x1000537;
+pub const Armenian_e: u32 = 0x1000567;
+pub const Armenian_AT: u32 = 0x1000538;
+pub const Armenian_at: u32 = 0x1000568;
+pub const Armenian_TO: u32 = 0x1000539;
+pub const Armenian_to: u32 = 0x1000569;
+pub const Armenian_ZHE: u32 = 0x100053a;
+pub const Armenian_zhe: u32 = 0x100056a;
+pub const Armenian_INI: u32 = 0x100053b;
+pub const Armenian_ini: u32 = 0x100056b;
+pub const Armenian_LYUN: u32 = 0x100053c;
+pub const Armenian_lyun: u32 = 0x100056c;
+pub const Armenian_KHE: u32 = 0x100053d;
+pub const Armenian_khe: u32 = 0x100056d;
+pub const Armenian_TSA: u32 = 0x100053e;
+pub const Armenian_tsa: u32 = 0x100056e;
+pub const Armenian_KEN: u32 = 0x100053f;
+pub const Armenian_ken: u32 = 0x100056f;
+pub const Armenian_HO: u32 = 0x1000540;
+pub const Armenian_ho: u32 = 0x1000570;
+pub const Armenian_DZA: u32 = 0x1000541;
+pub const Armenian_dza: u32 = 0x1000571;
+pub const Armenian_GHAT: u32 = 0x1000542;
+pub const Armenian_ghat: u32 = 0x1000572;
+pub const Armenian_TCHE: u32 = 0x1000543;
+pub const Armenian_tche: u32 = 0x1000573;
+pub const Armenian_MEN: u32 = 0x1000544;
+pub const Armenian_men: u32 = 0x1000574;
+pub const Armenian_HI: u32 = 0x1000545;
+pub const Armenian_hi: u32 = 0x1000575;
+pub const Armenian_NU: u32 = 0x1000546;
+pub const Armenian_nu: u32 = 0x1000576;
+pub const Armenian_SHA: u32 = 0x1000547;
+pub const Armenian_sha: u32 = 0x1000577;
+pub const Armenian_VO: u32 = 0x1000548;
+pub const Armenian_vo: u32 = 0x1000578;
+pub const Armenian_CHA: u32 = 0x1000549;
+pub const Armenian_cha: u32 = 0x1000579;
+pub const Armenian_PE: u32 = 0x100054a;
+pub const Armenian_pe: u32 = 0x100057a;
+pub const Armenian_JE: u32 = 0x100054b;
+pub const Armenian_je: u32 = 0x100057b;
+pub const Armenian_RA: u32 = 0x100054c;
+pub const Armenian_ra: u32 = 0x100057c;
+pub const Armenian_SE: u32 = 0x100054d;
+pub const Armenian_se: u32 = 0x100057d;
+pub const Armenian_VEV: u32 = 0x100054e;
+pub const Armenian_vev: u32 = 0x100057e;
+pub const Armenian_TYUN: u32 = 0x100054f;
+pub const Armenian_tyun: u32 = 0x100057f;
+pub const Armenian_RE: u32 = 0x1000550;
+pub const Armenian_re: u32 = 0x1000580;
+pub const Armenian_TSO: u32 = 0x1000551;
+pub const Armenian_tso: u32 = 0x1000581;
+pub const Armenian_VYUN: u32 = 0x1000552;
+pub const Armenian_vyun: u32 = 0x1000582;
+pub const Armenian_PYUR: u32 = 0x1000553;
+pub const Armenian_pyur: u32 = 0x1000583;
+pub const Armenian_KE: u32 = 0x1000554;
+pub const Armenian_ke: u32 = 0x1000584;
+pub const Armenian_O: u32 = 0x1000555;
+pub const Armenian_o: u32 = 0x1000585;
+pub const Armenian_FE: u32 = 0x1000556;
+pub const Armenian_fe: u32 = 0x1000586;
+pub const Armenian_apostrophe: u32 = 0x100055a;
+pub const Georgian_an: u32 = 0x10010d0;
+pub const Georgian_ban: u32 = 0x10010d1;
+pub const Georgian_gan: u32 = 0x10010d2;
+pub const Georgian_don: u32 = 0x10010d3;
+pub const Georgian_en: u32 = 0x10010d4;
+pub const Georgian_vin: u32 = 0x10010d5;
+pub const Georgian_zen: u32 = 0x10010d6;
+pub const Georgian_tan: u32 = 0x10010d7;
+pub const Georgian_in: u32 = 0x10010d8;
+pub const Georgian_kan: u32 = 0x10010d9;
+pub const Georgian_las: u32 = 0x10010da;
+pub const Georgian_man: u32 = 0x10010db;
+pub const Georgian_nar: u32 = 0x10010dc;
+pub const Georgian_on: u32 = 0x10010dd;
+pub const Georgian_par: u32 = 0x10010de;
+pub const Georgian_zhar: u32 = 0x10010df;
+pub const Georgian_rae: u32 = 0x10010e0;
+pub const Georgian_san: u32 = 0x10010e1;
+pub const Georgian_tar: u32 = 0x10010e2;
+pub const Georgian_un: u32 = 0x10010e3;
+pub const Georgian_phar: u32 = 0x10010e4;
+pub const Georgian_khar: u32 = 0x10010e5;
+pub const Georgian_ghan: u32 = 0x10010e6;
+pub const Georgian_qar: u32 = 0x10010e7;
+pub const Georgian_shin: u32 = 0x10010e8;
+pub const Georgian_chin: u32 = 0x10010e9;
+pub const Georgian_can: u32 = 0x10010ea;
+pub const Georgian_jil: u32 = 0x10010eb;
+pub const Georgian_cil: u32 = 0x10010ec;
+pub const Georgian_char: u32 = 0x10010ed;
+pub const Georgian_xan: u32 = 0x10010ee;
+pub const Georgian_jhan: u32 = 0x10010ef;
+pub const Georgian_hae: u32 = 0x10010f0;
+pub const Georgian_he: u32 = 0x10010f1;
+pub const Georgian_hie: u32 = 0x10010f2;
+pub const Georgian_we: u32 = 0x10010f3;
+pub const Georgian_har: u32 = 0x10010f4;
+pub const Georgian_hoe: u32 = 0x10010f5;
+pub const Georgian_fi: u32 = 0x10010f6;
+pub const Xabovedot: u32 = 0x1001e8a;
+pub const Ibreve: u32 = 0x100012c;
+pub const Zstroke: u32 = 0x10001b5;
+pub const Gcaron: u32 = 0x10001e6;
+pub const Ocaron: u32 = 0x10001d1;
+pub const Obarred: u32 = 0x100019f;
+pub const xabovedot: u32 = 0x1001e8b;
+pub const ibreve: u32 = 0x100012d;
+pub const zstroke: u32 = 0x10001b6;
+pub const gcaron: u32 = 0x10001e7;
+pub const ocaron: u32 = 0x10001d2;
+pub const obarred: u32 = 0x1000275;
+pub const SCHWA: u32 = 0x100018f;
+pub const schwa: u32 = 0x1000259;
+pub const EZH: u32 = 0x10001b7;
+pub const ezh: u32 = 0x1000292;
+pub const Lbelowdot: u32 = 0x1001e36;
+pub const lbelowdot: u32 = 0x1001e37;
+pub const Abelowdot: u32 = 0x1001ea0;
+pub const abelowdot: u32 = 0x1001ea1;
+pub const Ahook: u32 = 0x1001ea2;
+pub const ahook: u32 = 0x1001ea3;
+pub const Acircumflexacute: u32 = 0x1001ea4;
+pub const acircumflexacute: u32 = 0x1001ea5;
+pub const Acircumflexgrave: u32 = 0x1001ea6;
+pub const acircumflexgrave: u32 = 0x1001ea7;
+pub const Acircumflexhook: u32 = 0x1001ea8;
+pub const acircumflexhook: u32 = 0x1001ea9;
+pub const Acircumflextilde: u32 = 0x1001eaa;
+pub const acircumflextilde: u32 = 0x1001eab;
+pub const Acircumflexbelowdot: u32 = 0x1001eac;
+pub const acircumflexbelowdot: u32 = 0x1001ead;
+pub const Abreveacute: u32 = 0x1001eae;
+pub const abreveacute: u32 = 0x1001eaf;
+pub const Abrevegrave: u32 = 0x1001eb0;
+pub const abrevegrave: u32 = 0x1001eb1;
+pub const Abrevehook: u32 = 0x1001eb2;
+pub const abrevehook: u32 = 0x1001eb3;
+pub const Abrevetilde: u32 = 0x1001eb4;
+pub const abrevetilde: u32 = 0x1001eb5;
+pub const Abrevebelowdot: u32 = 0x1001eb6;
+pub const abrevebelowdot: u32 = 0x1001eb7;
+pub const Ebelowdot: u32 = 0x1001eb8;
+pub const ebelowdot: u32 = 0x1001eb9;
+pub const Ehook: u32 = 0x1001eba;
+pub const ehook: u32 = 0x1001ebb;
+pub const Etilde: u32 = 0x1001ebc;
+pub const etilde: u32 = 0x1001ebd;
+pub const Ecircumflexacute: u32 = 0x1001ebe;
+pub const ecircumflexacute: u32 = 0x1001ebf;
+pub const Ecircumflexgrave: u32 = 0x1001ec0;
+pub const ecircumflexgrave: u32 = 0x1001ec1;
+pub const Ecircumflexhook: u32 = 0x1001ec2;
+pub const ecircumflexhook: u32 = 0x1001ec3;
+pub const Ecircumflextilde: u32 = 0x1001ec4;
+pub const ecircumflextilde: u32 = 0x1001ec5;
+pub const Ecircumflexbelowdot: u32 = 0x1001ec6;
+pub const ecircumflexbelowdot: u32 = 0x1001ec7;
+pub const Ihook: u32 = 0x1001ec8;
+pub const ihook: u32 = 0x1001ec9;
+pub const Ibelowdot: u32 = 0x1001eca;
+pub const ibelowdot: u32 = 0x1001ecb;
+pub const Obelowdot: u32 = 0x1001ecc;
+pub const obelowdot: u32 = 0x1001ecd;
+pub const Ohook: u32 = 0x1001ece;
+pub const ohook: u32 = 0x1001ecf;
+pub const Ocircumflexacute: u32 = 0x1001ed0;
+pub const ocircumflexacute: u32 = 0x1001ed1;
+pub const Ocircumflexgrave: u32 = 0x1001ed2;
+pub const ocircumflexgrave: u32 = 0x1001ed3;
+pub const Ocircumflexhook: u32 = 0x1001ed4;
+pub const ocircumflexhook: u32 = 0x1001ed5;
+pub const Ocircumflextilde: u32 = 0x1001ed6;
+pub const ocircumflextilde: u32 = 0x1001ed7;
+pub const Ocircumflexbelowdot: u32 = 0x1001ed8;
+pub const ocircumflexbelowdot: u32 = 0x1001ed9;
+pub const Ohornacute: u32 = 0x1001eda;
+pub const ohornacute: u32 = 0x1001edb;
+pub const Ohorngrave: u32 = 0x1001edc;
+pub const ohorngrave: u32 = 0x1001edd;
+pub const Ohornhook: u32 = 0x1001ede;
+pub const ohornhook: u32 = 0x1001edf;
+pub const Ohorntilde: u32 = 0x1001ee0;
+pub const ohorntilde: u32 = 0x1001ee1;
+pub const Ohornbelowdot: u32 = 0x1001ee2;
+pub const ohornbelowdot: u32 = 0x1001ee3;
+pub const Ubelowdot: u32 = 0x1001ee4;
+pub const ubelowdot: u32 = 0x1001ee5;
+pub const Uhook: u32 = 0x1001ee6;
+pub const uhook: u32 = 0x1001ee7;
+pub const Uhornacute: u32 = 0x1001ee8;
+pub const uhornacute: u32 = 0x1001ee9;
+pub const Uhorngrave: u32 = 0x1001eea;
+pub const uhorngrave: u32 = 0x1001eeb;
+pub const Uhornhook: u32 = 0x1001eec;
+pub const uhornhook: u32 = 0x1001eed;
+pub const Uhorntilde: u32 = 0x1001eee;
+pub const uhorntilde: u32 = 0x1001eef;
+pub const Uhornbelowdot: u32 = 0x1001ef0;
+pub const uhornbelowdot: u32 = 0x1001ef1;
+pub const Ybelowdot: u32 = 0x1001ef4;
+pub const ybelowdot: u32 = 0x1001ef5;
+pub const Yhook: u32 = 0x1001ef6;
+pub const yhook: u32 = 0x1001ef7;
+pub const Ytilde: u32 = 0x1001ef8;
+pub const ytilde: u32 = 0x1001ef9;
+pub const Ohorn: u32 = 0x10001a0;
+pub const ohorn: u32 = 0x10001a1;
+pub const Uhorn: u32 = 0x10001af;
+pub const uhorn: u32 = 0x10001b0;
+pub const combining_tilde: u32 = 0x1000303;
+pub const combining_grave: u32 = 0x1000300;
+pub const combining_acute: u32 = 0x1000301;
+pub const combining_hook: u32 = 0x1000309;
+pub const combining_belowdot: u32 = 0x1000323;
+pub const EcuSign: u32 = 0x10020a0;
+pub const ColonSign: u32 = 0x10020a1;
+pub const CruzeiroSign: u32 = 0x10020a2;
+pub const FFrancSign: u32 = 0x10020a3;
+pub const LiraSign: u32 = 0x10020a4;
+pub const MillSign: u32 = 0x10020a5;
+pub const NairaSign: u32 = 0x10020a6;
+pub const PesetaSign: u32 = 0x10020a7;
+pub const RupeeSign: u32 = 0x10020a8;
+pub const WonSign: u32 = 0x10020a9;
+pub const NewSheqelSign: u32 = 0x10020aa;
+pub const DongSign: u32 = 0x10020ab;
+pub const EuroSign: u32 = 0x20ac;
+pub const zerosuperior: u32 = 0x1002070;
+pub const foursuperior: u32 = 0x1002074;
+pub const fivesuperior: u32 = 0x1002075;
+pub const sixsuperior: u32 = 0x1002076;
+pub const sevensuperior: u32 = 0x1002077;
+pub const eightsuperior: u32 = 0x1002078;
+pub const ninesuperior: u32 = 0x1002079;
+pub const zerosubscript: u32 = 0x1002080;
+pub const onesubscript: u32 = 0x1002081;
+pub const twosubscript: u32 = 0x1002082;
+pub const threesubscript: u32 = 0x1002083;
+pub const foursubscript: u32 = 0x1002084;
+pub const fivesubscript: u32 = 0x1002085;
+pub const sixsubscript: u32 = 0x1002086;
+pub const sevensubscript: u32 = 0x1002087;
+pub const eightsubscript: u32 = 0x1002088;
+pub const ninesubscript: u32 = 0x1002089;
+pub const partdifferential: u32 = 0x1002202;
+pub const emptyset: u32 = 0x1002205;
+pub const elementof: u32 = 0x1002208;
+pub const notelementof: u32 = 0x1002209;
+pub const containsas: u32 = 0x100220b;
+pub const squareroot: u32 = 0x100221a;
+pub const cuberoot: u32 = 0x100221b;
+pub const fourthroot: u32 = 0x100221c;
+pub const dintegral: u32 = 0x100222c;
+pub const tintegral: u32 = 0x100222d;
+pub const because: u32 = 0x1002235;
+pub const approxeq: u32 = 0x1002248;
+pub const notapproxeq: u32 = 0x1002247;
+pub const notidentical: u32 = 0x1002262;
+pub const stricteq: u32 = 0x1002263;
+pub const braille_dot_1: u32 = 0xfff1;
+pub const braille_dot_2: u32 = 0xfff2;
+pub const braille_dot_3: u32 = 0xfff3;
+pub const braille_dot_4: u32 = 0xfff4;
+pub const braille_dot_5: u32 = 0xfff5;
+pub const braille_dot_6: u32 = 0xfff6;
+pub const braille_dot_7: u32 = 0xfff7;
+pub const braille_dot_8: u32 = 0xfff8;
+pub const braille_dot_9: u32 = 0xfff9;
+pub const braille_dot_10: u32 = 0xfffa;
+pub const braille_blank: u32 = 0x1002800;
+pub const braille_dots_1: u32 = 0x1002801;
+pub const braille_dots_2: u32 = 0x1002802;
+pub const braille_dots_12: u32 = 0x1002803;
+pub const braille_dots_3: u32 = 0x1002804;
+pub const braille_dots_13: u32 = 0x1002805;
+pub const braille_dots_23: u32 = 0x1002806;
+pub const braille_dots_123: u32 = 0x1002807;
+pub const braille_dots_4: u32 = 0x1002808;
+pub const braille_dots_14: u32 = 0x1002809;
+pub const braille_dots_24: u32 = 0x100280a;
+pub const braille_dots_124: u32 = 0x100280b;
+pub const braille_dots_34: u32 = 0x100280c;
+pub const braille_dots_134: u32 = 0x100280d;
+pub const braille_dots_234: u32 = 0x100280e;
+pub const braille_dots_1234: u32 = 0x100280f;
+pub const braille_dots_5: u32 = 0x1002810;
+pub const braille_dots_15: u32 = 0x1002811;
+pub const braille_dots_25: u32 = 0x1002812;
+pub const braille_dots_125: u32 = 0x1002813;
+pub const braille_dots_35: u32 = 0x1002814;
+pub const braille_dots_135: u32 = 0x1002815;
+pub const braille_dots_235: u32 = 0x1002816;
+pub const braille_dots_1235: u32 = 0x1002817;
+pub const braille_dots_45: u32 = 0x1002818;
+pub const braille_dots_145: u32 = 0x1002819;
+pub const braille_dots_245: u32 = 0x100281a;
+pub const braille_dots_1245: u32 = 0x100281b;
+pub const braille_dots_345: u32 = 0x100281c;
+pub const braille_dots_1345: u32 = 0x100281d;
+pub const braille_dots_2345: u32 = 0x100281e;
+pub const braille_dots_12345: u32 = 0x100281f;
+pub const braille_dots_6: u32 = 0x1002820;
+pub const braille_dots_16: u32 = 0x1002821;
+pub const braille_dots_26: u32 = 0x1002822;
+pub const braille_dots_126: u32 = 0x1002823;
+pub const braille_dots_36: u32 = 0x1002824;
+pub const braille_dots_136: u32 = 0x1002825;
+pub const braille_dots_236: u32 = 0x1002826;
+pub const braille_dots_1236: u32 = 0x1002827;
+pub const braille_dots_46: u32 = 0x1002828;
+pub const braille_dots_146: u32 = 0x1002829;
+pub const braille_dots_246: u32 = 0x100282a;
+pub const braille_dots_1246: u32 = 0x100282b;
+pub const braille_dots_346: u32 = 0x100282c;
+pub const braille_dots_1346: u32 = 0x100282d;
+pub const braille_dots_2346: u32 = 0x100282e;
+pub const braille_dots_12346: u32 = 0x100282f;
+pub const braille_dots_56: u32 = 0x1002830;
+pub const braille_dots_156: u32 = 0x1002831;
+pub const braille_dots_256: u32 = 0x1002832;
+pub const braille_dots_1256: u32 = 0x1002833;
+pub const braille_dots_356: u32 = 0x1002834;
+pub const braille_dots_1356: u32 = 0x1002835;
+pub const braille_dots_2356: u32 = 0x1002836;
+pub const braille_dots_12356: u32 = 0x1002837;
+pub const braille_dots_456: u32 = 0x1002838;
+pub const braille_dots_1456: u32 = 0x1002839;
+pub const braille_dots_2456: u32 = 0x100283a;
+pub const braille_dots_12456: u32 = 0x100283b;
+pub const braille_dots_3456: u32 = 0x100283c;
+pub const braille_dots_13456: u32 = 0x100283d;
+pub const braille_dots_23456: u32 = 0x100283e;
+pub const braille_dots_123456: u32 = 0x100283f;
+pub const braille_dots_7: u32 = 0x1002840;
+pub const braille_dots_17: u32 = 0x1002841;
+pub const braille_dots_27: u32 = 0x1002842;
+pub const braille_dots_127: u32 = 0x1002843;
+pub const braille_dots_37: u32 = 0x1002844;
+pub const braille_dots_137: u32 = 0x1002845;
+pub const braille_dots_237: u32 = 0x1002846;
+pub const braille_dots_1237: u32 = 0x1002847;
+pub const braille_dots_47: u32 = 0x1002848;
+pub const braille_dots_147: u32 = 0x1002849;
+pub const braille_dots_247: u32 = 0x100284a;
+pub const braille_dots_1247: u32 = 0x100284b;
+pub const braille_dots_347: u32 = 0x100284c;
+pub const braille_dots_1347: u32 = 0x100284d;
+pub const braille_dots_2347: u32 = 0x100284e;
+pub const braille_dots_12347: u32 = 0x100284f;
+pub const braille_dots_57: u32 = 0x1002850;
+pub const braille_dots_157: u32 = 0x1002851;
+pub const braille_dots_257: u32 = 0x1002852;
+pub const braille_dots_1257: u32 = 0x1002853;
+pub const braille_dots_357: u32 = 0x1002854;
+pub const braille_dots_1357: u32 = 0x1002855;
+pub const braille_dots_2357: u32 = 0x1002856;
+pub const braille_dots_12357: u32 = 0x1002857;
+pub const braille_dots_457: u32 = 0x1002858;
+pub const braille_dots_1457: u32 = 0x1002859;
+pub const braille_dots_2457: u32 = 0x100285a;
+pub const braille_dots_12457: u32 = 0x100285b;
+pub const braille_dots_3457: u32 = 0x100285c;
+pub const braille_dots_13457: u32 = 0x100285d;
+pub const braille_dots_23457: u32 = 0x100285e;
+pub const braille_dots_123457: u32 = 0x100285f;
+pub const braille_dots_67: u32 = 0x1002860;
+pub const braille_dots_167: u32 = 0x1002861;
+pub const braille_dots_267: u32 = 0x1002862;
+pub const braille_dots_1267: u32 = 0x1002863;
+pub const braille_dots_367: u32 = 0x1002864;
+pub const braille_dots_1367: u32 = 0x1002865;
+pub const braille_dots_2367: u32 = 0x1002866;
+pub const braille_dots_12367: u32 = 0x1002867;
+pub const braille_dots_467: u32 = 0x1002868;
+pub const braille_dots_1467: u32 = 0x1002869;
+pub const braille_dots_2467: u32 = 0x100286a;
+pub const braille_dots_12467: u32 = 0x100286b;
+pub const braille_dots_3467: u32 = 0x100286c;
+pub const braille_dots_13467: u32 = 0x100286d;
+pub const braille_dots_23467: u32 = 0x100286e;
+pub const braille_dots_123467: u32 = 0x100286f;
+pub const braille_dots_567: u32 = 0x1002870;
+pub const braille_dots_1567: u32 = 0x1002871;
+pub const braille_dots_2567: u32 = 0x1002872;
+pub const braille_dots_12567: u32 = 0x1002873;
+pub const braille_dots_3567: u32 = 0x1002874;
+pub const braille_dots_13567: u32 = 0x1002875;
+pub const braille_dots_23567: u32 = 0x1002876;
+pub const braille_dots_123567: u32 = 0x1002877;
+pub const braille_dots_4567: u32 = 0x1002878;
+pub const braille_dots_14567: u32 = 0x1002879;
+pub const braille_dots_24567: u32 = 0x100287a;
+pub const braille_dots_124567: u32 = 0x100287b;
+pub const braille_dots_34567: u32 = 0x100287c;
+pub const braille_dots_134567: u32 = 0x100287d;
+pub const braille_dots_234567: u32 = 0x100287e;
+pub const braille_dots_1234567: u32 = 0x100287f;
+pub const braille_dots_8: u32 = 0x1002880;
+pub const braille_dots_18: u32 = 0x1002881;
+pub const braille_dots_28: u32 = 0x1002882;
+pub const braille_dots_128: u32 = 0x1002883;
+pub const braille_dots_38: u32 = 0x1002884;
+pub const braille_dots_138: u32 = 0x1002885;
+pub const braille_dots_238: u32 = 0x1002886;
+pub const braille_dots_1238: u32 = 0x1002887;
+pub const braille_dots_48: u32 = 0x1002888;
+pub const braille_dots_148: u32 = 0x1002889;
+pub const braille_dots_248: u32 = 0x100288a;
+pub const braille_dots_1248: u32 = 0x100288b;
+pub const braille_dots_348: u32 = 0x100288c;
+pub const braille_dots_1348: u32 = 0x100288d;
+pub const braille_dots_2348: u32 = 0x100288e;
+pub const braille_dots_12348: u32 = 0x100288f;
+pub const braille_dots_58: u32 = 0x1002890;
+pub const braille_dots_158: u32 = 0x1002891;
+pub const braille_dots_258: u32 = 0x1002892;
+pub const braille_dots_1258: u32 = 0x1002893;
+pub const braille_dots_358: u32 = 0x1002894;
+pub const braille_dots_1358: u32 = 0x1002895;
+pub const braille_dots_2358: u32 = 0x1002896;
+pub const braille_dots_12358: u32 = 0x1002897;
+pub const braille_dots_458: u32 = 0x1002898;
+pub const braille_dots_1458: u32 = 0x1002899;
+pub const braille_dots_2458: u32 = 0x100289a;
+pub const braille_dots_12458: u32 = 0x100289b;
+pub const braille_dots_3458: u32 = 0x100289c;
+pub const braille_dots_13458: u32 = 0x100289d;
+pub const braille_dots_23458: u32 = 0x100289e;
+pub const braille_dots_123458: u32 = 0x100289f;
+pub const braille_dots_68: u32 = 0x10028a0;
+pub const braille_dots_168: u32 = 0x10028a1;
+pub const braille_dots_268: u32 = 0x10028a2;
+pub const braille_dots_1268: u32 = 0x10028a3;
+pub const braille_dots_368: u32 = 0x10028a4;
+pub const braille_dots_1368: u32 = 0x10028a5;
+pub const braille_dots_2368: u32 = 0x10028a6;
+pub const braille_dots_12368: u32 = 0x10028a7;
+pub const braille_dots_468: u32 = 0x10028a8;
+pub const braille_dots_1468: u32 = 0x10028a9;
+pub const braille_dots_2468: u32 = 0x10028aa;
+pub const braille_dots_12468: u32 = 0x10028ab;
+pub const braille_dots_3468: u32 = 0x10028ac;
+pub const braille_dots_13468: u32 = 0x10028ad;
+pub const braille_dots_23468: u32 = 0x10028ae;
+pub const braille_dots_123468: u32 = 0x10028af;
+pub const braille_dots_568: u32 = 0x10028b0;
+pub const braille_dots_1568: u32 = 0x10028b1;
+pub const braille_dots_2568: u32 = 0x10028b2;
+pub const braille_dots_12568: u32 = 0x10028b3;
+pub const braille_dots_3568: u32 = 0x10028b4;
+pub const braille_dots_13568: u32 = 0x10028b5;
+pub const braille_dots_23568: u32 = 0x10028b6;
+pub const braille_dots_123568: u32 = 0x10028b7;
+pub const braille_dots_4568: u32 = 0x10028b8;
+pub const braille_dots_14568: u32 = 0x10028b9;
+pub const braille_dots_24568: u32 = 0x10028ba;
+pub const braille_dots_124568: u32 = 0x10028bb;
+pub const braille_dots_34568: u32 = 0x10028bc;
+pub const braille_dots_134568: u32 = 0x10028bd;
+pub const braille_dots_234568: u32 = 0x10028be;
+pub const braille_dots_1234568: u32 = 0x10028bf;
+pub const braille_dots_78: u32 = 0x10028c0;
+pub const braille_dots_178: u32 = 0x10028c1;
+pub const braille_dots_278: u32 = 0x10028c2;
+pub const braille_dots_1278: u32 = 0x10028c3;
+pub const braille_dots_378: u32 = 0x10028c4;
+pub const braille_dots_1378: u32 = 0x10028c5;
+pub const braille_dots_2378: u32 = 0x10028c6;
+pub const braille_dots_12378: u32 = 0x10028c7;
+pub const braille_dots_478: u32 = 0x10028c8;
+pub const braille_dots_1478: u32 = 0x10028c9;
+pub const braille_dots_2478: u32 = 0x10028ca;
+pub const braille_dots_12478: u32 = 0x10028cb;
+pub const braille_dots_3478: u32 = 0x10028cc;
+pub const braille_dots_13478: u32 = 0x10028cd;
+pub const braille_dots_23478: u32 = 0x10028ce;
+pub const braille_dots_123478: u32 = 0x10028cf;
+pub const braille_dots_578: u32 = 0x10028d0;
+pub const braille_dots_1578: u32 = 0x10028d1;
+pub const braille_dots_2578: u32 = 0x10028d2;
+pub const braille_dots_12578: u32 = 0x10028d3;
+pub const braille_dots_3578: u32 = 0x10028d4;
+pub const braille_dots_13578: u32 = 0x10028d5;
+pub const braille_dots_23578: u32 = 0x10028d6;
+pub const braille_dots_123578: u32 = 0x10028d7;
+pub const braille_dots_4578: u32 = 0x10028d8;
+pub const braille_dots_14578: u32 = 0x10028d9;
+pub const braille_dots_24578: u32 = 0x10028da;
+pub const braille_dots_124578: u32 = 0x10028db;
+pub const braille_dots_34578: u32 = 0x10028dc;
+pub const braille_dots_134578: u32 = 0x10028dd;
+pub const braille_dots_234578: u32 = 0x10028de;
+pub const braille_dots_1234578: u32 = 0x10028df;
+pub const braille_dots_678: u32 = 0x10028e0;
+pub const braille_dots_1678: u32 = 0x10028e1;
+pub const braille_dots_2678: u32 = 0x10028e2;
+pub const braille_dots_12678: u32 = 0x10028e3;
+pub const braille_dots_3678: u32 = 0x10028e4;
+pub const braille_dots_13678: u32 = 0x10028e5;
+pub const braille_dots_23678: u32 = 0x10028e6;
+pub const braille_dots_123678: u32 = 0x10028e7;
+pub const braille_dots_4678: u32 = 0x10028e8;
+pub const braille_dots_14678: u32 = 0x10028e9;
+pub const braille_dots_24678: u32 = 0x10028ea;
+pub const braille_dots_124678: u32 = 0x10028eb;
+pub const braille_dots_34678: u32 = 0x10028ec;
+pub const braille_dots_134678: u32 = 0x10028ed;
+pub const braille_dots_234678: u32 = 0x10028ee;
+pub const braille_dots_1234678: u32 = 0x10028ef;
+pub const braille_dots_5678: u32 = 0x10028f0;
+pub const braille_dots_15678: u32 = 0x10028f1;
+pub const braille_dots_25678: u32 = 0x10028f2;
+pub const braille_dots_125678: u32 = 0x10028f3;
+pub const braille_dots_35678: u32 = 0x10028f4;
+pub const braille_dots_135678: u32 = 0x10028f5;
+pub const braille_dots_235678: u32 = 0x10028f6;
+pub const braille_dots_1235678: u32 = 0x10028f7;
+pub const braille_dots_45678: u32 = 0x10028f8;
+pub const braille_dots_145678: u32 = 0x10028f9;
+pub const braille_dots_245678: u32 = 0x10028fa;
+pub const braille_dots_1245678: u32 = 0x10028fb;
+pub const braille_dots_345678: u32 = 0x10028fc;
+pub const braille_dots_1345678: u32 = 0x10028fd;
+pub const braille_dots_2345678: u32 = 0x10028fe;
+pub const braille_dots_12345678: u32 = 0x10028ff;
+pub const Sinh_ng: u32 = 0x1000d82;
+pub const Sinh_h2: u32 = 0x1000d83;
+pub const Sinh_a: u32 = 0x1000d85;
+pub const Sinh_aa: u32 = 0x1000d86;
+pub const Sinh_ae: u32 = 0x1000d87;
+pub const Sinh_aee: u32 = 0x1000d88;
+pub const Sinh_i: u32 = 0x1000d89;
+pub const Sinh_ii: u32 = 0x1000d8a;
+pub const Sinh_u: u32 = 0x1000d8b;
+pub const Sinh_uu: u32 = 0x1000d8c;
+pub const Sinh_ri: u32 = 0x1000d8d;
+pub const Sinh_rii: u32 = 0x1000d8e;
+pub const Sinh_lu: u32 = 0x1000d8f;
+pub const Sinh_luu: u32 = 0x1000d90;
+pub const Sinh_e: u32 = 0x1000d91;
+pub const Sinh_ee: u32 = 0x1000d92;
+pub const Sinh_ai: u32 = 0x1000d93;
+pub const Sinh_o: u32 = 0x1000d94;
+pub const Sinh_oo: u32 = 0x1000d95;
+pub const Sinh_au: u32 = 0x1000d96;
+pub const Sinh_ka: u32 = 0x1000d9a;
+pub const Sinh_kha: u32 = 0x1000d9b;
+pub const Sinh_ga: u32 = 0x1000d9c;
+pub const Sinh_gha: u32 = 0x1000d9d;
+pub const Sinh_ng2: u32 = 0x1000d9e;
+pub const Sinh_nga: u32 = 0x1000d9f;
+pub const Sinh_ca: u32 = 0x1000da0;
+pub const Sinh_cha: u32 = 0x1000da1;
+pub const Sinh_ja: u32 = 0x1000da2;
+pub const Sinh_jha: u32 = 0x1000da3;
+pub const Sinh_nya: u32 = 0x1000da4;
+pub const Sinh_jnya: u32 = 0x1000da5;
+pub const Sinh_nja: u32 = 0x1000da6;
+pub const Sinh_tta: u32 = 0x1000da7;
+pub const Sinh_ttha: u32 = 0x1000da8;
+pub const Sinh_dda: u32 = 0x1000da9;
+pub const Sinh_ddha: u32 = 0x1000daa;
+pub const Sinh_nna: u32 = 0x1000dab;
+pub const Sinh_ndda: u32 = 0x1000dac;
+pub const Sinh_tha: u32 = 0x1000dad;
+pub const Sinh_thha: u32 = 0x1000dae;
+pub const Sinh_dha: u32 = 0x1000daf;
+pub const Sinh_dhha: u32 = 0x1000db0;
+pub const Sinh_na: u32 = 0x1000db1;
+pub const Sinh_ndha: u32 = 0x1000db3;
+pub const Sinh_pa: u32 = 0x1000db4;
+pub const Sinh_pha: u32 = 0x1000db5;
+pub const Sinh_ba: u32 = 0x1000db6;
+pub const Sinh_bha: u32 = 0x1000db7;
+pub const Sinh_ma: u32 = 0x1000db8;
+pub const Sinh_mba: u32 = 0x1000db9;
+pub const Sinh_ya: u32 = 0x1000dba;
+pub const Sinh_ra: u32 = 0x1000dbb;
+pub const Sinh_la: u32 = 0x1000dbd;
+pub const Sinh_va: u32 = 0x1000dc0;
+pub const Sinh_sha: u32 = 0x1000dc1;
+pub const Sinh_ssha: u32 = 0x1000dc2;
+pub const Sinh_sa: u32 = 0x1000dc3;
+pub const Sinh_ha: u32 = 0x1000dc4;
+pub const Sinh_lla: u32 = 0x1000dc5;
+pub const Sinh_fa: u32 = 0x1000dc6;
+pub const Sinh_al: u32 = 0x1000dca;
+pub const Sinh_aa2: u32 = 0x1000dcf;
+pub const Sinh_ae2: u32 = 0x1000dd0;
+pub const Sinh_aee2: u32 = 0x1000dd1;
+pub const Sinh_i2: u32 = 0x1000dd2;
+pub const Sinh_ii2: u32 = 0x1000dd3;
+pub const Sinh_u2: u32 = 0x1000dd4;
+pub const Sinh_uu2: u32 = 0x1000dd6;
+pub const Sinh_ru2: u32 = 0x1000dd8;
+pub const Sinh_e2: u32 = 0x1000dd9;
+pub const Sinh_ee2: u32 = 0x1000dda;
+pub const Sinh_ai2: u32 = 0x1000ddb;
+pub const Sinh_o2: u32 = 0x1000ddc;
+pub const Sinh_oo2: u32 = 0x1000ddd;
+pub const Sinh_au2: u32 = 0x1000dde;
+pub const Sinh_lu2: u32 = 0x1000ddf;
+pub const Sinh_ruu2: u32 = 0x1000df2;
+pub const Sinh_luu2: u32 = 0x1000df3;
+pub const Sinh_kunddaliya: u32 = 0x1000df4;
+pub const ModeLock: u32 = 0x1008ff01;
+pub const MonBrightnessUp: u32 = 0x1008ff02;
+pub const MonBrightnessDown: u32 = 0x1008ff03;
+pub const KbdLightOnOff: u32 = 0x1008ff04;
+pub const KbdBrightnessUp: u32 = 0x1008ff05;
+pub const KbdBrightnessDown: u32 = 0x1008ff06;
+pub const MonBrightnessCycle: u32 = 0x1008ff07;
+pub const Standby: u32 = 0x1008ff10;
+pub const AudioLowerVolume: u32 = 0x1008ff11;
+pub const AudioMute: u32 = 0x1008ff12;
+pub const AudioRaiseVolume: u32 = 0x1008ff13;
+pub const AudioPlay: u32 = 0x1008ff14;
+pub const AudioStop: u32 = 0x1008ff15;
+pub const AudioPrev: u32 = 0x1008ff16;
+pub const AudioNext: u32 = 0x1008ff17;
+pub const HomePage: u32 = 0x1008ff18;
+pub const Mail: u32 = 0x1008ff19;
+pub const Start: u32 = 0x1008ff1a;
+pub const Search: u32 = 0x1008ff1b;
+pub const AudioRecord: u32 = 0x1008ff1c;
+pub const Calculator: u32 = 0x1008ff1d;
+pub const Memo: u32 = 0x1008ff1e;
+pub const ToDoList: u32 = 0x1008ff1f;
+pub const Calendar: u32 = 0x1008ff20;
+pub const PowerDown: u32 = 0x1008ff21;
+pub const ContrastAdjust: u32 = 0x1008ff22;
+pub const RockerUp: u32 = 0x1008ff23;
+pub const RockerDown: u32 = 0x1008ff24;
+pub const RockerEnter: u32 = 0x1008ff25;
+pub const Back: u32 = 0x1008ff26;
+pub const Forward: u32 = 0x1008ff27;
+pub const Stop: u32 = 0x1008ff28;
+pub const Refresh: u32 = 0x1008ff29;
+pub const PowerOff: u32 = 0x1008ff2a;
+pub const WakeUp: u32 = 0x1008ff2b;
+pub const Eject: u32 = 0x1008ff2c;
+pub const ScreenSaver: u32 = 0x1008ff2d;
+pub const WWW: u32 = 0x1008ff2e;
+pub const Sleep: u32 = 0x1008ff2f;
+pub const Favorites: u32 = 0x1008ff30;
+pub const AudioPause: u32 = 0x1008ff31;
+pub const AudioMedia: u32 = 0x1008ff32;
+pub const MyComputer: u32 = 0x1008ff33;
+pub const VendorHome: u32 = 0x1008ff34;
+pub const LightBulb: u32 = 0x1008ff35;
+pub const Shop: u32 = 0x1008ff36;
+pub const History: u32 = 0x1008ff37;
+pub const OpenURL: u32 = 0x1008ff38;
+pub const AddFavorite: u32 = 0x1008ff39;
+pub const HotLinks: u32 = 0x1008ff3a;
+pub const BrightnessAdjust: u32 = 0x1008ff3b;
+pub const Finance: u32 = 0x1008ff3c;
+pub const Community: u32 = 0x1008ff3d;
+pub const AudioRewind: u32 = 0x1008ff3e;
+pub const BackForward: u32 = 0x1008ff3f;
+pub const Launch0: u32 = 0x1008ff40;
+pub const Launch1: u32 = 0x1008ff41;
+pub const Launch2: u32 = 0x1008ff42;
+pub const Launch3: u32 = 0x1008ff43;
+pub const Launch4: u32 = 0x1008ff44;
+pub const Launch5: u32 = 0x1008ff45;
+pub const Launch6: u32 = 0x1008ff46;
+pub const Launch7: u32 = 0x1008ff47;
+pub const Launch8: u32 = 0x1008ff48;
+pub const Launch9: u32 = 0x1008ff49;
+pub const LaunchA: u32 = 0x1008ff4a;
+pub const LaunchB: u32 = 0x1008ff4b;
+pub const LaunchC: u32 = 0x1008ff4c;
+pub const LaunchD: u32 = 0x1008ff4d;
+pub const LaunchE: u32 = 0x1008ff4e;
+pub const LaunchF: u32 = 0x1008ff4f;
+pub const ApplicationLeft: u32 = 0x1008ff50;
+pub const ApplicationRight: u32 = 0x1008ff51;
+pub const Book: u32 = 0x1008ff52;
+pub const CD: u32 = 0x1008ff53;
+pub const Calculater: u32 = 0x1008ff54;
+pub const WindowClear: u32 = 0x1008ff55;
+pub const Close: u32 = 0x1008ff56;
+pub const Copy: u32 = 0x1008ff57;
+pub const Cut: u32 = 0x1008ff58;
+pub const Display: u32 = 0x1008ff59;
+pub const DOS: u32 = 0x1008ff5a;
+pub const Documents: u32 = 0x1008ff5b;
+pub const Excel: u32 = 0x1008ff5c;
+pub const Explorer: u32 = 0x1008ff5d;
+pub const Game: u32 = 0x1008ff5e;
+pub const Go: u32 = 0x1008ff5f;
+pub const iTouch: u32 = 0x1008ff60;
+pub const LogOff: u32 = 0x1008ff61;
+pub const Market: u32 = 0x1008ff62;
+pub const Meeting: u32 = 0x1008ff63;
+pub const MenuKB: u32 = 0x1008ff65;
+pub const MenuPB: u32 = 0x1008ff66;
+pub const MySites: u32 = 0x1008ff67;
+pub const New: u32 = 0x1008ff68;
+pub const News: u32 = 0x1008ff69;
+pub const OfficeHome: u32 = 0x1008ff6a;
+pub const Open: u32 = 0x1008ff6b;
+pub const Option: u32 = 0x1008ff6c;
+pub const Paste: u32 = 0x1008ff6d;
+pub const Phone: u32 = 0x1008ff6e;
+pub const Reply: u32 = 0x1008ff72;
+pub const Reload: u32 = 0x1008ff73;
+pub const RotateWindows: u32 = 0x1008ff74;
+pub const RotationPB: u32 = 0x1008ff75;
+pub const RotationKB: u32 = 0x1008ff76;
+pub const Save: u32 = 0x1008ff77;
+pub const ScrollUp: u32 = 0x1008ff78;
+pub const ScrollDown: u32 = 0x1008ff79;
+pub const ScrollClick: u32 = 0x1008ff7a;
+pub const Send: u32 = 0x1008ff7b;
+pub const Spell: u32 = 0x1008ff7c;
+pub const SplitScreen: u32 = 0x1008ff7d;
+pub const Support: u32 = 0x1008ff7e;
+pub const TaskPane: u32 = 0x1008ff7f;
+pub const Terminal: u32 = 0x1008ff80;
+pub const Tools: u32 = 0x1008ff81;
+pub const Travel: u32 = 0x1008ff82;
+pub const UserPB: u32 = 0x1008ff84;
+pub const User1KB: u32 = 0x1008ff85;
+pub const User2KB: u32 = 0x1008ff86;
+pub const Video: u32 = 0x1008ff87;
+pub const WheelButton: u32 = 0x1008ff88;
+pub const Word: u32 = 0x1008ff89;
+pub const Xfer: u32 = 0x1008ff8a;
+pub const ZoomIn: u32 = 0x1008ff8b;
+pub const ZoomOut: u32 = 0x1008ff8c;
+pub const Away: u32 = 0x1008ff8d;
+pub const Messenger: u32 = 0x1008ff8e;
+pub const WebCam: u32 = 0x1008ff8f;
+pub const MailForward: u32 = 0x1008ff90;
+pub const Pictures: u32 = 0x1008ff91;
+pub const Music: u32 = 0x1008ff92;
+pub const Battery: u32 = 0x1008ff93;
+pub const Bluetooth: u32 = 0x1008ff94;
+pub const WLAN: u32 = 0x1008ff95;
+pub const UWB: u32 = 0x1008ff96;
+pub const AudioForward: u32 = 0x1008ff97;
+pub const AudioRepeat: u32 = 0x1008ff98;
+pub const AudioRandomPlay: u32 = 0x1008ff99;
+pub const Subtitle: u32 = 0x1008ff9a;
+pub const AudioCycleTrack: u32 = 0x1008ff9b;
+pub const CycleAngle: u32 = 0x1008ff9c;
+pub const FrameBack: u32 = 0x1008ff9d;
+pub const FrameForward: u32 = 0x1008ff9e;
+pub const Time: u32 = 0x1008ff9f;
+pub const SelectButton: u32 = 0x1008ffa0;
+pub const View: u32 = 0x1008ffa1;
+pub const TopMenu: u32 = 0x1008ffa2;
+pub const Red: u32 = 0x1008ffa3;
+pub const Green: u32 = 0x1008ffa4;
+pub const Yellow: u32 = 0x1008ffa5;
+pub const Blue: u32 = 0x1008ffa6;
+pub const Suspend: u32 = 0x1008ffa7;
+pub const Hibernate: u32 = 0x1008ffa8;
+pub const TouchpadToggle: u32 = 0x1008ffa9;
+pub const TouchpadOn: u32 = 0x1008ffb0;
+pub const TouchpadOff: u32 = 0x1008ffb1;
+pub const AudioMicMute: u32 = 0x1008ffb2;
+pub const Keyboard: u32 = 0x1008ffb3;
+pub const WWAN: u32 = 0x1008ffb4;
+pub const RFKill: u32 = 0x1008ffb5;
+pub const AudioPreset: u32 = 0x1008ffb6;
+pub const RotationLockToggle: u32 = 0x1008ffb7;
+pub const FullScreen: u32 = 0x1008ffb8;
+pub const Switch_VT_1: u32 = 0x1008fe01;
+pub const Switch_VT_2: u32 = 0x1008fe02;
+pub const Switch_VT_3: u32 = 0x1008fe03;
+pub const Switch_VT_4: u32 = 0x1008fe04;
+pub const Switch_VT_5: u32 = 0x1008fe05;
+pub const Switch_VT_6: u32 = 0x1008fe06;
+pub const Switch_VT_7: u32 = 0x1008fe07;
+pub const Switch_VT_8: u32 = 0x1008fe08;
+pub const Switch_VT_9: u32 = 0x1008fe09;
+pub const Switch_VT_10: u32 = 0x1008fe0a;
+pub const Switch_VT_11: u32 = 0x1008fe0b;
+pub const Switch_VT_12: u32 = 0x1008fe0c;
+pub const Ungrab: u32 = 0x1008fe20;
+pub const ClearGrab: u32 = 0x1008fe21;
+pub const Next_VMode: u32 = 0x1008fe22;
+pub const Prev_VMode: u32 = 0x1008fe23;
+pub const LogWindowTree: u32 = 0x1008fe24;
+pub const LogGrabInfo: u32 = 0x1008fe25;
@@ -0,0 +1,79 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Keyboard Handling Functions
+
+use glib::translate::{FromGlibPtr, ToGlibPtr};
+use glib::to_bool;
+use gdk::ffi;
+use libc::c_uint;
+use std::char;
+
+pub mod constants;
+
+pub fn keyval_name(keyval: u32) -> Option<String> {
+    unsafe {
+        FromGlibPtr::borrow(
+            ffi::gdk_keyval_name(keyval as c_uint))
+    }
+}
+
+/// Converts a key name to a keyval. The name is case sensitive, returns None if the name isn't
+/// a valid key name.
+pub fn keyval_from_name(keyval_name: &str) -> Option<u32> {
+    match unsafe { ffi::gdk_keyval_from_name(keyval_name.borrow_to_glib().0) } {
+        0xffffff => None, // GDK_KEY_VoidSymbol
+        keyval => Some(keyval)
+    }
+}
+
+/// Returns the lowercase and uppercase versions of the keyval.
+pub fn keyval_convert_case(symbol: u32) -> (u32, u32) {
+    let mut lower : c_uint = 0;
+    let mut upper : c_uint = 0;
+
+    unsafe { ffi::gdk_keyval_convert_case(symbol as c_uint, &mut lower, &mut upper) };
+    (lower, upper)
+}
+
+pub fn keyval_to_upper(keyval: u32) -> u32 {
+    unsafe { ffi::gdk_keyval_to_upper(keyval as c_uint) }
+}
+
+pub fn keyval_to_lower(keyval: u32) -> u32 {
+    unsafe { ffi::gdk_keyval_to_lower(keyval as c_uint) }
+}
+
+pub fn keyval_is_upper(keyval: u32) -> bool {
+    unsafe { to_bool(ffi::gdk_keyval_is_upper(keyval as c_uint)) }
+}
+
+pub fn keyval_is_lower(keyval: u32) -> bool {
+    unsafe { to_bool(ffi::gdk_keyval_is_lower(keyval as c_uint)) }
+}
+
+/// Converts a keyval to the character it produces, None if it has no character equivalent.
+pub fn keyval_to_unicode(keyval: u32) -> Option<char> {
+    match unsafe { ffi::gdk_keyval_to_unicode(keyval as c_uint) } {
+        0 => None,
+        c => char::from_u32(c)
+    }
+}
+
+/// Converts a character to a keyval. Characters without a keysym equivalent are mapped to
+/// `character as u32 | 0x01000000`.
+pub fn unicode_to_keyval(c: char) -> u32 {
+    unsafe { ffi::gdk_unicode_to_keyval(c as u32) }
+}
@@ -26,6 +26,9 @@ pub use self::rt::{
     get_display_arg_name,
     notify_startup_complete,
     notify_startup_complete_with_id,
+    notify_startup_complete_for,
+    get_startup_id,
+    STARTUP_ID_ENV,
     get_program_class,
     set_program_class,
     flush,
@@ -43,7 +46,12 @@ pub use self::rt::set_allowed_backends;
 
 pub use self::events::{
     EventType,
+    EventTrait,
     Event,
+    EventKind,
+    OwnedEvent,
+    EventSequence,
+    events_pending,
     EventAny,
     EventExpose,
     EventVisibility,
@@ -74,16 +82,17 @@ pub use self::events::{
 
 pub use gdk_ffi::enums::modifier_intent::ModifierIntent;
 pub use gdk_ffi::enums::modifier_type::ModifierType;
+pub use gdk_ffi::enums::drag_action::DragAction;
+pub use gdk_ffi::enums::event_mask::EventMask;
+pub use gdk_ffi::enums::window_hints::WindowHints;
 pub use gdk_ffi::enums::{
     self,
     WindowType,
     WindowState,
     WindowEdge,
-    WindowHints,
     WindowTypeHint,
     FullscreenMode,
     WMDecoration,
-    EventMask,
     InputSource,
     InputMode,
     AxisUse,
@@ -94,33 +103,49 @@ pub use gdk_ffi::enums::{
     CursorType,
     PixbufAlphaMode,
     PixbufError,
+    InterpType,
+    PixbufRotation,
     ColorSpace,
     FrameClockPhase,
     WindowWindowClass,
     Gravity,
     WMFunction,
-    DragAction,
     DragProtocol
 };
 
 pub use self::widgets::{
     Color,
     RGBA,
+    ColorTrait,
     Device,
+    DeviceGrab,
+    SeatGrab,
+    TimeCoord,
     Display,
     Atom,
     Screen,
     Rectangle,
     Window,
+    WindowRef,
     Visual,
     DeviceManager,
     Cursor,
+    CursorCache,
     Pixbuf,
+    PixbufAnimation,
+    PixbufAnimationIter,
+    PixbufFormat,
+    PixbufLoader,
     Point,
     DisplayManager,
     WindowAttr,
     DragContext,
-    AppLaunchContext
+    Geometry,
+    AppLaunchContext,
+    Keymap,
+    KeymapKey,
+    Monitor,
+    MonitorIter
 };
 #[cfg(feature = "GTK_3_8")]
 pub use self::widgets::{
@@ -128,12 +153,37 @@ pub use self::widgets::{
     FrameTimings,
 };
 
+pub use self::cairo_interaction::{
+    cairo_set_source_pixbuf,
+    cairo_set_source_rgba,
+    cairo_set_source_window,
+    cairo_rectangle,
+    cairo_region,
+    cairo_get_clip_rectangle,
+    cairo_region_create_from_surface,
+    pixbuf_get_from_window,
+    pixbuf_get_from_surface
+};
+#[cfg(feature = "GTK_3_10")]
+pub use self::cairo_interaction::cairo_surface_create_from_pixbuf;
+
 pub use self::keys::{
-    keyval_name
+    keyval_name,
+    keyval_from_name,
+    keyval_convert_case,
+    keyval_to_upper,
+    keyval_to_lower,
+    keyval_is_upper,
+    keyval_is_lower,
+    keyval_to_unicode,
+    unicode_to_keyval
 };
 
 mod events;
 mod rt;
-mod keys;
+pub mod keys;
+mod cairo_interaction;
 pub mod widgets;
+#[cfg(feature = "X11")]
+pub mod x11;
 
@@ -16,6 +16,7 @@
 //! General — Library initialization and miscellaneous functions
 
 use std::ptr;
+use std::env;
 use glib::translate::{FromGlibPtr, ToGlibPtr};
 use gdk::ffi;
 
@@ -48,6 +49,31 @@ pub fn notify_startup_complete_with_id(startup_id: &str) {
     }
 }
 
+/// Name of the environment variable a launcher gives the startup ID of an application in.
+pub const STARTUP_ID_ENV: &'static str = "DESKTOP_STARTUP_ID";
+
+/// Gets the startup ID given by the launcher of the application, without removing it from the
+/// environment.
+///
+/// GDK reads and removes the variable itself when the display is opened, to set the startup ID
+/// and the user time of the first toplevel and complete the notification when it is shown. After
+/// `gtk::init` the ID is given by `X11Display::get_startup_notification_id` instead.
+pub fn get_startup_id() -> Option<String> {
+    match env::var(STARTUP_ID_ENV) {
+        Ok(ref id) if !id.is_empty() => Some(id.clone()),
+        _ => None
+    }
+}
+
+/// Ends the startup notification of `startup_id` (as returned by `get_startup_id`), or the
+/// one of the application if None.
+pub fn notify_startup_complete_for(startup_id: Option<&str>) {
+    match startup_id {
+        Some(id) => notify_startup_complete_with_id(id),
+        None => notify_startup_complete()
+    }
+}
+
 #[cfg(feature = "GTK_3_10")]
 pub fn set_allowed_backends(backends: &str) {
     unsafe {
@@ -96,7 +122,9 @@ pub fn error_trap_push() {
     unsafe { ffi::gdk_error_trap_push() }
 }
 
-pub fn error_trap_pop() {
+/// Removes an error trap pushed with `error_trap_push`, returns the X error code of the last
+/// error caught or 0.
+pub fn error_trap_pop() -> i32 {
     unsafe { ffi::gdk_error_trap_pop() }
 }
 
@@ -26,6 +26,24 @@ pub struct AppLaunchContext {
 }
 
 impl AppLaunchContext {
+    /// Gets a launch context for the default display.
+    pub fn new() -> Option<AppLaunchContext> {
+        unsafe {
+            let display = ffi::gdk_display_get_default();
+
+            if display.is_null() {
+                return None;
+            }
+            let tmp = ffi::gdk_display_get_app_launch_context(display);
+
+            if tmp.is_null() {
+                None
+            } else {
+                Some(AppLaunchContext { pointer: tmp })
+            }
+        }
+    }
+
     pub fn set_screen(&self, screen: &gdk::Screen) {
         unsafe { ffi::gdk_app_launch_context_set_screen(self.pointer, screen.unwrap_pointer()) }
     }
@@ -49,4 +67,10 @@ impl AppLaunchContext {
     }
 }
 
+impl Drop for AppLaunchContext {
+    fn drop(&mut self) {
+        unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) }
+    }
+}
+
 impl_GObjectFunctions!(AppLaunchContext, C_GdkAppLaunchContext);
\ No newline at end of file
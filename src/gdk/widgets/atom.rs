@@ -16,6 +16,10 @@
 use gdk::ffi;
 use glib::translate::{FromGlibPtr, ToGlibPtr};
 
+/// An interned string identifying a selection, a target or a property. Two atoms are equal if
+/// they represent the same string.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Atom {
     pointer: ffi::C_GdkAtom
 }
@@ -62,6 +66,35 @@ impl Atom {
         }
     }
 
+    /// GDK_NONE, the null atom.
+    pub fn none() -> Atom {
+        Atom::wrap_pointer(0 as ffi::C_GdkAtom)
+    }
+
+    /// The PRIMARY selection, which holds the currently selected text (middle-click paste on X11).
+    pub fn selection_primary() -> Atom {
+        Atom::wrap_pointer(1 as ffi::C_GdkAtom)
+    }
+
+    /// The SECONDARY selection.
+    pub fn selection_secondary() -> Atom {
+        Atom::wrap_pointer(2 as ffi::C_GdkAtom)
+    }
+
+    /// The CLIPBOARD selection, used by explicit copy and paste.
+    pub fn selection_clipboard() -> Atom {
+        Atom::wrap_pointer(69 as ffi::C_GdkAtom)
+    }
+
+    /// The STRING target.
+    pub fn target_string() -> Atom {
+        Atom::wrap_pointer(31 as ffi::C_GdkAtom)
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.pointer.is_null()
+    }
+
     // I can't use the gObject macros for this object
     pub fn wrap_pointer(pointer: ffi::C_GdkAtom) -> Atom {
         Atom {
@@ -0,0 +1,76 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Colors — Colors of the deprecated GdkColor APIs
+
+use glib::to_bool;
+use glib::translate::{FromGlibPtr, ToGlibPtr};
+use gdk_ffi as ffi;
+use gdk_ffi::{C_GdkColor, C_GdkRGBA};
+use pango;
+use super::rgba::RGBA;
+
+/// Helpers for `gdk::Color`, the 16 bits per channel opaque color used by the deprecated APIs
+/// (such as `modify_fg`). New code should use `gdk::RGBA`.
+pub trait ColorTrait {
+    fn new(red: u16, green: u16, blue: u16) -> C_GdkColor;
+    /// Parses a color name from the X11 rgb.txt file or a hexadecimal value (`#rgb`, `#rrggbb`,
+    /// `#rrrgggbbb` or `#rrrrggggbbbb`).
+    fn from_str(spec: &str) -> Option<C_GdkColor>;
+    /// Returns the color in the form `#rrrrggggbbbb`.
+    fn to_string(&self) -> Option<String>;
+    /// Converts the color to an opaque RGBA, same as `RGBA::from_color`.
+    fn to_rgba(&self) -> C_GdkRGBA;
+    fn to_pango_color(&self) -> pango::Color;
+}
+
+impl ColorTrait for C_GdkColor {
+    fn new(red: u16, green: u16, blue: u16) -> C_GdkColor {
+        C_GdkColor {
+            pixel: 0,
+            red: red,
+            green: green,
+            blue: blue
+        }
+    }
+
+    fn from_str(spec: &str) -> Option<C_GdkColor> {
+        let mut color = <C_GdkColor as ColorTrait>::new(0, 0, 0);
+
+        if unsafe { to_bool(ffi::gdk_color_parse(spec.borrow_to_glib().0, &mut color)) } {
+            Some(color)
+        } else {
+            None
+        }
+    }
+
+    fn to_string(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::take(ffi::gdk_color_to_string(self))
+        }
+    }
+
+    fn to_rgba(&self) -> C_GdkRGBA {
+        <C_GdkRGBA as RGBA>::from_color(self)
+    }
+
+    fn to_pango_color(&self) -> pango::Color {
+        pango::Color {
+            red: self.red,
+            green: self.green,
+            blue: self.blue
+        }
+    }
+}
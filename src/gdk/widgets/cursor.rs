@@ -15,9 +15,14 @@
 
 //! Cursors — Standard and pixmap cursors
 
+use std::collections::HashMap;
 use gdk::{self, ffi};
 use glib::translate::ToGlibPtr;
-//use libc::{c_int};
+use libc::c_int;
+#[cfg(feature = "GTK_3_10")]
+use libc::c_double;
+#[cfg(feature = "GTK_3_10")]
+use cairo;
 
 #[repr(C)]
 pub struct Cursor {
@@ -37,7 +42,9 @@ impl Cursor {
         }
     }
 
-    /*pub fn new_from_pixbuf(display: &gdk::Display, pixbuf: &gdk::Pixbuf, x: i32, y: i32) -> Option<Cursor> {
+    /// Creates a cursor from a pixbuf, `x` and `y` are the position of the hotspot in the pixbuf.
+    /// The hotspot can also be given by the "x_hot" and "y_hot" options of the pixbuf.
+    pub fn new_from_pixbuf(display: &gdk::Display, pixbuf: &gdk::Pixbuf, x: i32, y: i32) -> Option<Cursor> {
         let tmp = unsafe { ffi::gdk_cursor_new_from_pixbuf(display.unwrap_pointer(), pixbuf.unwrap_pointer(), x as c_int, y as c_int) };
 
         if tmp.is_null() {
@@ -47,8 +54,26 @@ impl Cursor {
                 pointer: tmp
             })
         }
-    }*/
+    }
+
+    /// Creates a cursor from a cairo image surface, its device scale is used for HiDPI displays.
+    #[cfg(feature = "GTK_3_10")]
+    pub fn new_from_surface(display: &gdk::Display, surface: &cairo::Surface, x: f64, y: f64) -> Option<Cursor> {
+        let tmp = unsafe {
+            ffi::gdk_cursor_new_from_surface(display.unwrap_pointer(), surface.get_ptr(), x as c_double, y as c_double)
+        };
 
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Cursor {
+                pointer: tmp
+            })
+        }
+    }
+
+    /// Creates a cursor from a name of the cursor theme, such as the CSS cursor names ("pointer",
+    /// "text", "crosshair", "grab"...). Returns None if the theme doesn't have it.
     pub fn new_from_name(display: &gdk::Display, name: &str) -> Option<Cursor> {
         let tmp = unsafe {
             ffi::gdk_cursor_new_from_name(display.unwrap_pointer(), name.borrow_to_glib().0)
@@ -85,7 +110,8 @@ impl Cursor {
         }
     }
 
-    /*pub fn get_image(&self) -> Option<gdk::Pixbuf> {
+    /// Returns the image of the cursor, None if it can't be represented as a pixbuf.
+    pub fn get_image(&self) -> Option<gdk::Pixbuf> {
         let tmp = unsafe { ffi::gdk_cursor_get_image(self.pointer) };
 
         if tmp.is_null() {
@@ -93,11 +119,132 @@ impl Cursor {
         } else {
             Some(gdk::Pixbuf::wrap_pointer(tmp))
         }
-    }*/
+    }
+
+    /// Returns the image of the cursor as a cairo surface along with its hotspot.
+    #[cfg(feature = "GTK_3_10")]
+    pub fn get_surface(&self) -> Option<(cairo::Surface, f64, f64)> {
+        let mut x_hot = 0.;
+        let mut y_hot = 0.;
+        let tmp = unsafe { ffi::gdk_cursor_get_surface(self.pointer, &mut x_hot, &mut y_hot) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some((cairo::Surface::wrap(tmp), x_hot, y_hot))
+        }
+    }
 
     pub fn get_cursor_type(&self) -> gdk::CursorType {
         unsafe { ffi::gdk_cursor_get_cursor_type(self.pointer) }
     }
 }
 
-impl_GObjectFunctions!(Cursor, C_GdkCursor);
\ No newline at end of file
+impl Clone for Cursor {
+    fn clone(&self) -> Cursor {
+        unsafe { ::glib::ffi::g_object_ref(self.pointer as *mut ::glib::ffi::C_GObject) };
+
+        Cursor {
+            pointer: self.pointer
+        }
+    }
+}
+
+impl Drop for Cursor {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
+
+impl_GObjectFunctions!(Cursor, C_GdkCursor);
+
+/// Keeps the cursors of a display alive so they are only created once, setting a cached cursor
+/// on a window is then cheap (on hover for example).
+///
+/// Standard and named cursors are created on first use, custom cursors are registered with
+/// `insert` under a name of your choice.
+pub struct CursorCache {
+    display: gdk::Display,
+    types: HashMap<gdk::CursorType, Cursor>,
+    names: HashMap<String, Option<Cursor>>
+}
+
+impl CursorCache {
+    pub fn new(display: &gdk::Display) -> CursorCache {
+        CursorCache {
+            display: gdk::Display::wrap_pointer(display.unwrap_pointer()),
+            types: HashMap::new(),
+            names: HashMap::new()
+        }
+    }
+
+    /// Creates a cache for the default display.
+    pub fn get_default() -> Option<CursorCache> {
+        gdk::Display::get_default().map(|display| CursorCache::new(&display))
+    }
+
+    pub fn get_display(&self) -> &gdk::Display {
+        &self.display
+    }
+
+    /// Returns the standard cursor of the given type.
+    pub fn get(&mut self, cursor_type: gdk::CursorType) -> Option<&Cursor> {
+        if !self.types.contains_key(&cursor_type) {
+            match Cursor::new_for_display(&self.display, cursor_type) {
+                Some(cursor) => { self.types.insert(cursor_type, cursor); }
+                None => return None
+            }
+        }
+        self.types.get(&cursor_type)
+    }
+
+    /// Returns the cursor registered with `insert` under this name, or the cursor of the theme
+    /// with this name. A name missing from the theme is remembered as such.
+    pub fn get_by_name(&mut self, name: &str) -> Option<&Cursor> {
+        if !self.names.contains_key(name) {
+            let cursor = Cursor::new_from_name(&self.display, name);
+            self.names.insert(name.to_string(), cursor);
+        }
+        match self.names.get(name) {
+            Some(&Some(ref cursor)) => Some(cursor),
+            _ => None
+        }
+    }
+
+    /// Registers a custom cursor under `name`, replacing the previous one.
+    pub fn insert(&mut self, name: &str, cursor: Cursor) {
+        self.names.insert(name.to_string(), Some(cursor));
+    }
+
+    /// Creates a cursor from a pixbuf and registers it under `name`.
+    pub fn insert_pixbuf(&mut self, name: &str, pixbuf: &gdk::Pixbuf, x: i32, y: i32) -> Option<&Cursor> {
+        match Cursor::new_from_pixbuf(&self.display, pixbuf, x, y) {
+            Some(cursor) => self.insert(name, cursor),
+            None => return None
+        }
+        self.get_by_name(name)
+    }
+
+    /// Creates a cursor from a cairo surface and registers it under `name`.
+    #[cfg(feature = "GTK_3_10")]
+    pub fn insert_surface(&mut self, name: &str, surface: &cairo::Surface, x: f64, y: f64) -> Option<&Cursor> {
+        match Cursor::new_from_surface(&self.display, surface, x, y) {
+            Some(cursor) => self.insert(name, cursor),
+            None => return None
+        }
+        self.get_by_name(name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Cursor> {
+        self.names.remove(name).and_then(|cursor| cursor)
+    }
+
+    /// Drops all the cached cursors.
+    pub fn clear(&mut self) {
+        self.types.clear();
+        self.names.clear();
+    }
+}
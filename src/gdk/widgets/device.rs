@@ -16,8 +16,8 @@
 //! GdkDevice — Object representing an input device
 
 use gdk::{self, ffi};
-use libc::{c_uint};
-use glib::to_bool;
+use libc::{c_int, c_uint};
+use glib::{to_bool, to_gboolean};
 use glib::translate::{FromGlibPtr};
 
 #[repr(C)]
@@ -77,7 +77,7 @@ impl Device {
         unsafe { ffi::gdk_device_get_device_type(self.pointer) }
     }
 
-    /*pub fn get_display(&self) -> Option<gdk::Display> {
+    pub fn get_display(&self) -> Option<gdk::Display> {
         let tmp = unsafe { ffi::gdk_device_get_display(self.pointer) };
 
         if tmp.is_null() {
@@ -85,7 +85,7 @@ impl Device {
         } else {
             Some(gdk::Display::wrap_pointer(tmp))
         }
-    }*/
+    }
 
     pub fn get_has_cursor(&self) -> bool {
         unsafe { to_bool(ffi::gdk_device_get_has_cursor(self.pointer)) }
@@ -99,22 +99,74 @@ impl Device {
         unsafe { ffi::gdk_device_get_n_keys(self.pointer) }
     }
 
-    /*pub fn warp(&self, screen: &gdk::Screen, x: i32, y: i32) {
+    /// Moves the pointer of the device to `x`, `y` on `screen`.
+    pub fn warp(&self, screen: &gdk::Screen, x: i32, y: i32) {
         unsafe { ffi::gdk_device_warp(self.pointer, screen.unwrap_pointer(), x as c_int, y as c_int) }
     }
 
+    /// Grabs the device so all its events are delivered to `window`, until the returned guard is
+    /// dropped or `ungrab` is called on it. If `owner_events` is true, events for the other
+    /// windows of the application are delivered as usual. `cursor` is shown during the grab, for
+    /// pointer devices. `time_` should be the time of the event starting the grab.
     pub fn grab(&self, window: &gdk::Window, grab_ownership: gdk::GrabOwnership, owner_events: bool, event_mask: gdk::EventMask,
-        cursor: &mut gdk::Cursor, time_: u32) -> gdk::GrabStatus {
-        unsafe {
+        cursor: Option<&gdk::Cursor>, time_: u32) -> Result<DeviceGrab, gdk::GrabStatus> {
+        let cursor = match cursor {
+            Some(cursor) => cursor.unwrap_pointer(),
+            None => ::std::ptr::null_mut()
+        };
+        let status = unsafe {
             ffi::gdk_device_grab(self.pointer, window.unwrap_pointer(), grab_ownership, to_gboolean(owner_events),
-                event_mask, cursor.unwrap_pointer(), time_)
+                event_mask, cursor, time_)
+        };
+
+        match status {
+            gdk::GrabStatus::Success => Ok(DeviceGrab { device: Device { pointer: self.pointer }, active: true }),
+            error => Err(error)
         }
-    }*/
+    }
+
+    /// Grabs the pointer and the keyboard of the seat of this master device (see `grab`). The
+    /// cursor applies to the pointer. Both are released when the returned guard is dropped, if
+    /// one of the grabs fails, none is kept. Fails with `GrabStatus::Failed` if the device has no
+    /// associated device (it isn't a master device).
+    pub fn grab_seat(&self, window: &gdk::Window, grab_ownership: gdk::GrabOwnership, owner_events: bool, event_mask: gdk::EventMask,
+        cursor: Option<&gdk::Cursor>, time_: u32) -> Result<SeatGrab, gdk::GrabStatus> {
+        let associated = match self.get_associated_device() {
+            Some(device) => device,
+            None => return Err(gdk::GrabStatus::Failed)
+        };
+        let (pointer, keyboard) = match self.get_source() {
+            gdk::InputSource::Keyboard => (associated, Device { pointer: self.pointer }),
+            _ => (Device { pointer: self.pointer }, associated)
+        };
+        let pointer_grab = try!(pointer.grab(window, grab_ownership, owner_events, event_mask, cursor, time_));
+        let keyboard_grab = try!(keyboard.grab(window, grab_ownership, owner_events, event_mask, None, time_));
+
+        Ok(SeatGrab {
+            pointer: pointer_grab,
+            keyboard: keyboard_grab
+        })
+    }
 
+    /// Releases a grab of the device, prefer dropping the guard returned by `grab`.
     pub fn ungrab(&self, time_: u32) {
         unsafe { ffi::gdk_device_ungrab(self.pointer, time_) }
     }
 
+    /// Returns the screen the pointer of the device is on, along with its position.
+    pub fn get_position(&self) -> Option<(gdk::Screen, i32, i32)> {
+        let mut screen = ::std::ptr::null_mut();
+        let mut x = 0;
+        let mut y = 0;
+
+        unsafe { ffi::gdk_device_get_position(self.pointer, &mut screen, &mut x, &mut y) };
+        if screen.is_null() {
+            None
+        } else {
+            Some((gdk::Screen::wrap_pointer(screen), x as i32, y as i32))
+        }
+    }
+
     /*pub fn get_state(&self, window: &gdk::Window, axes: &mut [f64], mask: &mut gdk;:ModifierType) {
         unsafe { ffi::gdk_device_get_state(self.pointer, window.unwrap_pointer(), axes.as_mut_ptr(), mask) }
     }
@@ -161,35 +213,57 @@ impl Device {
         } else {
             Some(gdk::Window::wrap_pointer(ptr))
         }
-    }
+    }*/
 
-    pub fn get_history(&self, window: &gdk::Window, start: u32, stop: u32) -> Vec<gdk::TimeCoord> {
-        let mut ptr = ::std::ptr::null_mut();
+    /// Returns the motion events of the device in `window` between the times `start` and `stop`
+    /// (inclusive), as stored by the windowing system. None if the device has no motion history,
+    /// which is the usual case: XInput 2 devices and the Wayland backend don't keep one. Smooth
+    /// strokes shouldn't depend on it, turn off the event compression of the window (see
+    /// `gdk::Window::set_event_compression`) to get every motion event instead.
+    pub fn get_history(&self, window: &gdk::Window, start: u32, stop: u32) -> Option<Vec<TimeCoord>> {
+        let mut events = ::std::ptr::null_mut();
         let mut n_events : c_int = 0;
 
-        unsafe { ffi::gdk_device_get_history(self.pointer, window.unwrap_pointer(), start, stop, &mut ptr, &mut n_events) };
-        
-        let mut ret = Vec::with_capacity(n_events as uint);
-        
-        for i in range(0, n_events) {
-            ret.push(gdk::TimeCoord::wrap_pointer(::std::ptr::read(ptr.offset(i))));
+        if !unsafe { to_bool(ffi::gdk_device_get_history(self.pointer, window.unwrap_pointer(), start, stop,
+                                                         &mut events, &mut n_events)) } {
+            return None;
         }
-        ret
-    }
 
-    pub fn free_history(events: &[gdk::TimeCoord]) {
-        let mut tmp = Vec::with_capacity(events.len());
+        let n_axes = ::std::cmp::min(self.get_n_axes() as usize, ffi::GDK_MAX_TIMECOORD_AXES);
+        let mut ret = Vec::with_capacity(n_events as usize);
+
+        for i in 0..n_events {
+            unsafe {
+                let event = &**events.offset(i as isize);
 
-        for i in range(0, events.len()) {
-            tmp.push(events[i].unwrap_pointer());
+                ret.push(TimeCoord {
+                    time: event.time,
+                    axes: event.axes[..n_axes].to_vec()
+                });
+            }
         }
-        unsafe { ffi::gdk_device_free_history(events.as_mut_ptr(), events.len()) }
-    }*/
+        unsafe { ffi::gdk_device_free_history(events, n_events) };
+        Some(ret)
+    }
 
     pub fn get_axis(&self, axes: &mut [f64], use_: gdk::AxisUse, value: &mut f64) -> bool {
         unsafe { to_bool(ffi::gdk_device_get_axis(self.pointer, axes.as_mut_ptr(), use_, value)) }
     }
 
+    /// Returns the axes of the device which are used as stylus axes (pressure, tilt and wheel).
+    pub fn get_stylus_axes(&self) -> Vec<gdk::AxisUse> {
+        let mut ret = Vec::new();
+
+        for i in 0..self.get_n_axes() {
+            match self.get_axis_use(i as u32) {
+                use_ @ gdk::AxisUse::Pressure | use_ @ gdk::AxisUse::XTilt
+                    | use_ @ gdk::AxisUse::YTilt | use_ @ gdk::AxisUse::Wheel => ret.push(use_),
+                _ => {}
+            }
+        }
+        ret
+    }
+
     /*pub fn get_axis_value(&self, axes: &mut [f64], label: &mut gdk::Atom, value: &mut f64) -> bool {
         unsafe { to_bool(ffi::gdk_device_get_axis_value(self.pointer, axes.as_mut_ptr(), label.unwrap_pointer(), value)) }
     }*/
@@ -206,3 +280,93 @@ impl Device {
 }
 
 impl_GObjectFunctions!(Device, C_GdkDevice);
+
+/// A motion event of the history of a device, see `Device::get_history`.
+#[derive(Clone, Debug)]
+pub struct TimeCoord {
+    time: u32,
+    axes: Vec<f64>
+}
+
+impl TimeCoord {
+    /// Returns the timestamp of the event.
+    pub fn get_time(&self) -> u32 {
+        self.time
+    }
+
+    /// Returns the raw values of the axes, use `Device::get_axis_use` for their meaning.
+    pub fn get_axes(&self) -> &[f64] {
+        &self.axes
+    }
+
+    /// Returns the value of the axis `use_` of `device` (the device of the history), like
+    /// `gdk::AxisUse::Pressure`.
+    pub fn get_axis(&self, device: &Device, use_: gdk::AxisUse) -> Option<f64> {
+        let mut axes = self.axes.clone();
+        let mut value = 0.;
+
+        if device.get_n_axes() as usize <= axes.len() && device.get_axis(&mut axes, use_, &mut value) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position of the event relative to the window given to `Device::get_history`.
+    pub fn get_position(&self, device: &Device) -> Option<(f64, f64)> {
+        match (self.get_axis(device, gdk::AxisUse::X), self.get_axis(device, gdk::AxisUse::Y)) {
+            (Some(x), Some(y)) => Some((x, y)),
+            _ => None
+        }
+    }
+}
+
+/// A grab of a device, released when dropped.
+pub struct DeviceGrab {
+    device: Device,
+    active: bool
+}
+
+impl DeviceGrab {
+    pub fn get_device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Releases the grab at `time_`, instead of `GDK_CURRENT_TIME` when dropped.
+    pub fn ungrab(mut self, time_: u32) {
+        self.device.ungrab(time_);
+        self.active = false;
+    }
+}
+
+impl Drop for DeviceGrab {
+    fn drop(&mut self) {
+        if self.active {
+            self.device.ungrab(0);
+            self.active = false;
+        }
+    }
+}
+
+/// A grab of both the pointer and the keyboard of a seat, released when dropped.
+pub struct SeatGrab {
+    pointer: DeviceGrab,
+    keyboard: DeviceGrab
+}
+
+impl SeatGrab {
+    pub fn get_pointer(&self) -> &Device {
+        self.pointer.get_device()
+    }
+
+    pub fn get_keyboard(&self) -> &Device {
+        self.keyboard.get_device()
+    }
+
+    pub fn ungrab(self, time_: u32) {
+        let SeatGrab { pointer, keyboard } = self;
+
+        keyboard.ungrab(time_);
+        pointer.ungrab(time_);
+    }
+}
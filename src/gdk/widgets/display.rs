@@ -113,29 +113,17 @@ impl Display {
         unsafe { to_bool(ffi::gdk_display_is_closed(self.pointer)) }
     }
 
-    /*pub fn get_event(&self) -> Option<gdk::Event> {
-        let tmp = unsafe { ffi::gdk_display_get_event(self.pointer) };
-
-        if tmp.is_null() {
-            None
-        } else {
-            unsafe { Some(gdk::Event::wrap(tmp)) }
-        }
+    pub fn get_event(&self) -> Option<gdk::OwnedEvent> {
+        gdk::OwnedEvent::wrap_pointer(unsafe { ffi::gdk_display_get_event(self.pointer) })
     }
 
-    pub fn peek_event(&self) -> Option<gdk::Event> {
-        let tmp = unsafe { ffi::gdk_display_peek_event(self.pointer) };
-
-        if tmp.is_null() {
-            None
-        } else {
-            unsafe { Some(gdk::Event::wrap(tmp)) }
-        }
+    pub fn peek_event(&self) -> Option<gdk::OwnedEvent> {
+        gdk::OwnedEvent::wrap_pointer(unsafe { ffi::gdk_display_peek_event(self.pointer) })
     }
 
     pub fn put_event(&self, event: &gdk::Event) {
         unsafe { ffi::gdk_display_put_event(self.pointer, event.unwrap_pointer() as *const ffi::C_GdkEvent) }
-    }*/
+    }
 
     pub fn has_pending(&self) -> bool {
         unsafe { to_bool(ffi::gdk_display_has_pending(self.pointer)) }
@@ -204,15 +192,15 @@ impl Display {
         unsafe { to_bool(ffi::gdk_display_supports_composite(self.pointer)) }
     }
 
-    /*pub fn get_app_launch_context(&self) -> Option<gdk::AppLaunchContext> {
+    pub fn get_app_launch_context(&self) -> Option<gdk::AppLaunchContext> {
         let tmp = unsafe { ffi::gdk_display_get_app_launch_context(self.pointer) };
 
         if tmp.is_null() {
             None
         } else {
-            unsafe { Some(gdk::AppLaunchContext::wrap(tmp)) }
+            Some(gdk::AppLaunchContext::wrap_pointer(tmp))
         }
-    }*/
+    }
 
     pub fn notify_startup_complete(&self, startup_id: &str) {
         unsafe {
@@ -222,3 +210,15 @@ impl Display {
 }
 
 impl_GObjectFunctions!(Display, C_GdkDisplay);
+
+impl ::glib::traits::FFIGObject for Display {
+    fn unwrap_gobject(&self) -> *mut ::glib::ffi::C_GObject {
+        self.pointer as *mut ::glib::ffi::C_GObject
+    }
+
+    fn wrap_object(object: *mut ::glib::ffi::C_GObject) -> Display {
+        Display { pointer: object as *mut ffi::C_GdkDisplay }
+    }
+}
+
+impl_connect!(Display -> Opened, Closed);
@@ -72,4 +72,16 @@ impl FrameClock {
     }
 }
 
-impl_GObjectFunctions!(FrameClock, C_GdkFrameClock);
\ No newline at end of file
+impl_GObjectFunctions!(FrameClock, C_GdkFrameClock);
+
+impl ::glib::traits::FFIGObject for FrameClock {
+    fn unwrap_gobject(&self) -> *mut ::glib::ffi::C_GObject {
+        self.pointer as *mut ::glib::ffi::C_GObject
+    }
+
+    fn wrap_object(object: *mut ::glib::ffi::C_GObject) -> FrameClock {
+        FrameClock { pointer: object as *mut ffi::C_GdkFrameClock }
+    }
+}
+
+impl_connect!(FrameClock -> FlushEvents, BeforePaint, Update, Layout, Paint, AfterPaint, ResumeEvents);
\ No newline at end of file
@@ -0,0 +1,126 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Geometry — Size constraints of a window
+
+use gdk::{self, ffi};
+use gdk_ffi::enums::window_hints;
+use libc::c_int;
+
+/// Builder for the geometry hints of a window, each setter also sets the matching hint flag so
+/// only the given constraints are used.
+///
+/// ```ignore
+/// let geometry = gdk::Geometry::new().min_size(200, 100).resize_inc(10, 10);
+/// window.set_geometry_hints(None::<&gtk::Label>, &geometry);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Geometry {
+    geometry: ffi::C_GdkGeometry,
+    mask: gdk::WindowHints
+}
+
+impl Geometry {
+    pub fn new() -> Geometry {
+        Geometry {
+            geometry: ffi::C_GdkGeometry {
+                min_width: -1,
+                min_height: -1,
+                max_width: -1,
+                max_height: -1,
+                base_width: -1,
+                base_height: -1,
+                width_inc: 1,
+                height_inc: 1,
+                min_aspect: 0.,
+                max_aspect: 0.,
+                win_gravity: gdk::Gravity::NorthWest
+            },
+            mask: gdk::WindowHints::empty()
+        }
+    }
+
+    /// -1 uses the requisition of the window, with gtk::Window only.
+    pub fn min_size(mut self, width: i32, height: i32) -> Geometry {
+        self.geometry.min_width = width as c_int;
+        self.geometry.min_height = height as c_int;
+        self.mask = self.mask | window_hints::HintMinSize;
+        self
+    }
+
+    /// -1 uses the requisition of the window, with gtk::Window only.
+    pub fn max_size(mut self, width: i32, height: i32) -> Geometry {
+        self.geometry.max_width = width as c_int;
+        self.geometry.max_height = height as c_int;
+        self.mask = self.mask | window_hints::HintMaxSize;
+        self
+    }
+
+    /// The size the resize increments are added to.
+    pub fn base_size(mut self, width: i32, height: i32) -> Geometry {
+        self.geometry.base_width = width as c_int;
+        self.geometry.base_height = height as c_int;
+        self.mask = self.mask | window_hints::HintBaseSize;
+        self
+    }
+
+    /// Bounds of the width / height ratio.
+    pub fn aspect(mut self, min_aspect: f64, max_aspect: f64) -> Geometry {
+        self.geometry.min_aspect = min_aspect;
+        self.geometry.max_aspect = max_aspect;
+        self.mask = self.mask | window_hints::HintAspect;
+        self
+    }
+
+    /// The size of the window is base size + N * increment.
+    pub fn resize_inc(mut self, width_inc: i32, height_inc: i32) -> Geometry {
+        self.geometry.width_inc = width_inc as c_int;
+        self.geometry.height_inc = height_inc as c_int;
+        self.mask = self.mask | window_hints::HintResizeInc;
+        self
+    }
+
+    pub fn gravity(mut self, gravity: gdk::Gravity) -> Geometry {
+        self.geometry.win_gravity = gravity;
+        self.mask = self.mask | window_hints::HintWinGravity;
+        self
+    }
+
+    /// Indicates that the program has set the position of the window.
+    pub fn pos(mut self) -> Geometry {
+        self.mask = self.mask | window_hints::HintPos;
+        self
+    }
+
+    /// Indicates that the position of the window was explicitly set by the user.
+    pub fn user_pos(mut self) -> Geometry {
+        self.mask = self.mask | window_hints::HintUserPos;
+        self
+    }
+
+    /// Indicates that the size of the window was explicitly set by the user.
+    pub fn user_size(mut self) -> Geometry {
+        self.mask = self.mask | window_hints::HintUserSize;
+        self
+    }
+
+    pub fn get_hints(&self) -> gdk::WindowHints {
+        self.mask
+    }
+
+    pub fn get_geometry(&self) -> &ffi::C_GdkGeometry {
+        &self.geometry
+    }
+}
@@ -0,0 +1,163 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! GdkKeymap — Translation between hardware keycodes, keyvals and modifier states
+
+use gdk::{self, ffi};
+use glib::to_bool;
+use libc::{c_int, c_uint};
+use std::ptr;
+
+pub use gdk_ffi::C_GdkKeymapKey as KeymapKey;
+
+#[repr(C)]
+pub struct Keymap {
+    pointer: *mut ffi::C_GdkKeymap
+}
+
+impl Keymap {
+    /// Returns the keymap of the default display.
+    pub fn get_default() -> Option<Keymap> {
+        let tmp = unsafe { ffi::gdk_keymap_get_default() };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Keymap::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn get_for_display(display: &gdk::Display) -> Option<Keymap> {
+        let tmp = unsafe { ffi::gdk_keymap_get_for_display(display.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Keymap::wrap_pointer(tmp))
+        }
+    }
+
+    /// Looks up the keyval mapped to a keycode/group/level triplet. Returns 0 if no keyval is
+    /// mapped to the key.
+    pub fn lookup_key(&self, key: &KeymapKey) -> u32 {
+        unsafe { ffi::gdk_keymap_lookup_key(self.pointer, key) }
+    }
+
+    /// Translates the contents of a key event into a keyval, an effective group and a level.
+    /// The modifiers that affected the translation are returned as well, they should be masked
+    /// out of the event state before comparing it to an accelerator.
+    ///
+    /// Returns None if the keycode doesn't map to a keyval.
+    pub fn translate_keyboard_state(&self, hardware_keycode: u32, state: gdk::ModifierType, group: i32)
+        -> Option<(u32, i32, i32, gdk::ModifierType)> {
+        let mut keyval : c_uint = 0;
+        let mut effective_group : c_int = 0;
+        let mut level : c_int = 0;
+        let mut consumed_modifiers = gdk::ModifierType::empty();
+
+        if unsafe { to_bool(ffi::gdk_keymap_translate_keyboard_state(self.pointer, hardware_keycode as c_uint, state,
+            group as c_int, &mut keyval, &mut effective_group, &mut level, &mut consumed_modifiers)) } {
+            Some((keyval, effective_group, level, consumed_modifiers))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the keys that can generate the keyval. Usually the first one is the key to use
+    /// for an accelerator.
+    pub fn get_entries_for_keyval(&self, keyval: u32) -> Vec<KeymapKey> {
+        let mut keys = ptr::null_mut();
+        let mut n_keys : c_int = 0;
+
+        unsafe {
+            if to_bool(ffi::gdk_keymap_get_entries_for_keyval(self.pointer, keyval as c_uint, &mut keys, &mut n_keys)) {
+                let ret = ::std::slice::from_raw_parts(keys as *const KeymapKey, n_keys as usize).to_vec();
+
+                ::glib::ffi::g_free(keys as ::glib::ffi::gpointer);
+                ret
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    /// Returns the keys bound to the keycode along with the keyval they generate.
+    pub fn get_entries_for_keycode(&self, hardware_keycode: u32) -> Vec<(KeymapKey, u32)> {
+        let mut keys = ptr::null_mut();
+        let mut keyvals = ptr::null_mut();
+        let mut n_entries : c_int = 0;
+
+        unsafe {
+            if to_bool(ffi::gdk_keymap_get_entries_for_keycode(self.pointer, hardware_keycode as c_uint, &mut keys,
+                &mut keyvals, &mut n_entries)) {
+                let ret = ::std::slice::from_raw_parts(keys as *const KeymapKey, n_entries as usize).iter().cloned()
+                    .zip(::std::slice::from_raw_parts(keyvals as *const u32, n_entries as usize).iter().cloned())
+                    .collect();
+
+                ::glib::ffi::g_free(keys as ::glib::ffi::gpointer);
+                ::glib::ffi::g_free(keyvals as ::glib::ffi::gpointer);
+                ret
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    /// Returns true if there are layouts for both right-to-left and left-to-right languages.
+    pub fn have_bidi_layouts(&self) -> bool {
+        unsafe { to_bool(ffi::gdk_keymap_have_bidi_layouts(self.pointer)) }
+    }
+
+    pub fn get_caps_lock_state(&self) -> bool {
+        unsafe { to_bool(ffi::gdk_keymap_get_caps_lock_state(self.pointer)) }
+    }
+
+    pub fn get_num_lock_state(&self) -> bool {
+        unsafe { to_bool(ffi::gdk_keymap_get_num_lock_state(self.pointer)) }
+    }
+
+    /// Returns the current state of the modifiers.
+    #[cfg(feature = "GTK_3_4")]
+    pub fn get_modifier_state(&self) -> gdk::ModifierType {
+        gdk::ModifierType::from_bits_truncate(unsafe { ffi::gdk_keymap_get_modifier_state(self.pointer) })
+    }
+
+    /// Adds the virtual modifiers (Super, Hyper, Meta) matching the real modifiers set in `state`.
+    pub fn add_virtual_modifiers(&self, state: gdk::ModifierType) -> gdk::ModifierType {
+        let mut state = state;
+
+        unsafe { ffi::gdk_keymap_add_virtual_modifiers(self.pointer, &mut state) };
+        state
+    }
+
+    /// Maps the virtual modifiers set in `state` to real modifiers. Returns None if two virtual
+    /// modifiers are mapped to the same real modifier.
+    pub fn map_virtual_modifiers(&self, state: gdk::ModifierType) -> Option<gdk::ModifierType> {
+        let mut state = state;
+
+        if unsafe { to_bool(ffi::gdk_keymap_map_virtual_modifiers(self.pointer, &mut state)) } {
+            Some(state)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "GTK_3_4")]
+    pub fn get_modifier_mask(&self, intent: gdk::ModifierIntent) -> gdk::ModifierType {
+        unsafe { ffi::gdk_keymap_get_modifier_mask(self.pointer, intent) }
+    }
+}
+
+impl_GObjectFunctions!(Keymap, C_GdkKeymap);
@@ -16,23 +16,30 @@
 pub use self::app_launch_context::AppLaunchContext;
 pub use self::atom::Atom;
 pub use gdk_ffi::C_GdkColor as Color;
-pub use self::cursor::Cursor;
-pub use self::device::Device;
+pub use self::cursor::{Cursor, CursorCache};
+pub use self::device::{Device, DeviceGrab, SeatGrab, TimeCoord};
 pub use self::device_manager::DeviceManager;
 pub use self::display::Display;
 pub use self::display_manager::DisplayManager;
 pub use self::drag_context::DragContext;
+pub use self::geometry::Geometry;
+pub use self::keymap::{Keymap, KeymapKey};
+pub use self::monitor::{Monitor, MonitorIter};
 #[cfg(feature = "GTK_3_8")]
 pub use self::frame_clock::FrameClock;
 #[cfg(feature = "GTK_3_8")]
 pub use self::frame_timings::FrameTimings;
 pub use self::pixbuf::Pixbuf;
+pub use self::pixbuf_animation::{PixbufAnimation, PixbufAnimationIter};
+pub use self::pixbuf_format::PixbufFormat;
+pub use self::pixbuf_loader::PixbufLoader;
 pub use self::point::Point;
 pub use self::rectangle::Rectangle;
 pub use self::rgba::RGBA;
+pub use self::color::ColorTrait;
 pub use self::screen::Screen;
 pub use self::visual::Visual;
-pub use self::window::{WindowAttr, Window};
+pub use self::window::{WindowAttr, Window, WindowRef};
 
 mod app_launch_context;
 mod atom;
@@ -42,14 +49,21 @@ mod device_manager;
 mod display;
 mod display_manager;
 mod drag_context;
+mod geometry;
+mod keymap;
+mod monitor;
 #[cfg(feature = "GTK_3_8")]
 mod frame_clock;
 #[cfg(feature = "GTK_3_8")]
 mod frame_timings;
 mod pixbuf;
+mod pixbuf_animation;
+mod pixbuf_format;
+mod pixbuf_loader;
 mod point;
 mod rectangle;
 mod rgba;
+mod color;
 mod screen;
 mod visual;
 mod window;
@@ -0,0 +1,148 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Monitors — The outputs of a screen
+
+use gdk::{self, ffi};
+use gdk_ffi::C_GdkRectangle;
+
+/// A snapshot of the properties of a monitor. It is not updated when the configuration changes,
+/// get a new one from the "monitors-changed" signal of the screen.
+#[derive(Clone)]
+pub struct Monitor {
+    number: i32,
+    geometry: C_GdkRectangle,
+    workarea: C_GdkRectangle,
+    scale_factor: i32,
+    width_mm: i32,
+    height_mm: i32,
+    plug_name: Option<String>,
+    primary: bool
+}
+
+impl Monitor {
+    /// Queries the properties of the monitor `monitor_num` of the screen. Returns None if the
+    /// screen has no such monitor.
+    pub fn new(screen: &gdk::Screen, monitor_num: i32) -> Option<Monitor> {
+        if monitor_num < 0 || monitor_num >= screen.get_n_monitors() {
+            return None;
+        }
+
+        let mut geometry = C_GdkRectangle { x: 0, y: 0, width: 0, height: 0 };
+        let mut workarea = C_GdkRectangle { x: 0, y: 0, width: 0, height: 0 };
+
+        screen.get_monitor_geometry(monitor_num, &mut geometry);
+        screen.get_monitor_workarea(monitor_num, &mut workarea);
+
+        Some(Monitor {
+            number: monitor_num,
+            geometry: geometry,
+            workarea: workarea,
+            scale_factor: get_scale_factor(screen, monitor_num),
+            width_mm: screen.get_monitor_width_mm(monitor_num),
+            height_mm: screen.get_monitor_height_mm(monitor_num),
+            plug_name: screen.get_monitor_plug_name(monitor_num),
+            primary: screen.get_primary_monitor() == monitor_num
+        })
+    }
+
+    /// Returns the index of the monitor in the screen.
+    pub fn get_number(&self) -> i32 {
+        self.number
+    }
+
+    /// Returns the area of the screen covered by the monitor, in application pixels.
+    pub fn get_geometry(&self) -> ffi::C_GdkRectangle {
+        self.geometry
+    }
+
+    /// Returns the part of the monitor available for windows, without the panels and docks.
+    pub fn get_workarea(&self) -> ffi::C_GdkRectangle {
+        self.workarea
+    }
+
+    /// Returns the number of device pixels per application pixel. Always 1 without the
+    /// GTK_3_10 feature.
+    pub fn get_scale_factor(&self) -> i32 {
+        self.scale_factor
+    }
+
+    /// Returns the physical width of the monitor in millimeters, -1 if unknown.
+    pub fn get_width_mm(&self) -> i32 {
+        self.width_mm
+    }
+
+    /// Returns the physical height of the monitor in millimeters, -1 if unknown.
+    pub fn get_height_mm(&self) -> i32 {
+        self.height_mm
+    }
+
+    /// Returns the output name of the monitor ("HDMI-1" for instance), if known.
+    pub fn get_plug_name(&self) -> Option<&str> {
+        self.plug_name.as_ref().map(|s| &s[..])
+    }
+
+    pub fn is_primary(&self) -> bool {
+        self.primary
+    }
+}
+
+/// Iterator over the monitors of a screen, as returned by `Screen::get_monitors`.
+pub struct MonitorIter<'a> {
+    screen: &'a gdk::Screen,
+    current: i32,
+    n_monitors: i32
+}
+
+impl<'a> MonitorIter<'a> {
+    pub fn new(screen: &'a gdk::Screen) -> MonitorIter<'a> {
+        MonitorIter {
+            screen: screen,
+            current: 0,
+            n_monitors: screen.get_n_monitors()
+        }
+    }
+}
+
+impl<'a> Iterator for MonitorIter<'a> {
+    type Item = Monitor;
+
+    fn next(&mut self) -> Option<Monitor> {
+        if self.current >= self.n_monitors {
+            return None;
+        }
+
+        let ret = Monitor::new(self.screen, self.current);
+
+        self.current += 1;
+        ret
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.n_monitors - self.current) as usize;
+
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(feature = "GTK_3_10")]
+fn get_scale_factor(screen: &gdk::Screen, monitor_num: i32) -> i32 {
+    screen.get_monitor_scale_factor(monitor_num)
+}
+
+#[cfg(not(feature = "GTK_3_10"))]
+fn get_scale_factor(_screen: &gdk::Screen, _monitor_num: i32) -> i32 {
+    1
+}
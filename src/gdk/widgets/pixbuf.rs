@@ -15,9 +15,13 @@
 
 /// The GdkPixbuf structure contains information that describes an image in memory.
 
-use glib::translate::{FromGlibPtr, ToGlibPtr};
+use glib::translate::{FromGlibPtr, ToGlibPtr, Stash};
+use glib::{self, GlibContainer};
 use gdk::{self, ffi};
+use cairo;
+use super::pixbuf_loader::loader_error;
 use c_vec::CVec;
+use libc::{c_char, c_uchar, c_void, size_t};
 use std::ptr::Unique;
 
 #[repr(C)]
@@ -29,6 +33,361 @@ pub struct Pixbuf {
 }
 
 impl Pixbuf {
+    /// Creates a new Pixbuf structure and allocates a buffer for it. The buffer has an optimal
+    /// rowstride. Note that the buffer is not cleared, you will have to fill it completely yourself.
+    pub fn new(colorspace: gdk::ColorSpace, has_alpha: bool, bits_per_sample: i32, width: i32,
+               height: i32) -> Option<Pixbuf> {
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_new(colorspace, ::glib::to_gboolean(has_alpha), bits_per_sample, width, height)
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Creates a new Pixbuf out of in-memory image data owned by Rust. The data is moved into the
+    /// pixbuf and freed with it. Currently only RGB images with 8 bits per sample are supported.
+    /// Returns None if `bits_per_sample` isn't 8 or `data` is too short for the given dimensions.
+    pub fn new_from_vec(data: Vec<u8>, colorspace: gdk::ColorSpace, has_alpha: bool, bits_per_sample: i32,
+                        width: i32, height: i32, rowstride: i32) -> Option<Pixbuf> {
+        if bits_per_sample != 8 {
+            return None;
+        }
+
+        let n_channels = if has_alpha { 4 } else { 3 };
+        let last_row = (width * n_channels * bits_per_sample + 7) / 8;
+
+        if width <= 0 || height <= 0 || rowstride < last_row
+            || data.len() < (rowstride * (height - 1) + last_row) as usize {
+            return None;
+        }
+
+        let mut data = Box::new(data);
+        let pixels = data.as_mut_ptr();
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_new_from_data(pixels, colorspace, ::glib::to_gboolean(has_alpha), bits_per_sample,
+                width, height, rowstride, Some(destroy_vec), ::std::mem::transmute(data))
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Copies the given area of a cairo surface to a new pixbuf, converting the pixels to RGB(A).
+    /// Same as `gdk::pixbuf_get_from_surface`.
+    pub fn get_from_surface(surface: &cairo::Surface, src_x: i32, src_y: i32, width: i32, height: i32) -> Option<Pixbuf> {
+        gdk::pixbuf_get_from_surface(surface, src_x, src_y, width, height)
+    }
+
+    /// Creates a new Pixbuf by loading an image from a file. The file format is detected
+    /// automatically.
+    pub fn new_from_file(filename: &str) -> Result<Pixbuf, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe { ffi::gdk_pixbuf_new_from_file(filename.borrow_to_glib().0, &mut error) };
+
+        Pixbuf::from_result(tmp, error)
+    }
+
+    /// Creates a new Pixbuf by loading an image from a file, scaled to fit in the requested size
+    /// while preserving the aspect ratio. A width or height of -1 means not to constrain it.
+    pub fn new_from_file_at_size(filename: &str, width: i32, height: i32) -> Result<Pixbuf, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_new_from_file_at_size(filename.borrow_to_glib().0, width, height, &mut error)
+        };
+
+        Pixbuf::from_result(tmp, error)
+    }
+
+    /// Creates a new Pixbuf by loading an image from a file, scaled to the requested size.
+    pub fn new_from_file_at_scale(filename: &str, width: i32, height: i32,
+                                  preserve_aspect_ratio: bool) -> Result<Pixbuf, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_new_from_file_at_scale(filename.borrow_to_glib().0, width, height,
+                ::glib::to_gboolean(preserve_aspect_ratio), &mut error)
+        };
+
+        Pixbuf::from_result(tmp, error)
+    }
+
+    /// Creates a new Pixbuf by loading an image from a GResource.
+    pub fn new_from_resource(resource_path: &str) -> Result<Pixbuf, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe { ffi::gdk_pixbuf_new_from_resource(resource_path.borrow_to_glib().0, &mut error) };
+
+        Pixbuf::from_result(tmp, error)
+    }
+
+    pub fn new_from_resource_at_scale(resource_path: &str, width: i32, height: i32,
+                                      preserve_aspect_ratio: bool) -> Result<Pixbuf, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_new_from_resource_at_scale(resource_path.borrow_to_glib().0, width, height,
+                ::glib::to_gboolean(preserve_aspect_ratio), &mut error)
+        };
+
+        Pixbuf::from_result(tmp, error)
+    }
+
+    /// Creates a new Pixbuf from encoded image data, using a PixbufLoader. The format is
+    /// detected automatically.
+    pub fn new_from_bytes(data: &[u8]) -> Result<Pixbuf, glib::Error> {
+        let loader = match gdk::PixbufLoader::new() {
+            Some(loader) => loader,
+            None => return Err(loader_error(gdk::PixbufError::Failed, "could not create a pixbuf loader"))
+        };
+        let written = loader.write(data);
+        let closed = loader.close();
+
+        try!(written);
+        try!(closed);
+        match loader.get_pixbuf() {
+            Some(pixbuf) => Ok(pixbuf),
+            None => Err(loader_error(gdk::PixbufError::CorruptImage, "no image could be loaded from the data"))
+        }
+    }
+
+    /// Creates a new Pixbuf with a copy of the information in the specified pixbuf.
+    pub fn copy(&self) -> Option<Pixbuf> {
+        let tmp = unsafe { ffi::gdk_pixbuf_copy(self.pointer as *const ffi::C_GdkPixbuf) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Parses an image file far enough to determine its format and size.
+    pub fn get_file_info(filename: &str, width: &mut i32, height: &mut i32) -> Option<gdk::PixbufFormat> {
+        let tmp = unsafe { ffi::gdk_pixbuf_get_file_info(filename.borrow_to_glib().0, width, height) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::PixbufFormat::wrap_pointer(tmp))
+        }
+    }
+
+    /// Saves the pixbuf to a file in format `type_`, which is "jpeg", "png", "tiff", "ico" or
+    /// "bmp" by default. `options` are the format specific key/value pairs, like
+    /// `[("quality", "90")]` for "jpeg".
+    pub fn save(&self, filename: &str, type_: &str, options: &[(&str, &str)]) -> Result<(), glib::Error> {
+        let keys: Vec<&str> = options.iter().map(|&(k, _)| k).collect();
+        let values: Vec<&str> = options.iter().map(|&(_, v)| v).collect();
+        let keys: Stash<*const *const c_char, Vec<&str>> = keys.borrow_to_glib();
+        let values: Stash<*const *const c_char, Vec<&str>> = values.borrow_to_glib();
+        let mut error = ::std::ptr::null_mut();
+
+        let ret = unsafe {
+            ::glib::to_bool(ffi::gdk_pixbuf_savev(self.pointer, filename.borrow_to_glib().0, type_.borrow_to_glib().0,
+                keys.0 as *mut *mut c_char, values.0 as *mut *mut c_char, &mut error))
+        };
+
+        if ret {
+            Ok(())
+        } else {
+            Err(GlibContainer::wrap(error))
+        }
+    }
+
+    /// Saves the pixbuf to a new buffer in format `type_`, see `save` for the options.
+    pub fn save_to_buffer(&self, type_: &str, options: &[(&str, &str)]) -> Result<Vec<u8>, glib::Error> {
+        let keys: Vec<&str> = options.iter().map(|&(k, _)| k).collect();
+        let values: Vec<&str> = options.iter().map(|&(_, v)| v).collect();
+        let keys: Stash<*const *const c_char, Vec<&str>> = keys.borrow_to_glib();
+        let values: Stash<*const *const c_char, Vec<&str>> = values.borrow_to_glib();
+        let mut buffer = ::std::ptr::null_mut();
+        let mut buffer_size : size_t = 0;
+        let mut error = ::std::ptr::null_mut();
+
+        let ret = unsafe {
+            ::glib::to_bool(ffi::gdk_pixbuf_save_to_bufferv(self.pointer, &mut buffer, &mut buffer_size,
+                type_.borrow_to_glib().0, keys.0 as *mut *mut c_char, values.0 as *mut *mut c_char, &mut error))
+        };
+
+        if ret {
+            let data = unsafe {
+                let tmp = ::std::slice::from_raw_parts(buffer as *const u8, buffer_size as usize).to_vec();
+                ::glib::ffi::g_free(buffer as ::glib::ffi::gpointer);
+                tmp
+            };
+
+            Ok(data)
+        } else {
+            Err(GlibContainer::wrap(error))
+        }
+    }
+
+    /// Creates a new pixbuf which represents a sub-region of `self`. The new pixbuf shares its
+    /// pixels with the original pixbuf, so writing to one affects both. The new pixbuf holds a
+    /// reference to `self`, so `self` will not be finalized until the new pixbuf is finalized.
+    pub fn new_subpixbuf(&self, src_x: i32, src_y: i32, width: i32, height: i32) -> Option<Pixbuf> {
+        let tmp = unsafe { ffi::gdk_pixbuf_new_subpixbuf(self.pointer, src_x, src_y, width, height) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Creates a new Pixbuf containing a copy of `self` scaled to `dest_width` x `dest_height`.
+    pub fn scale_simple(&self, dest_width: i32, dest_height: i32, interp_type: gdk::InterpType) -> Option<Pixbuf> {
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_scale_simple(self.pointer as *const ffi::C_GdkPixbuf, dest_width, dest_height, interp_type)
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Creates a transformation of `self` by scaling by `scale_x` and `scale_y` then translating by
+    /// `offset_x` and `offset_y`, then renders the rectangle (`dest_x`, `dest_y`, `dest_width`,
+    /// `dest_height`) of the resulting image onto `dest`, replacing the previous contents.
+    pub fn scale(&self, dest: &Pixbuf, dest_x: i32, dest_y: i32, dest_width: i32, dest_height: i32, offset_x: f64,
+                 offset_y: f64, scale_x: f64, scale_y: f64, interp_type: gdk::InterpType) {
+        unsafe {
+            ffi::gdk_pixbuf_scale(self.pointer as *const ffi::C_GdkPixbuf, dest.pointer, dest_x, dest_y, dest_width,
+                dest_height, offset_x, offset_y, scale_x, scale_y, interp_type)
+        }
+    }
+
+    /// Creates a transformation of `self` like `scale`, then alpha blends the rectangle of the
+    /// resulting image onto `dest`. `overall_alpha` goes from 0 to 255.
+    pub fn composite(&self, dest: &Pixbuf, dest_x: i32, dest_y: i32, dest_width: i32, dest_height: i32, offset_x: f64,
+                     offset_y: f64, scale_x: f64, scale_y: f64, interp_type: gdk::InterpType, overall_alpha: i32) {
+        unsafe {
+            ffi::gdk_pixbuf_composite(self.pointer as *const ffi::C_GdkPixbuf, dest.pointer, dest_x, dest_y, dest_width,
+                dest_height, offset_x, offset_y, scale_x, scale_y, interp_type, overall_alpha)
+        }
+    }
+
+    /// Creates a transformation of `self` like `composite`, but the image is composited onto a
+    /// checkboard of `color1` and `color2` (as 0xRRGGBB) with squares of `check_size` pixels
+    /// instead of onto the previous contents of `dest`.
+    pub fn composite_color(&self, dest: &Pixbuf, dest_x: i32, dest_y: i32, dest_width: i32, dest_height: i32,
+                           offset_x: f64, offset_y: f64, scale_x: f64, scale_y: f64, interp_type: gdk::InterpType,
+                           overall_alpha: i32, check_x: i32, check_y: i32, check_size: i32, color1: u32, color2: u32) {
+        unsafe {
+            ffi::gdk_pixbuf_composite_color(self.pointer as *const ffi::C_GdkPixbuf, dest.pointer, dest_x, dest_y,
+                dest_width, dest_height, offset_x, offset_y, scale_x, scale_y, interp_type, overall_alpha, check_x,
+                check_y, check_size, color1, color2)
+        }
+    }
+
+    /// Creates a new Pixbuf by scaling `self` to `dest_width` x `dest_height` and compositing the
+    /// result with a checkboard of colors `color1` and `color2`.
+    pub fn composite_color_simple(&self, dest_width: i32, dest_height: i32, interp_type: gdk::InterpType,
+                                  overall_alpha: i32, check_size: i32, color1: u32, color2: u32) -> Option<Pixbuf> {
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_composite_color_simple(self.pointer as *const ffi::C_GdkPixbuf, dest_width, dest_height,
+                interp_type, overall_alpha, check_size, color1, color2)
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Rotates a pixbuf by a multiple of 90 degrees, and returns the result in a new pixbuf.
+    pub fn rotate_simple(&self, angle: gdk::PixbufRotation) -> Option<Pixbuf> {
+        let tmp = unsafe { ffi::gdk_pixbuf_rotate_simple(self.pointer as *const ffi::C_GdkPixbuf, angle) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Flips a pixbuf horizontally or vertically and returns the result in a new pixbuf.
+    pub fn flip(&self, horizontal: bool) -> Option<Pixbuf> {
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_flip(self.pointer as *const ffi::C_GdkPixbuf, ::glib::to_gboolean(horizontal))
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Modifies saturation and optionally pixelates `self`, placing the result in `dest`. `self`
+    /// and `dest` may be the same pixbuf with no ill effects. A saturation of 1.0 leaves it
+    /// unchanged, 0.0 makes it greyscale and more than 1.0 oversaturates it.
+    pub fn saturate_and_pixelate(&self, dest: &Pixbuf, saturation: f32, pixelate: bool) {
+        unsafe {
+            ffi::gdk_pixbuf_saturate_and_pixelate(self.pointer as *const ffi::C_GdkPixbuf, dest.pointer, saturation,
+                ::glib::to_gboolean(pixelate))
+        }
+    }
+
+    /// Takes an existing pixbuf and adds an alpha channel to it, in a new pixbuf. If
+    /// `substitute_color` is true, the color (`r`, `g`, `b`) is made fully transparent.
+    pub fn add_alpha(&self, substitute_color: bool, r: u8, g: u8, b: u8) -> Option<Pixbuf> {
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_add_alpha(self.pointer as *const ffi::C_GdkPixbuf, ::glib::to_gboolean(substitute_color),
+                r, g, b)
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    /// Copies a rectangular area from `self` to `dest_pixbuf`. Conversion of pixbuf formats is
+    /// done automatically.
+    pub fn copy_area(&self, src_x: i32, src_y: i32, width: i32, height: i32, dest_pixbuf: &Pixbuf, dest_x: i32,
+                     dest_y: i32) {
+        unsafe {
+            ffi::gdk_pixbuf_copy_area(self.pointer as *const ffi::C_GdkPixbuf, src_x, src_y, width, height,
+                dest_pixbuf.pointer, dest_x, dest_y)
+        }
+    }
+
+    /// Clears a pixbuf to the given RGBA value, converting the value to the pixbuf's format.
+    /// `pixel` is 0xRRGGBBAA, the alpha part is ignored if the pixbuf has no alpha channel.
+    pub fn fill(&self, pixel: u32) {
+        unsafe { ffi::gdk_pixbuf_fill(self.pointer, pixel) }
+    }
+
+    /// Takes an existing pixbuf and checks for the presence of an associated "orientation"
+    /// option, which may be provided by the jpeg (as an EXIF tag) or tiff loaders. Returns a new
+    /// pixbuf transformed accordingly, or a new reference to `self` if no transform is needed.
+    pub fn apply_embedded_orientation(&self) -> Option<Pixbuf> {
+        let tmp = unsafe { ffi::gdk_pixbuf_apply_embedded_orientation(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Pixbuf { pointer: tmp })
+        }
+    }
+
+    fn from_result(pointer: *mut ffi::C_GdkPixbuf, error: *mut ::glib::ffi::C_GError) -> Result<Pixbuf, glib::Error> {
+        if pointer.is_null() {
+            Err(GlibContainer::wrap(error))
+        } else {
+            Ok(Pixbuf { pointer: pointer })
+        }
+    }
+
     pub fn get_colorspace(&self) -> gdk::ColorSpace {
         unsafe { ffi::gdk_pixbuf_get_colorspace(self.pointer as *const ffi::C_GdkPixbuf) }
     }
@@ -103,4 +462,27 @@ impl Pixbuf {
     }
 }
 
+extern "C" fn destroy_vec(_pixels: *mut c_uchar, data: *mut c_void) {
+    let _: Box<Vec<u8>> = unsafe { ::std::mem::transmute(data) };
+}
+
+impl Clone for Pixbuf {
+    fn clone(&self) -> Pixbuf {
+        unsafe { ::glib::ffi::g_object_ref(self.pointer as *mut ::glib::ffi::C_GObject) };
+
+        Pixbuf {
+            pointer: self.pointer
+        }
+    }
+}
+
+impl Drop for Pixbuf {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
+
 impl_GObjectFunctions!(Pixbuf, C_GdkPixbuf);
\ No newline at end of file
@@ -0,0 +1,226 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Animations — Animated images.
+
+use glib::translate::ToGlibPtr;
+use glib::{self, GlibContainer};
+use glib::ffi::C_GTimeVal;
+use gdk::{self, ffi};
+use libc::c_long;
+use std::io::{ErrorKind, Read};
+use super::pixbuf_loader::loader_error;
+use std::marker::PhantomData;
+
+/// A PixbufAnimation represents an animation, like an animated GIF file. A static image loaded
+/// as an animation has a single frame.
+pub struct PixbufAnimation {
+    pointer: *mut ffi::C_GdkPixbufAnimation
+}
+
+impl PixbufAnimation {
+    /// Creates a new animation by loading it from a file. The file format is detected
+    /// automatically.
+    pub fn new_from_file(filename: &str) -> Result<PixbufAnimation, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe { ffi::gdk_pixbuf_animation_new_from_file(filename.borrow_to_glib().0, &mut error) };
+
+        if tmp.is_null() {
+            Err(GlibContainer::wrap(error))
+        } else {
+            Ok(PixbufAnimation { pointer: tmp })
+        }
+    }
+
+    /// Creates a new animation by loading it from a GResource.
+    pub fn new_from_resource(resource_path: &str) -> Result<PixbufAnimation, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe { ffi::gdk_pixbuf_animation_new_from_resource(resource_path.borrow_to_glib().0, &mut error) };
+
+        if tmp.is_null() {
+            Err(GlibContainer::wrap(error))
+        } else {
+            Ok(PixbufAnimation { pointer: tmp })
+        }
+    }
+
+    /// Creates a new animation by reading encoded image data from a stream until its end, using
+    /// a PixbufLoader.
+    pub fn new_from_reader<R: Read>(reader: &mut R) -> Result<PixbufAnimation, glib::Error> {
+        let loader = match gdk::PixbufLoader::new() {
+            Some(loader) => loader,
+            None => return Err(loader_error(gdk::PixbufError::Failed, "could not create a pixbuf loader"))
+        };
+        let mut buf = [0u8; 4096];
+
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(len) => {
+                    if let Err(e) = loader.write(&buf[..len]) {
+                        let _ = loader.close();
+                        return Err(e);
+                    }
+                }
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    let _ = loader.close();
+                    return Err(loader_error(gdk::PixbufError::Failed, &format!("{}", e)));
+                }
+            }
+        }
+        try!(loader.close());
+        match loader.get_animation() {
+            Some(animation) => Ok(animation),
+            None => Err(loader_error(gdk::PixbufError::CorruptImage, "no image could be loaded from the stream"))
+        }
+    }
+
+    pub fn get_width(&self) -> i32 {
+        unsafe { ffi::gdk_pixbuf_animation_get_width(self.pointer) }
+    }
+
+    pub fn get_height(&self) -> i32 {
+        unsafe { ffi::gdk_pixbuf_animation_get_height(self.pointer) }
+    }
+
+    /// Returns true if the animation is in fact a normal still image, in which case
+    /// `get_static_image` should be used to display it.
+    pub fn is_static_image(&self) -> bool {
+        unsafe { ::glib::to_bool(ffi::gdk_pixbuf_animation_is_static_image(self.pointer)) }
+    }
+
+    /// Returns the unanimated image, or the first frame of the animation if it is not static.
+    pub fn get_static_image(&self) -> Option<gdk::Pixbuf> {
+        let tmp = unsafe { ffi::gdk_pixbuf_animation_get_static_image(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
+            Some(gdk::Pixbuf::wrap_pointer(tmp))
+        }
+    }
+
+    /// Gets an iterator for displaying the animation, starting at `start_time` in microseconds.
+    /// None means the current wall clock time. The same clock must be used for
+    /// `PixbufAnimationIter::advance`, for example `FrameClock::get_frame_time`.
+    pub fn get_iter<'a>(&'a self, start_time: Option<i64>) -> Option<PixbufAnimationIter<'a>> {
+        let time = start_time.map(to_time_val);
+        let tmp = unsafe {
+            ffi::gdk_pixbuf_animation_get_iter(self.pointer, match time {
+                Some(ref t) => t as *const C_GTimeVal,
+                None => ::std::ptr::null()
+            })
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(PixbufAnimationIter {
+                pointer: tmp,
+                _marker: PhantomData
+            })
+        }
+    }
+}
+
+impl Clone for PixbufAnimation {
+    fn clone(&self) -> PixbufAnimation {
+        unsafe { ::glib::ffi::g_object_ref(self.pointer as *mut ::glib::ffi::C_GObject) };
+
+        PixbufAnimation {
+            pointer: self.pointer
+        }
+    }
+}
+
+impl Drop for PixbufAnimation {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
+
+impl_GObjectFunctions!(PixbufAnimation, C_GdkPixbufAnimation);
+
+/// An opaque struct representing an iterator which points to a certain position in an animation.
+pub struct PixbufAnimationIter<'a> {
+    pointer: *mut ffi::C_GdkPixbufAnimationIter,
+    _marker: PhantomData<&'a PixbufAnimation>
+}
+
+impl<'a> PixbufAnimationIter<'a> {
+    /// Possibly advances an animation to a new frame, chosen based on `current_time` in
+    /// microseconds, None meaning the current wall clock time. Returns true if the image may
+    /// need updating.
+    pub fn advance(&self, current_time: Option<i64>) -> bool {
+        let time = current_time.map(to_time_val);
+
+        unsafe {
+            ::glib::to_bool(ffi::gdk_pixbuf_animation_iter_advance(self.pointer, match time {
+                Some(ref t) => t as *const C_GTimeVal,
+                None => ::std::ptr::null()
+            }))
+        }
+    }
+
+    /// Gets the number of milliseconds the current pixbuf should be displayed, or -1 if the
+    /// current pixbuf should be displayed forever.
+    pub fn get_delay_time(&self) -> i32 {
+        unsafe { ffi::gdk_pixbuf_animation_iter_get_delay_time(self.pointer) }
+    }
+
+    /// Used to determine how to respond to the area_updated signal on PixbufLoader when loading
+    /// an animation.
+    pub fn on_currently_loading_frame(&self) -> bool {
+        unsafe { ::glib::to_bool(ffi::gdk_pixbuf_animation_iter_on_currently_loading_frame(self.pointer)) }
+    }
+
+    /// Gets the current pixbuf which should be displayed. The pixbuf might not be the same size
+    /// as the animation itself, and its content may change on the next `advance`.
+    pub fn get_pixbuf(&self) -> Option<gdk::Pixbuf> {
+        let tmp = unsafe { ffi::gdk_pixbuf_animation_iter_get_pixbuf(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
+            Some(gdk::Pixbuf::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GdkPixbufAnimationIter {
+        self.pointer
+    }
+}
+
+impl<'a> Drop for PixbufAnimationIter<'a> {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
+
+fn to_time_val(time: i64) -> C_GTimeVal {
+    C_GTimeVal {
+        tv_sec: (time / 1000000) as c_long,
+        tv_usec: (time % 1000000) as c_long
+    }
+}
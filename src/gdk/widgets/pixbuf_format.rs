@@ -0,0 +1,106 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Module Interface — Extending GdkPixBuf
+
+use glib::translate::{FromGlibPtr, FromGlibPtrContainer};
+use glib::to_gboolean;
+use gdk::ffi;
+use libc::c_char;
+
+/// A PixbufFormat contains information about the image format accepted by a module. Formats
+/// are owned by gdk-pixbuf and live as long as the program.
+#[derive(Clone, Copy)]
+pub struct PixbufFormat {
+    pointer: *mut ffi::C_GdkPixbufFormat
+}
+
+impl PixbufFormat {
+    /// Obtains the available information about the image formats supported by GdkPixbuf.
+    pub fn get_formats() -> Vec<PixbufFormat> {
+        let list = unsafe { ffi::gdk_pixbuf_get_formats() };
+        let mut ret = Vec::new();
+        let mut it = list;
+
+        unsafe {
+            while !it.is_null() {
+                ret.push(PixbufFormat::wrap_pointer((*it).data as *mut ffi::C_GdkPixbufFormat));
+                it = (*it).next;
+            }
+            ::glib::ffi::g_slist_free(list);
+        }
+        ret
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        unsafe { FromGlibPtr::take(ffi::gdk_pixbuf_format_get_name(self.pointer)) }
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        unsafe { FromGlibPtr::take(ffi::gdk_pixbuf_format_get_description(self.pointer)) }
+    }
+
+    /// Returns the mime types supported by the format.
+    pub fn get_mime_types(&self) -> Vec<String> {
+        unsafe { take_strv(ffi::gdk_pixbuf_format_get_mime_types(self.pointer)) }
+    }
+
+    /// Returns the filename extensions typically used for files in the given format.
+    pub fn get_extensions(&self) -> Vec<String> {
+        unsafe { take_strv(ffi::gdk_pixbuf_format_get_extensions(self.pointer)) }
+    }
+
+    /// Returns whether pixbufs can be saved in the given format.
+    pub fn is_writable(&self) -> bool {
+        unsafe { ::glib::to_bool(ffi::gdk_pixbuf_format_is_writable(self.pointer)) }
+    }
+
+    /// Returns whether this image format is scalable, like svg.
+    pub fn is_scalable(&self) -> bool {
+        unsafe { ::glib::to_bool(ffi::gdk_pixbuf_format_is_scalable(self.pointer)) }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        unsafe { ::glib::to_bool(ffi::gdk_pixbuf_format_is_disabled(self.pointer)) }
+    }
+
+    /// Disables or enables an image format. A disabled format is not used when trying to load
+    /// an image.
+    pub fn set_disabled(&self, disabled: bool) {
+        unsafe { ffi::gdk_pixbuf_format_set_disabled(self.pointer, to_gboolean(disabled)) }
+    }
+
+    /// Returns information about the license of the image loader for the format, like "LGPL".
+    pub fn get_license(&self) -> Option<String> {
+        unsafe { FromGlibPtr::take(ffi::gdk_pixbuf_format_get_license(self.pointer)) }
+    }
+
+    pub fn wrap_pointer(pointer: *mut ffi::C_GdkPixbufFormat) -> PixbufFormat {
+        PixbufFormat {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GdkPixbufFormat {
+        self.pointer
+    }
+}
+
+unsafe fn take_strv(strv: *mut *mut c_char) -> Vec<String> {
+    let ret: Vec<String> = FromGlibPtrContainer::borrow(strv as *const *const c_char);
+
+    ::glib::ffi::g_strfreev(strv);
+    ret
+}
@@ -0,0 +1,157 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! GdkPixbufLoader — Application-driven progressive image loading.
+
+use glib::translate::ToGlibPtr;
+use glib::{self, GlibContainer};
+use gdk::{self, ffi};
+use libc::{c_int, size_t};
+
+/// A PixbufLoader provides a way for applications to drive the process of loading an image, by
+/// letting them send the image data directly to the loader instead of having the loader read
+/// the data from a file. `close` must be called once all the data has been written.
+pub struct PixbufLoader {
+    pointer: *mut ffi::C_GdkPixbufLoader
+}
+
+impl PixbufLoader {
+    /// Creates a new pixbuf loader object, the image format is detected from the data.
+    pub fn new() -> Option<PixbufLoader> {
+        let tmp = unsafe { ffi::gdk_pixbuf_loader_new() };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(PixbufLoader { pointer: tmp })
+        }
+    }
+
+    /// Creates a new pixbuf loader object that always attempts to parse image data as if it
+    /// were an image of type `image_type`, like "png" or "jpeg".
+    pub fn new_with_type(image_type: &str) -> Result<PixbufLoader, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe { ffi::gdk_pixbuf_loader_new_with_type(image_type.borrow_to_glib().0, &mut error) };
+
+        if tmp.is_null() {
+            Err(GlibContainer::wrap(error))
+        } else {
+            Ok(PixbufLoader { pointer: tmp })
+        }
+    }
+
+    /// Creates a new pixbuf loader object that always attempts to parse image data as if it
+    /// were an image of mime type `mime_type`, like "image/png".
+    pub fn new_with_mime_type(mime_type: &str) -> Result<PixbufLoader, glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let tmp = unsafe { ffi::gdk_pixbuf_loader_new_with_mime_type(mime_type.borrow_to_glib().0, &mut error) };
+
+        if tmp.is_null() {
+            Err(GlibContainer::wrap(error))
+        } else {
+            Ok(PixbufLoader { pointer: tmp })
+        }
+    }
+
+    /// Causes the image to be scaled while it is loaded. Must be called before the image size
+    /// is known, i.e. before the first call to `write` that gets the header parsed.
+    pub fn set_size(&self, width: i32, height: i32) {
+        unsafe { ffi::gdk_pixbuf_loader_set_size(self.pointer, width, height) }
+    }
+
+    /// Causes the loader to parse the next bytes of an image.
+    pub fn write(&self, buf: &[u8]) -> Result<(), glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let ret = unsafe {
+            ::glib::to_bool(ffi::gdk_pixbuf_loader_write(self.pointer, buf.as_ptr(), buf.len() as size_t, &mut error))
+        };
+
+        if ret {
+            Ok(())
+        } else {
+            Err(GlibContainer::wrap(error))
+        }
+    }
+
+    /// Informs the loader that no further writes will occur, so that it can free its internal
+    /// loading structures. An error is returned if the image data was truncated or invalid.
+    pub fn close(&self) -> Result<(), glib::Error> {
+        let mut error = ::std::ptr::null_mut();
+        let ret = unsafe { ::glib::to_bool(ffi::gdk_pixbuf_loader_close(self.pointer, &mut error)) };
+
+        if ret {
+            Ok(())
+        } else {
+            Err(GlibContainer::wrap(error))
+        }
+    }
+
+    /// Queries the Pixbuf that the loader is currently creating, None until enough data has been
+    /// written to know the size of the image.
+    pub fn get_pixbuf(&self) -> Option<gdk::Pixbuf> {
+        let tmp = unsafe { ffi::gdk_pixbuf_loader_get_pixbuf(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
+            Some(gdk::Pixbuf::wrap_pointer(tmp))
+        }
+    }
+
+    /// Queries the PixbufAnimation that the loader is currently creating. A static image is
+    /// returned as an animation with a single frame.
+    pub fn get_animation(&self) -> Option<gdk::PixbufAnimation> {
+        let tmp = unsafe { ffi::gdk_pixbuf_loader_get_animation(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
+            Some(gdk::PixbufAnimation::wrap_pointer(tmp))
+        }
+    }
+
+    /// Obtains the available information about the format of the currently loading image file.
+    pub fn get_format(&self) -> Option<gdk::PixbufFormat> {
+        let tmp = unsafe { ffi::gdk_pixbuf_loader_get_format(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::PixbufFormat::wrap_pointer(tmp))
+        }
+    }
+}
+
+/// Creates an error in the GdkPixbuf error domain, for the failures of the functions built on
+/// top of a PixbufLoader which don't come with a GError.
+pub fn loader_error(code: gdk::PixbufError, message: &str) -> glib::Error {
+    unsafe {
+        GlibContainer::wrap(glib::ffi::g_error_new_literal(ffi::gdk_pixbuf_error_quark(), code as c_int,
+            message.borrow_to_glib().0))
+    }
+}
+
+impl Drop for PixbufLoader {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
+
+impl_GObjectFunctions!(PixbufLoader, C_GdkPixbufLoader);
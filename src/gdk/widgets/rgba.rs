@@ -17,9 +17,25 @@
 
 use glib::translate::{FromGlibPtr, ToGlibPtr};
 use gdk_ffi as ffi;
-use gdk_ffi::C_GdkRGBA;
+use gdk_ffi::{C_GdkRGBA, C_GdkColor};
+use cairo;
+use pango;
+
+/// Converts a channel from 0. to 1. to a channel from 0 to 65535.
+fn to_u16(channel: f64) -> u16 {
+    let channel = if channel < 0. { 0. } else if channel > 1. { 1. } else { channel };
+
+    (channel * 65535. + 0.5) as u16
+}
 
 pub trait RGBA {
+    fn new(red: f64, green: f64, blue: f64, alpha: f64) -> C_GdkRGBA;
+    /// Parses a color: a name from the X11 rgb.txt file ("red", "dark slate gray"...), a
+    /// hexadecimal value (`#rgb`, `#rrggbb`, `#rrrgggbbb` or `#rrrrggggbbbb`), or a CSS `rgb(r,g,b)` or
+    /// `rgba(r,g,b,a)` value with channels from 0 to 255 or percentages.
+    fn from_str(spec: &str) -> Option<C_GdkRGBA>;
+    /// Converts an opaque GdkColor.
+    fn from_color(color: &C_GdkColor) -> C_GdkRGBA;
     fn white() -> C_GdkRGBA;
     fn blue() -> C_GdkRGBA;
     fn green() -> C_GdkRGBA;
@@ -29,10 +45,46 @@ pub trait RGBA {
     fn parse(&mut self, spec: &str) -> bool;
     fn equal(&self, other: &C_GdkRGBA) -> bool;
     fn hash(&self) -> u32;
+    /// Returns the color in the CSS format `rgb(r,g,b)`, or `rgba(r,g,b,a)` if the color isn't
+    /// opaque. The result can be parsed back with `parse`.
     fn to_string(&self) -> Option<String>;
+    /// Converts the color to a GdkColor, dropping the alpha channel.
+    fn to_color(&self) -> C_GdkColor;
+    /// Converts the color to a pango attribute color, dropping the alpha channel.
+    fn to_pango_color(&self) -> pango::Color;
+    /// Sets the color as the source of `cr`.
+    fn set_source(&self, cr: &cairo::Context);
 }
 
 impl RGBA for C_GdkRGBA {
+    fn new(red: f64, green: f64, blue: f64, alpha: f64) -> C_GdkRGBA {
+        C_GdkRGBA {
+            red: red,
+            green: green,
+            blue: blue,
+            alpha: alpha
+        }
+    }
+
+    fn from_str(spec: &str) -> Option<C_GdkRGBA> {
+        let mut rgba = <C_GdkRGBA as RGBA>::new(0., 0., 0., 0.);
+
+        if rgba.parse(spec) {
+            Some(rgba)
+        } else {
+            None
+        }
+    }
+
+    fn from_color(color: &C_GdkColor) -> C_GdkRGBA {
+        C_GdkRGBA {
+            red: color.red as f64 / 65535.,
+            green: color.green as f64 / 65535.,
+            blue: color.blue as f64 / 65535.,
+            alpha: 1.
+        }
+    }
+
     fn white() -> C_GdkRGBA {
         C_GdkRGBA {
             red: 1f64,
@@ -107,4 +159,25 @@ impl RGBA for C_GdkRGBA {
                 ffi::gdk_rgba_to_string(self))
         }
     }
+
+    fn to_color(&self) -> C_GdkColor {
+        C_GdkColor {
+            pixel: 0,
+            red: to_u16(self.red),
+            green: to_u16(self.green),
+            blue: to_u16(self.blue)
+        }
+    }
+
+    fn to_pango_color(&self) -> pango::Color {
+        pango::Color {
+            red: to_u16(self.red),
+            green: to_u16(self.green),
+            blue: to_u16(self.blue)
+        }
+    }
+
+    fn set_source(&self, cr: &cairo::Context) {
+        unsafe { ffi::gdk_cairo_set_source_rgba(cr.get_ptr(), self) }
+    }
 }
@@ -148,6 +148,16 @@ impl Screen {
         }
     }
 
+    /// Returns the properties of the monitor, None if the screen has no such monitor.
+    pub fn get_monitor(&self, monitor_num: i32) -> Option<gdk::Monitor> {
+        gdk::Monitor::new(self, monitor_num)
+    }
+
+    /// Returns an iterator over the monitors of the screen.
+    pub fn get_monitors(&self) -> gdk::MonitorIter {
+        gdk::MonitorIter::new(self)
+    }
+
     #[cfg(feature = "GTK_3_10")]
     pub fn get_monitor_scale_factor(&self, monitor_num: i32) -> i32 {
         unsafe { ffi::gdk_screen_get_monitor_scale_factor(self.pointer, monitor_num as c_int) }
@@ -173,3 +183,15 @@ impl Screen {
 }
 
 impl_GObjectFunctions!(Screen, C_GdkScreen);
+
+impl ::glib::traits::FFIGObject for Screen {
+    fn unwrap_gobject(&self) -> *mut ::glib::ffi::C_GObject {
+        self.pointer as *mut ::glib::ffi::C_GObject
+    }
+
+    fn wrap_object(object: *mut ::glib::ffi::C_GObject) -> Screen {
+        Screen { pointer: object as *mut ffi::C_GdkScreen }
+    }
+}
+
+impl_connect!(Screen -> MonitorsChanged, SizeChanged, CompositedChanged);
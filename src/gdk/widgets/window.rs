@@ -16,8 +16,10 @@
 //! Windows — Onscreen display areas in the target window system
 
 use gdk::{self, ffi};
+use cairo;
 use libc::{c_int, c_char};
 use std::ptr;
+use std::ops::Deref;
 use glib::translate::{Stash, ToGlib, ToGlibPtr, from_glib};
 
 /// Attributes to use for a newly-created window.
@@ -314,13 +316,10 @@ impl Window {
             root_y as c_int, timestamp) }
     }
 
-    /* FIXME : I think the Event struct is missing, not just a trait is needed:
-    https://developer.gnome.org/gdk3/3.14/gdk3-Event-Structures.html#GdkEvent
-
     #[cfg(feature = "GTK_3_14")]
     pub fn show_window_menu(&self, event: &gdk::Event) {
         unsafe { ffi::gdk_window_show_window_menu(self.pointer, event.unwrap_pointer()) }
-    }*/
+    }
 
     pub fn constrain_size(&self, flags: gdk::WindowHints, width: i32, height: i32, new_width: &mut i32, new_height: &mut i32) {
         unsafe { ffi::gdk_window_constrain_size(self.pointer, flags, width as c_int, height as c_int, new_width as *mut c_int,
@@ -415,6 +414,40 @@ impl Window {
         unsafe { ffi::gdk_window_set_child_input_shapes(self.pointer) }
     }
 
+    /// Makes pixels of the window outside of `shape_region` transparent, so the window may be
+    /// nonrectangular. None removes the shape.
+    pub fn shape_combine_region(&self, shape_region: Option<&cairo::Region>, offset_x: i32, offset_y: i32) {
+        let region = match shape_region {
+            Some(region) => region.get_ptr() as *const _,
+            None => ptr::null()
+        };
+
+        unsafe { ffi::gdk_window_shape_combine_region(self.pointer, region, offset_x as c_int, offset_y as c_int) }
+    }
+
+    /// Makes the window only receive the input events in `shape_region`, the events outside go
+    /// to the windows below (click-through). None removes the input shape.
+    pub fn input_shape_combine_region(&self, shape_region: Option<&cairo::Region>, offset_x: i32, offset_y: i32) {
+        let region = match shape_region {
+            Some(region) => region.get_ptr() as *const _,
+            None => ptr::null()
+        };
+
+        unsafe { ffi::gdk_window_input_shape_combine_region(self.pointer, region, offset_x as c_int, offset_y as c_int) }
+    }
+
+    /// Tells the compositor which parts of the window are fully opaque, as an optimization.
+    /// None marks the whole window as possibly transparent.
+    #[cfg(feature = "GTK_3_10")]
+    pub fn set_opaque_region(&self, region: Option<&cairo::Region>) {
+        let region = match region {
+            Some(region) => region.get_ptr(),
+            None => ptr::null_mut()
+        };
+
+        unsafe { ffi::gdk_window_set_opaque_region(self.pointer, region) }
+    }
+
     pub fn merge_child_input_shapes(&self) {
         unsafe { ffi::gdk_window_merge_child_input_shapes(self.pointer) }
     }
@@ -443,6 +476,7 @@ impl Window {
         if tmp.is_null() {
             None
         } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
             Some(gdk::Cursor::wrap_pointer(tmp))
         }
     }
@@ -461,8 +495,9 @@ impl Window {
             height as *mut c_int) }
     }
 
-    pub fn set_geometry_hints(&self, geometry: &ffi::C_GdkGeometry, geom_mask: gdk::WindowHints) {
-        unsafe { ffi::gdk_window_set_geometry_hints(self.pointer, geometry, geom_mask) }
+    /// Sets the size constraints of the window, only the fields set in `geometry` are used.
+    pub fn set_geometry_hints(&self, geometry: &gdk::Geometry) {
+        unsafe { ffi::gdk_window_set_geometry_hints(self.pointer, geometry.get_geometry(), geometry.get_hints()) }
     }
 
     pub fn get_width(&self) -> i32 {
@@ -663,6 +698,7 @@ impl Window {
         if tmp.is_null() {
             None
         } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
             Some(gdk::Cursor::wrap_pointer(tmp))
         }
     }
@@ -764,3 +800,42 @@ impl Drop for Window {
 }
 
 impl_GObjectFunctions!(Window, C_GdkWindow);
+
+/// A reference to a window owned by someone else (GDK, another part of the application or
+/// another X client). It keeps the window alive but, unlike `Window`, doesn't destroy it when
+/// dropped. Derefs to `Window`.
+pub struct WindowRef {
+    window: Window
+}
+
+impl WindowRef {
+    /// Wraps a borrowed window, taking a new reference on it.
+    pub fn wrap_pointer(pointer: *mut ffi::C_GdkWindow) -> WindowRef {
+        unsafe { ::glib::ffi::g_object_ref(pointer as *mut ::glib::ffi::C_GObject) };
+        WindowRef { window: Window { pointer: pointer } }
+    }
+}
+
+impl Clone for WindowRef {
+    fn clone(&self) -> WindowRef {
+        WindowRef::wrap_pointer(self.window.pointer)
+    }
+}
+
+impl Deref for WindowRef {
+    type Target = Window;
+
+    fn deref(&self) -> &Window {
+        &self.window
+    }
+}
+
+impl Drop for WindowRef {
+    fn drop(&mut self) {
+        if !self.window.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.window.pointer as *mut ::glib::ffi::C_GObject) };
+            // the drop of the inner Window does nothing once the pointer is null
+            self.window.pointer = ptr::null_mut();
+        }
+    }
+}
@@ -0,0 +1,259 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! X11 backend — Interoperation with Xlib, enabled by the `X11` feature
+
+use gdk;
+use gdk::ffi::x11;
+use glib::to_bool;
+use glib::translate::{FromGlibPtr, ToGlibPtr};
+
+pub use gdk_ffi::x11::{XID, C_XDisplay as XDisplay};
+
+/// X11 specific functions of a window. The methods return None when the window isn't handled by
+/// the X11 backend (under Wayland or Broadway).
+pub trait X11Window {
+    /// Wraps a window created by another X client. Dropping the returned window only releases the
+    /// reference, the X window is left to its owner.
+    fn foreign_new_for_display(display: &gdk::Display, window: XID) -> Option<gdk::WindowRef>;
+    /// Gets the window already wrapping the X window `window`, if any.
+    fn lookup_for_display(display: &gdk::Display, window: XID) -> Option<gdk::WindowRef>;
+    /// Returns true if the window is handled by the X11 backend.
+    fn is_x11(&self) -> bool;
+    /// Gets the X ID of the window, None if it isn't an X11 window.
+    fn get_xid(&self) -> Option<XID>;
+    /// Sets a UTF8_STRING property on the window, None deletes it.
+    fn set_utf8_property(&self, name: &str, value: Option<&str>) -> Option<()>;
+    /// Sets the _NET_WM_USER_TIME of the window, for the focus stealing prevention.
+    fn set_user_time(&self, timestamp: u32) -> Option<()>;
+    /// Makes a roundtrip to the X server to get its current time, the window must have
+    /// PropertyChangeMask in its events.
+    fn get_server_time(&self) -> Option<u32>;
+}
+
+impl X11Window for gdk::Window {
+    fn foreign_new_for_display(display: &gdk::Display, window: XID) -> Option<gdk::WindowRef> {
+        let tmp = unsafe { x11::gdk_x11_window_foreign_new_for_display(display.unwrap_pointer(), window) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            let window = gdk::WindowRef::wrap_pointer(tmp);
+
+            // the reference returned by gdk_x11_window_foreign_new_for_display is now held by window
+            unsafe { ::glib::ffi::g_object_unref(tmp as *mut ::glib::ffi::C_GObject) };
+            Some(window)
+        }
+    }
+
+    fn lookup_for_display(display: &gdk::Display, window: XID) -> Option<gdk::WindowRef> {
+        let tmp = unsafe { x11::gdk_x11_window_lookup_for_display(display.unwrap_pointer(), window) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::WindowRef::wrap_pointer(tmp))
+        }
+    }
+
+    fn is_x11(&self) -> bool {
+        unsafe {
+            to_bool(::glib::ffi::g_type_check_instance_is_a(self.unwrap_pointer() as *mut ::glib::ffi::C_GObject,
+                x11::gdk_x11_window_get_type()))
+        }
+    }
+
+    fn get_xid(&self) -> Option<XID> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_window_get_xid(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+
+    fn set_utf8_property(&self, name: &str, value: Option<&str>) -> Option<()> {
+        if self.is_x11() {
+            Some(unsafe {
+                x11::gdk_x11_window_set_utf8_property(self.unwrap_pointer(), name.borrow_to_glib().0,
+                    value.borrow_to_glib().0)
+            })
+        } else {
+            None
+        }
+    }
+
+    fn set_user_time(&self, timestamp: u32) -> Option<()> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_window_set_user_time(self.unwrap_pointer(), timestamp) })
+        } else {
+            None
+        }
+    }
+
+    fn get_server_time(&self) -> Option<u32> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_get_server_time(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+}
+
+/// X11 specific functions of a display. The methods return None when the display isn't handled
+/// by the X11 backend (under Wayland or Broadway).
+pub trait X11Display {
+    /// Returns true if the display is handled by the X11 backend.
+    fn is_x11(&self) -> bool;
+    /// Gets the Xlib display, None if it isn't an X11 display.
+    fn get_xdisplay(&self) -> Option<*mut XDisplay>;
+    /// Gets the timestamp of the last user interaction with the application.
+    fn get_user_time(&self) -> Option<u32>;
+    /// Gets the startup notification ID of the application, None if it has none.
+    fn get_startup_notification_id(&self) -> Option<String>;
+    /// Sets the startup notification ID of the application, for when it didn't get it from
+    /// DESKTOP_STARTUP_ID (given by another instance of the application for example).
+    fn set_startup_notification_id(&self, startup_id: &str) -> Option<()>;
+    fn error_trap_push(&self) -> Option<()>;
+    /// Removes the last error trap, returns the X error code of the last error caught or 0.
+    fn error_trap_pop(&self) -> Option<i32>;
+    fn error_trap_pop_ignored(&self) -> Option<()>;
+    /// Runs `f` between `error_trap_push` and `error_trap_pop`, returns the X error code as
+    /// Err if an X error was caught. `f` isn't run if the display isn't an X11 display.
+    fn with_error_trap<T, F: FnOnce() -> T>(&self, f: F) -> Option<Result<T, i32>>;
+    /// Grabs the X server, other clients can't do requests until `ungrab` is called.
+    fn grab(&self) -> Option<()>;
+    fn ungrab(&self) -> Option<()>;
+}
+
+impl X11Display for gdk::Display {
+    fn is_x11(&self) -> bool {
+        unsafe {
+            to_bool(::glib::ffi::g_type_check_instance_is_a(self.unwrap_pointer() as *mut ::glib::ffi::C_GObject,
+                x11::gdk_x11_display_get_type()))
+        }
+    }
+
+    fn get_xdisplay(&self) -> Option<*mut XDisplay> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_display_get_xdisplay(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+
+    fn get_user_time(&self) -> Option<u32> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_display_get_user_time(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+
+    fn get_startup_notification_id(&self) -> Option<String> {
+        if self.is_x11() {
+            unsafe { FromGlibPtr::borrow(x11::gdk_x11_display_get_startup_notification_id(self.unwrap_pointer())) }
+        } else {
+            None
+        }
+    }
+
+    fn set_startup_notification_id(&self, startup_id: &str) -> Option<()> {
+        if self.is_x11() {
+            Some(unsafe {
+                x11::gdk_x11_display_set_startup_notification_id(self.unwrap_pointer(), startup_id.borrow_to_glib().0)
+            })
+        } else {
+            None
+        }
+    }
+
+    fn error_trap_push(&self) -> Option<()> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_display_error_trap_push(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+
+    fn error_trap_pop(&self) -> Option<i32> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_display_error_trap_pop(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+
+    fn error_trap_pop_ignored(&self) -> Option<()> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_display_error_trap_pop_ignored(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+
+    fn with_error_trap<T, F: FnOnce() -> T>(&self, f: F) -> Option<Result<T, i32>> {
+        if !self.is_x11() {
+            return None;
+        }
+        unsafe { x11::gdk_x11_display_error_trap_push(self.unwrap_pointer()) };
+        let ret = f();
+
+        match unsafe { x11::gdk_x11_display_error_trap_pop(self.unwrap_pointer()) } {
+            0 => Some(Ok(ret)),
+            code => Some(Err(code))
+        }
+    }
+
+    fn grab(&self) -> Option<()> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_display_grab(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+
+    fn ungrab(&self) -> Option<()> {
+        if self.is_x11() {
+            Some(unsafe { x11::gdk_x11_display_ungrab(self.unwrap_pointer()) })
+        } else {
+            None
+        }
+    }
+}
+
+/// Runs `f` between `gdk::error_trap_push` and `gdk::error_trap_pop` (for all the displays),
+/// returns the X error code as Err if an X error was caught.
+///
+/// ```ignore
+/// let ret = gdk::x11::error_trap(|| window.set_utf8_property("WM_CLASS", Some("tool")));
+/// ```
+pub fn error_trap<T, F: FnOnce() -> T>(f: F) -> Result<T, i32> {
+    gdk::error_trap_push();
+    let ret = f();
+
+    match gdk::error_trap_pop() {
+        0 => Ok(ret),
+        code => Err(code)
+    }
+}
+
+/// Same as `error_trap`, but ignores the X errors without waiting for them.
+pub fn error_trap_ignored<T, F: FnOnce() -> T>(f: F) -> T {
+    gdk::error_trap_push();
+    let ret = f();
+
+    gdk::error_trap_pop_ignored();
+    ret
+}
@@ -0,0 +1,67 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Keyboard Accelerators — Parsing and formatting of accelerator strings
+
+use glib::translate::{FromGlibPtr, ToGlibPtr};
+use glib::to_bool;
+use gtk::ffi;
+use gdk;
+use libc::c_uint;
+
+/// Returns whether the keyval and modifiers form a valid accelerator.
+pub fn accelerator_valid(keyval: u32, modifiers: gdk::ModifierType) -> bool {
+    unsafe { to_bool(ffi::gtk_accelerator_valid(keyval as c_uint, modifiers)) }
+}
+
+/// Parses an accelerator string such as "<Control>a" or "<Shift><Alt>F1". Returns None if the
+/// string can't be parsed.
+pub fn accelerator_parse(accelerator: &str) -> Option<(u32, gdk::ModifierType)> {
+    let mut key : c_uint = 0;
+    let mut mods = gdk::ModifierType::empty();
+
+    unsafe { ffi::gtk_accelerator_parse(accelerator.borrow_to_glib().0, &mut key, &mut mods) };
+    if key == 0 && mods.is_empty() {
+        None
+    } else {
+        Some((key, mods))
+    }
+}
+
+/// Converts an accelerator to a string parseable by `accelerator_parse`.
+pub fn accelerator_name(accelerator_key: u32, accelerator_mods: gdk::ModifierType) -> Option<String> {
+    unsafe {
+        FromGlibPtr::take(
+            ffi::gtk_accelerator_name(accelerator_key as c_uint, accelerator_mods))
+    }
+}
+
+/// Converts an accelerator to a string suitable to be displayed to the user, "Ctrl+A" for
+/// instance.
+pub fn accelerator_get_label(accelerator_key: u32, accelerator_mods: gdk::ModifierType) -> Option<String> {
+    unsafe {
+        FromGlibPtr::take(
+            ffi::gtk_accelerator_get_label(accelerator_key as c_uint, accelerator_mods))
+    }
+}
+
+/// Sets the modifiers that are considered when checking accelerators.
+pub fn accelerator_set_default_mod_mask(default_mod_mask: gdk::ModifierType) {
+    unsafe { ffi::gtk_accelerator_set_default_mod_mask(default_mod_mask) }
+}
+
+pub fn accelerator_get_default_mod_mask() -> gdk::ModifierType {
+    unsafe { ffi::gtk_accelerator_get_default_mod_mask() }
+}
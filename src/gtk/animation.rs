@@ -0,0 +1,221 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Animations — Eased transitions driven by the frame clock of a widget
+
+use std::rc::Rc;
+use std::cell::Cell;
+use libc::c_uint;
+use gtk::{ffi, WidgetTrait};
+use gdk;
+
+/// Identifier of a tick callback, returned by `add_tick_callback`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TickCallbackId {
+    id: u32
+}
+
+impl TickCallbackId {
+    #[doc(hidden)]
+    pub fn new(id: u32) -> TickCallbackId {
+        TickCallbackId { id: id }
+    }
+
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+}
+
+/// Easing curves mapping the progress of a transition (from 0 to 1) to the progress of the value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Easing {
+    Linear,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic
+}
+
+impl Easing {
+    /// Applies the curve to `t`, which is clamped between 0 and 1.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = if t < 0. { 0. } else if t > 1. { 1. } else { t };
+
+        match *self {
+            Easing::Linear => t,
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => t * (2. - t),
+            Easing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2. * t * t
+                } else {
+                    -1. + (4. - 2. * t) * t
+                }
+            }
+            Easing::EaseInCubic => t * t * t,
+            Easing::EaseOutCubic => {
+                let u = t - 1.;
+                u * u * u + 1.
+            }
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    let u = 2. * t - 2.;
+                    (t - 1.) * u * u + 1.
+                }
+            }
+        }
+    }
+}
+
+/// Describes a transition of a value between two bounds over a duration.
+///
+/// ```ignore
+/// let animation = gtk::Transition::new(0., 1., 250).easing(gtk::Easing::EaseOutCubic)
+///     .start(&widget, move |value| { /* update and queue_draw */ });
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Transition {
+    from: f64,
+    to: f64,
+    duration: i64,
+    easing: Easing
+}
+
+impl Transition {
+    /// Creates a linear transition from `from` to `to` lasting `duration_ms` milliseconds.
+    pub fn new(from: f64, to: f64, duration_ms: u32) -> Transition {
+        Transition {
+            from: from,
+            to: to,
+            duration: duration_ms as i64 * 1000,
+            easing: Easing::Linear
+        }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Transition {
+        self.easing = easing;
+        self
+    }
+
+    pub fn get_easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Returns the duration of the transition in microseconds, the unit of the frame clock.
+    pub fn get_duration(&self) -> i64 {
+        self.duration
+    }
+
+    /// Returns the value of the transition `elapsed` microseconds after it started.
+    pub fn value_at(&self, elapsed: i64) -> f64 {
+        let progress = if self.duration <= 0 {
+            1.
+        } else {
+            elapsed as f64 / self.duration as f64
+        };
+
+        self.from + (self.to - self.from) * self.easing.apply(progress)
+    }
+
+    /// Starts the transition on the frame clock of `widget`. `setter` is called once per frame
+    /// with the current value, and last with the final value unless the animation is cancelled.
+    pub fn start<T, F>(&self, widget: &T, mut setter: F) -> Animation
+        where T: WidgetTrait, F: FnMut(f64) + 'static {
+        let state = Rc::new(Cell::new(AnimationState::Running));
+        let tick_state = state.clone();
+        let transition = *self;
+        let mut start_time = None;
+
+        let id = widget.add_tick_callback(move |frame_clock: &gdk::FrameClock| {
+            if tick_state.get() == AnimationState::Cancelled {
+                return false;
+            }
+
+            let now = frame_clock.get_frame_time();
+            let start = match start_time {
+                Some(start) => start,
+                None => {
+                    start_time = Some(now);
+                    now
+                }
+            };
+            let elapsed = now - start;
+
+            if elapsed >= transition.duration {
+                setter(transition.to);
+                tick_state.set(AnimationState::Finished);
+                false
+            } else {
+                setter(transition.value_at(elapsed));
+                true
+            }
+        });
+
+        unsafe { ::glib::ffi::g_object_ref(widget.unwrap_widget() as *mut ::glib::ffi::C_GObject) };
+
+        Animation {
+            id: id,
+            state: state,
+            widget: widget.unwrap_widget()
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum AnimationState {
+    Running,
+    Finished,
+    Cancelled
+}
+
+/// Handle on a running transition, it keeps a reference on the animated widget. Dropping it
+/// doesn't stop the animation.
+pub struct Animation {
+    id: TickCallbackId,
+    state: Rc<Cell<AnimationState>>,
+    widget: *mut ffi::C_GtkWidget
+}
+
+impl Animation {
+    /// Stops the animation and removes its tick callback, the setter won't be called anymore.
+    pub fn cancel(&self) {
+        if self.state.get() == AnimationState::Running {
+            self.state.set(AnimationState::Cancelled);
+            unsafe { ffi::gtk_widget_remove_tick_callback(self.widget, self.id.get_id() as c_uint) }
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state.get() == AnimationState::Finished
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.state.get() == AnimationState::Cancelled
+    }
+
+    pub fn get_tick_callback_id(&self) -> TickCallbackId {
+        self.id
+    }
+}
+
+impl Drop for Animation {
+    fn drop(&mut self) {
+        unsafe { ::glib::ffi::g_object_unref(self.widget as *mut ::glib::ffi::C_GObject) }
+    }
+}
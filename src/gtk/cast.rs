@@ -512,6 +512,10 @@ pub fn GTK_TEXT_MARK(widget: *mut ::glib::ffi::C_GObject) -> *mut ffi::C_GtkText
     unsafe { ffi::cast_GtkTextMark(widget) }
 }
 
+pub fn GTK_CLIPBOARD(obj: *mut ::glib::ffi::C_GObject) -> *mut ffi::C_GtkClipboard {
+    unsafe { ffi::cast_GtkClipboard(obj) }
+}
+
 #[cfg(feature = "GTK_3_10")]
 pub fn GTK_PLACES_SIDEBAR(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkPlacesSidebar {
     unsafe { ffi::cast_GtkPlacesSidebar(widget) }
@@ -546,6 +550,15 @@ pub fn GTK_SOCKET(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkSocket {
     unsafe { ffi::cast_GtkSocket(widget) }
 }
 
+#[cfg(target_os = "linux")]
+pub fn GTK_PLUG(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkPlug {
+    unsafe { ffi::cast_GtkPlug(widget) }
+}
+
 pub fn GTK_EVENT_BOX(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkEventBox {
     unsafe { ffi::cast_GtkEventBox(widget) }
-}
\ No newline at end of file
+}
+
+pub fn GTK_OFFSCREEN_WINDOW(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkOffscreenWindow {
+    unsafe { ffi::cast_GtkOffscreenWindow(widget) }
+}
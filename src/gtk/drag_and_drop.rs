@@ -0,0 +1,44 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Drag and Drop — Functions for controlling drag and drop handling
+
+use gtk::ffi;
+use gdk;
+use glib::to_gboolean;
+use glib::translate::ToGlibPtr;
+use libc::c_int;
+
+/// Informs the drag source that the drop is finished and that the data of the drag will no
+/// longer be required. `del` asks the source to delete the original data (move action).
+pub fn drag_finish(context: &gdk::DragContext, success: bool, del: bool, time_: u32) {
+    unsafe { ffi::gtk_drag_finish(context.unwrap_pointer(), to_gboolean(success), to_gboolean(del), time_) }
+}
+
+/// Sets the icon of the drag to the default icon. Must be called from a "drag-begin" handler.
+pub fn drag_set_icon_default(context: &gdk::DragContext) {
+    unsafe { ffi::gtk_drag_set_icon_default(context.unwrap_pointer()) }
+}
+
+/// Sets the icon of the drag, the hot spot is the position of the pointer in the pixbuf.
+pub fn drag_set_icon_pixbuf(context: &gdk::DragContext, pixbuf: &gdk::Pixbuf, hot_x: i32, hot_y: i32) {
+    unsafe { ffi::gtk_drag_set_icon_pixbuf(context.unwrap_pointer(), pixbuf.unwrap_pointer(), hot_x as c_int, hot_y as c_int) }
+}
+
+pub fn drag_set_icon_name(context: &gdk::DragContext, icon_name: &str, hot_x: i32, hot_y: i32) {
+    unsafe {
+        ffi::gtk_drag_set_icon_name(context.unwrap_pointer(), icon_name.borrow_to_glib().0, hot_x as c_int, hot_y as c_int)
+    }
+}
@@ -0,0 +1,1018 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Gestures — Recognizers of touch and pointer gestures
+//!
+//! The recognizers follow the points of contact of a widget, touches and the primary button of
+//! the pointer, and report high level gestures through callbacks:
+//!
+//! ```ignore
+//! let zoom = gtk::GestureZoom::new();
+//! zoom.connect_scale_changed(move |scale| chart.set_zoom(start_zoom * scale));
+//! zoom.attach(&drawing_area);
+//! ```
+//!
+//! A gesture can be attached to several widgets, it then follows the points of each widget
+//! separately: the settings and the callbacks are shared, the state of the gesture isn't. The
+//! handlers stay connected as long as the widget lives and are freed with it.
+
+use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+use std::f64::consts::PI;
+use std::mem;
+use libc::c_uint;
+use glib::ffi::{self, gpointer, Gboolean};
+use glib::to_gboolean;
+use glib::traits::Connect;
+use gdk::{self, EventTrait};
+use gdk_ffi::enums::event_mask;
+use gtk::WidgetTrait;
+use gtk::signals::{TouchEvent, ButtonPressEvent, ButtonReleaseEvent, MotionNotifyEvent};
+
+/// Distance in pixels a point can move before a tap or a long press is cancelled.
+pub const DEFAULT_THRESHOLD: f64 = 8.;
+/// Delay in milliseconds before a press becomes a long press.
+pub const DEFAULT_LONG_PRESS_DELAY: u32 = 500;
+/// Delay in milliseconds between two taps counted as a multiple tap.
+pub const DEFAULT_MULTI_TAP_DELAY: u32 = 400;
+/// Time window in milliseconds used to compute the velocity of a swipe.
+const SWIPE_CAPTURE_PERIOD: u32 = 150;
+
+/// Widgets gestures can be attached to: they emit the touch, button and motion events.
+pub trait GestureWidget: WidgetTrait + Connect<'static, TouchEvent<'static>> + Connect<'static, ButtonPressEvent<'static>>
+    + Connect<'static, ButtonReleaseEvent<'static>> + Connect<'static, MotionNotifyEvent<'static>> {}
+
+impl<T> GestureWidget for T where T: WidgetTrait + Connect<'static, TouchEvent<'static>> + Connect<'static, ButtonPressEvent<'static>>
+    + Connect<'static, ButtonReleaseEvent<'static>> + Connect<'static, MotionNotifyEvent<'static>> {}
+
+/// Identifies a point of contact: the pointer or a touch sequence.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PointId {
+    Pointer,
+    Touch(gdk::EventSequence)
+}
+
+/// A point of contact followed by a gesture, in widget coordinates. Times are event times in
+/// milliseconds.
+#[derive(Clone, Copy, Debug)]
+pub struct Point {
+    pub id: PointId,
+    pub start_x: f64,
+    pub start_y: f64,
+    pub x: f64,
+    pub y: f64,
+    pub start_time: u32,
+    pub time: u32
+}
+
+impl Point {
+    /// Returns the offset of the point since it started.
+    pub fn get_offset(&self) -> (f64, f64) {
+        (self.x - self.start_x, self.y - self.start_y)
+    }
+
+    fn get_distance(&self) -> f64 {
+        let (dx, dy) = self.get_offset();
+
+        (dx * dx + dy * dy).sqrt()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Phase {
+    Begin,
+    Update,
+    End,
+    Cancel
+}
+
+trait Recognizer {
+    /// The state of the gesture on one widget.
+    type State;
+
+    fn new_state(&self) -> Self::State;
+    /// Called for each change of a point, `points` holds all the points in contact including
+    /// `point`, also on `End` and `Cancel`.
+    fn handle(&self, state: &Self::State, phase: Phase, point: &Point, points: &[Point]);
+}
+
+struct Tracker<R: Recognizer> {
+    points: RefCell<Vec<Point>>,
+    state: R::State,
+    recognizer: R
+}
+
+impl<R: Recognizer> Tracker<R> {
+    fn new(recognizer: R) -> Tracker<R> {
+        Tracker {
+            points: RefCell::new(Vec::new()),
+            state: recognizer.new_state(),
+            recognizer: recognizer
+        }
+    }
+
+    fn process(&self, phase: Phase, id: PointId, x: f64, y: f64, time: u32) {
+        // the points aren't borrowed while the recognizer runs, its callbacks may run a nested
+        // main loop dispatching more events
+        let (point, points) = {
+            let mut points = self.points.borrow_mut();
+            let index = match points.iter().position(|point| point.id == id) {
+                Some(index) => {
+                    if phase == Phase::Begin {
+                        return;
+                    }
+                    let point = &mut points[index];
+
+                    point.x = x;
+                    point.y = y;
+                    point.time = time;
+                    index
+                }
+                None => {
+                    if phase != Phase::Begin {
+                        return;
+                    }
+                    points.push(Point {
+                        id: id,
+                        start_x: x,
+                        start_y: y,
+                        x: x,
+                        y: y,
+                        start_time: time,
+                        time: time
+                    });
+                    points.len() - 1
+                }
+            };
+            let point = points[index];
+
+            if phase == Phase::End || phase == Phase::Cancel {
+                let remaining = points.clone();
+
+                points.remove(index);
+                (point, remaining)
+            } else {
+                (point, points.clone())
+            }
+        };
+
+        self.recognizer.handle(&self.state, phase, &point, &points);
+    }
+}
+
+fn is_emulated<E: EventTrait>(event: &E) -> bool {
+    match event.as_event().get_source_device() {
+        Some(device) => device.get_source() == gdk::InputSource::TouchScreen,
+        None => false
+    }
+}
+
+/// The event handlers of a recognizer attached to a widget, freed with the widget.
+struct Handlers {
+    _touch: Box<FnMut(&gdk::EventTouch) -> bool>,
+    _press: Box<FnMut(&gdk::EventButton) -> bool>,
+    _release: Box<FnMut(&gdk::EventButton) -> bool>,
+    _motion: Box<FnMut(&gdk::EventMotion) -> bool>
+}
+
+fn connect_recognizer<W: GestureWidget, R: Recognizer + 'static>(widget: &W, recognizer: R) {
+    let tracker = Rc::new(Tracker::new(recognizer));
+
+    widget.add_events(event_mask::ButtonPressMask | event_mask::ButtonReleaseMask | event_mask::PointerMotionMask
+        | event_mask::TouchMask);
+
+    // The signals only borrow the closures, they are kept in Handlers until the widget is
+    // disposed, after its signal handlers are disconnected.
+    let touch_tracker = tracker.clone();
+    let mut touch : Box<FnMut(&gdk::EventTouch) -> bool> = Box::new(move |event: &gdk::EventTouch| {
+        let sequence = match event.as_event().get_event_sequence() {
+            Some(sequence) => sequence,
+            None => return false
+        };
+        let phase = match event._type {
+            gdk::EventType::TouchBegin => Phase::Begin,
+            gdk::EventType::TouchUpdate => Phase::Update,
+            gdk::EventType::TouchEnd => Phase::End,
+            _ => Phase::Cancel
+        };
+
+        touch_tracker.process(phase, PointId::Touch(sequence), event.x, event.y, event.time);
+        false
+    });
+    widget.connect(TouchEvent::new(unsafe { mem::transmute(&mut *touch) }));
+
+    let press_tracker = tracker.clone();
+    let mut press : Box<FnMut(&gdk::EventButton) -> bool> = Box::new(move |event: &gdk::EventButton| {
+        if event._type == gdk::EventType::ButtonPress && event.button == 1 && !is_emulated(event) {
+            press_tracker.process(Phase::Begin, PointId::Pointer, event.x, event.y, event.time);
+        }
+        false
+    });
+    widget.connect(ButtonPressEvent::new(unsafe { mem::transmute(&mut *press) }));
+
+    let release_tracker = tracker.clone();
+    let mut release : Box<FnMut(&gdk::EventButton) -> bool> = Box::new(move |event: &gdk::EventButton| {
+        if event.button == 1 && !is_emulated(event) {
+            release_tracker.process(Phase::End, PointId::Pointer, event.x, event.y, event.time);
+        }
+        false
+    });
+    widget.connect(ButtonReleaseEvent::new(unsafe { mem::transmute(&mut *release) }));
+
+    let motion_tracker = tracker;
+    let mut motion : Box<FnMut(&gdk::EventMotion) -> bool> = Box::new(move |event: &gdk::EventMotion| {
+        if !is_emulated(event) {
+            motion_tracker.process(Phase::Update, PointId::Pointer, event.x, event.y, event.time);
+        }
+        false
+    });
+    widget.connect(MotionNotifyEvent::new(unsafe { mem::transmute(&mut *motion) }));
+
+    let handlers = Box::new(Handlers {
+        _touch: touch,
+        _press: press,
+        _release: release,
+        _motion: motion
+    });
+
+    unsafe {
+        ffi::g_object_weak_ref(widget.unwrap_widget() as *mut ffi::C_GObject, handlers_destroy, mem::transmute(handlers));
+    }
+}
+
+extern "C" fn handlers_destroy(user_data: gpointer, _: *mut ffi::C_GObject) {
+    unsafe {
+        let handlers : Box<Handlers> = mem::transmute(user_data);
+        drop(handlers);
+    }
+}
+
+/// Calls `callback` every `interval` milliseconds until it returns false.
+fn timeout_add<F: FnMut() -> bool + 'static>(interval: u32, callback: F) -> u32 {
+    let callback : Box<Box<FnMut() -> bool>> = Box::new(Box::new(callback));
+
+    unsafe {
+        ffi::g_timeout_add_full(ffi::G_PRIORITY_DEFAULT, interval as c_uint, timeout_trampoline, mem::transmute(callback),
+            timeout_destroy) as u32
+    }
+}
+
+fn timeout_remove(id: u32) {
+    unsafe { ffi::g_source_remove(id as c_uint); }
+}
+
+extern "C" fn timeout_trampoline(user_data: gpointer) -> Gboolean {
+    unsafe {
+        let callback : &mut Box<FnMut() -> bool> = mem::transmute(user_data);
+
+        to_gboolean(callback())
+    }
+}
+
+extern "C" fn timeout_destroy(user_data: gpointer) {
+    unsafe {
+        let callback : Box<Box<FnMut() -> bool>> = mem::transmute(user_data);
+        drop(callback);
+    }
+}
+
+/// Calls the callback in `slot`. It is taken out of the slot during the call, so it can connect
+/// another callback or run a nested main loop; it isn't called again until it returns.
+fn emit<F: ?Sized>(slot: &RefCell<Option<Box<F>>>, f: &mut FnMut(&mut F)) {
+    let callback = slot.borrow_mut().take();
+
+    if let Some(mut callback) = callback {
+        f(&mut *callback);
+
+        let mut slot = slot.borrow_mut();
+
+        // keep the callback connected during the call, if any
+        if slot.is_none() {
+            *slot = Some(callback);
+        }
+    }
+}
+
+/// Returns the first two points, the ones followed by two finger gestures.
+fn get_pair(points: &[Point]) -> Option<(&Point, &Point)> {
+    if points.len() < 2 {
+        None
+    } else {
+        Some((&points[0], &points[1]))
+    }
+}
+
+fn is_in_pair(point: &Point, points: &[Point]) -> bool {
+    points.iter().take(2).any(|other| other.id == point.id)
+}
+
+struct TapState {
+    valid: bool,
+    last_tap: Option<(u32, f64, f64, u32)>
+}
+
+struct TapInner {
+    threshold: Cell<f64>,
+    multi_tap_delay: Cell<u32>,
+    tapped: RefCell<Option<Box<FnMut(u32, f64, f64)>>>
+}
+
+/// Recognizes taps and clicks of a single point, counting multiple taps (double tap...).
+#[derive(Clone)]
+pub struct GestureTap {
+    inner: Rc<TapInner>
+}
+
+impl GestureTap {
+    pub fn new() -> GestureTap {
+        GestureTap {
+            inner: Rc::new(TapInner {
+                threshold: Cell::new(DEFAULT_THRESHOLD),
+                multi_tap_delay: Cell::new(DEFAULT_MULTI_TAP_DELAY),
+                tapped: RefCell::new(None)
+            })
+        }
+    }
+
+    /// Sets the callback called on release with the number of taps and their position.
+    pub fn connect_tapped<F: FnMut(u32, f64, f64) + 'static>(&self, callback: F) {
+        *self.inner.tapped.borrow_mut() = Some(Box::new(callback));
+    }
+
+    pub fn set_threshold(&self, threshold: f64) {
+        self.inner.threshold.set(threshold);
+    }
+
+    pub fn set_multi_tap_delay(&self, delay: u32) {
+        self.inner.multi_tap_delay.set(delay);
+    }
+
+    pub fn attach<W: GestureWidget>(&self, widget: &W) {
+        connect_recognizer(widget, self.clone());
+    }
+}
+
+impl Recognizer for GestureTap {
+    type State = RefCell<TapState>;
+
+    fn new_state(&self) -> RefCell<TapState> {
+        RefCell::new(TapState { valid: false, last_tap: None })
+    }
+
+    fn handle(&self, state: &RefCell<TapState>, phase: Phase, point: &Point, points: &[Point]) {
+        let threshold = self.inner.threshold.get();
+        let multi_tap_delay = self.inner.multi_tap_delay.get();
+        let tap = {
+            let mut state = state.borrow_mut();
+
+            match phase {
+                Phase::Begin => {
+                    state.valid = points.len() == 1;
+                    None
+                }
+                Phase::Update => {
+                    if point.get_distance() > threshold {
+                        state.valid = false;
+                    }
+                    None
+                }
+                Phase::End => {
+                    if state.valid && points.len() == 1 && point.get_distance() <= threshold {
+                        let n_press = match state.last_tap {
+                            Some((time, x, y, n_press)) if point.start_time.wrapping_sub(time) <= multi_tap_delay
+                                && (point.x - x).abs() <= threshold && (point.y - y).abs() <= threshold => n_press + 1,
+                            _ => 1
+                        };
+
+                        state.valid = false;
+                        state.last_tap = Some((point.time, point.x, point.y, n_press));
+                        Some(n_press)
+                    } else {
+                        state.valid = false;
+                        None
+                    }
+                }
+                Phase::Cancel => {
+                    state.valid = false;
+                    None
+                }
+            }
+        };
+
+        if let Some(n_press) = tap {
+            emit(&self.inner.tapped, &mut |callback| callback(n_press, point.x, point.y));
+        }
+    }
+}
+
+struct LongPressState {
+    timeout: Option<u32>,
+    position: (f64, f64)
+}
+
+struct LongPressInner {
+    threshold: Cell<f64>,
+    delay: Cell<u32>,
+    pressed: RefCell<Option<Box<FnMut(f64, f64)>>>,
+    cancelled: RefCell<Option<Box<FnMut()>>>
+}
+
+/// Recognizes a single point held without moving during a delay.
+#[derive(Clone)]
+pub struct GestureLongPress {
+    inner: Rc<LongPressInner>
+}
+
+impl GestureLongPress {
+    pub fn new() -> GestureLongPress {
+        GestureLongPress {
+            inner: Rc::new(LongPressInner {
+                threshold: Cell::new(DEFAULT_THRESHOLD),
+                delay: Cell::new(DEFAULT_LONG_PRESS_DELAY),
+                pressed: RefCell::new(None),
+                cancelled: RefCell::new(None)
+            })
+        }
+    }
+
+    /// Sets the callback called with the position of the point once it has been held long enough.
+    pub fn connect_pressed<F: FnMut(f64, f64) + 'static>(&self, callback: F) {
+        *self.inner.pressed.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback called when a press ends or moves before the delay.
+    pub fn connect_cancelled<F: FnMut() + 'static>(&self, callback: F) {
+        *self.inner.cancelled.borrow_mut() = Some(Box::new(callback));
+    }
+
+    pub fn set_threshold(&self, threshold: f64) {
+        self.inner.threshold.set(threshold);
+    }
+
+    /// Sets the delay of the press in milliseconds.
+    pub fn set_delay(&self, delay: u32) {
+        self.inner.delay.set(delay);
+    }
+
+    pub fn attach<W: GestureWidget>(&self, widget: &W) {
+        connect_recognizer(widget, self.clone());
+    }
+
+    fn start(&self, state: &Rc<RefCell<LongPressState>>, x: f64, y: f64) {
+        let gesture = self.clone();
+        let press = state.clone();
+        let id = timeout_add(self.inner.delay.get(), move || {
+            let position = {
+                let mut press = press.borrow_mut();
+
+                press.timeout = None;
+                press.position
+            };
+
+            emit(&gesture.inner.pressed, &mut |callback| callback(position.0, position.1));
+            false
+        });
+        let mut state = state.borrow_mut();
+
+        state.timeout = Some(id);
+        state.position = (x, y);
+    }
+
+    /// Stops the pending press, returns true if there was one.
+    fn stop(state: &RefCell<LongPressState>) -> bool {
+        match state.borrow_mut().timeout.take() {
+            Some(id) => {
+                timeout_remove(id);
+                true
+            }
+            None => false
+        }
+    }
+}
+
+impl Recognizer for GestureLongPress {
+    // shared with the pending timeout
+    type State = Rc<RefCell<LongPressState>>;
+
+    fn new_state(&self) -> Rc<RefCell<LongPressState>> {
+        Rc::new(RefCell::new(LongPressState { timeout: None, position: (0., 0.) }))
+    }
+
+    fn handle(&self, state: &Rc<RefCell<LongPressState>>, phase: Phase, point: &Point, points: &[Point]) {
+        let cancelled = match phase {
+            Phase::Begin => {
+                let cancelled = GestureLongPress::stop(state);
+
+                if points.len() == 1 {
+                    self.start(state, point.x, point.y);
+                }
+                cancelled
+            }
+            Phase::Update => point.get_distance() > self.inner.threshold.get() && GestureLongPress::stop(state),
+            Phase::End | Phase::Cancel => GestureLongPress::stop(state)
+        };
+
+        if cancelled {
+            emit(&self.inner.cancelled, &mut |callback| callback());
+        }
+    }
+}
+
+struct DragInner {
+    drag_begin: RefCell<Option<Box<FnMut(f64, f64)>>>,
+    drag_update: RefCell<Option<Box<FnMut(f64, f64)>>>,
+    drag_end: RefCell<Option<Box<FnMut(f64, f64)>>>
+}
+
+/// Follows the first point of contact, reporting its offset from the start position.
+#[derive(Clone)]
+pub struct GestureDrag {
+    inner: Rc<DragInner>
+}
+
+impl GestureDrag {
+    pub fn new() -> GestureDrag {
+        GestureDrag {
+            inner: Rc::new(DragInner {
+                drag_begin: RefCell::new(None),
+                drag_update: RefCell::new(None),
+                drag_end: RefCell::new(None)
+            })
+        }
+    }
+
+    /// Sets the callback called with the start position of the drag.
+    pub fn connect_drag_begin<F: FnMut(f64, f64) + 'static>(&self, callback: F) {
+        *self.inner.drag_begin.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback called with the offset from the start position when the point moves.
+    pub fn connect_drag_update<F: FnMut(f64, f64) + 'static>(&self, callback: F) {
+        *self.inner.drag_update.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback called with the final offset when the point is released.
+    pub fn connect_drag_end<F: FnMut(f64, f64) + 'static>(&self, callback: F) {
+        *self.inner.drag_end.borrow_mut() = Some(Box::new(callback));
+    }
+
+    pub fn attach<W: GestureWidget>(&self, widget: &W) {
+        connect_recognizer(widget, self.clone());
+    }
+}
+
+impl Recognizer for GestureDrag {
+    // the point followed
+    type State = RefCell<Option<PointId>>;
+
+    fn new_state(&self) -> RefCell<Option<PointId>> {
+        RefCell::new(None)
+    }
+
+    fn handle(&self, state: &RefCell<Option<PointId>>, phase: Phase, point: &Point, points: &[Point]) {
+        let current = *state.borrow();
+        let (dx, dy) = point.get_offset();
+
+        match phase {
+            Phase::Begin => {
+                if current.is_none() && points.len() == 1 {
+                    *state.borrow_mut() = Some(point.id);
+                    emit(&self.inner.drag_begin, &mut |callback| callback(point.start_x, point.start_y));
+                }
+            }
+            Phase::Update => {
+                if current == Some(point.id) {
+                    emit(&self.inner.drag_update, &mut |callback| callback(dx, dy));
+                }
+            }
+            Phase::End | Phase::Cancel => {
+                if current == Some(point.id) {
+                    *state.borrow_mut() = None;
+                    emit(&self.inner.drag_end, &mut |callback| callback(dx, dy));
+                }
+            }
+        }
+    }
+}
+
+struct SwipeInner {
+    swipe: RefCell<Option<Box<FnMut(f64, f64)>>>
+}
+
+/// Reports the velocity of a single point when it is released.
+#[derive(Clone)]
+pub struct GestureSwipe {
+    inner: Rc<SwipeInner>
+}
+
+impl GestureSwipe {
+    pub fn new() -> GestureSwipe {
+        GestureSwipe {
+            inner: Rc::new(SwipeInner {
+                swipe: RefCell::new(None)
+            })
+        }
+    }
+
+    /// Sets the callback called with the velocity in pixels per second on both axes.
+    pub fn connect_swipe<F: FnMut(f64, f64) + 'static>(&self, callback: F) {
+        *self.inner.swipe.borrow_mut() = Some(Box::new(callback));
+    }
+
+    pub fn attach<W: GestureWidget>(&self, widget: &W) {
+        connect_recognizer(widget, self.clone());
+    }
+}
+
+impl Recognizer for GestureSwipe {
+    // the recent positions of the point, with their times
+    type State = RefCell<Vec<(u32, f64, f64)>>;
+
+    fn new_state(&self) -> RefCell<Vec<(u32, f64, f64)>> {
+        RefCell::new(Vec::new())
+    }
+
+    fn handle(&self, state: &RefCell<Vec<(u32, f64, f64)>>, phase: Phase, point: &Point, points: &[Point]) {
+        let velocity = {
+            let mut history = state.borrow_mut();
+
+            if points.len() != 1 {
+                history.clear();
+                return;
+            }
+            match phase {
+                Phase::Begin | Phase::Update => {
+                    history.retain(|&(time, _, _)| point.time.wrapping_sub(time) <= SWIPE_CAPTURE_PERIOD);
+                    history.push((point.time, point.x, point.y));
+                    return;
+                }
+                Phase::Cancel => {
+                    history.clear();
+                    return;
+                }
+                Phase::End => {
+                    let velocity = match history.iter().find(|&&(time, _, _)| point.time.wrapping_sub(time) <= SWIPE_CAPTURE_PERIOD) {
+                        Some(&(time, x, y)) if point.time != time => {
+                            let elapsed = point.time.wrapping_sub(time) as f64 / 1000.;
+
+                            ((point.x - x) / elapsed, (point.y - y) / elapsed)
+                        }
+                        _ => (0., 0.)
+                    };
+
+                    history.clear();
+                    velocity
+                }
+            }
+        };
+
+        emit(&self.inner.swipe, &mut |callback| callback(velocity.0, velocity.1));
+    }
+}
+
+fn get_pair_distance(first: &Point, second: &Point) -> f64 {
+    let dx = second.x - first.x;
+    let dy = second.y - first.y;
+
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn get_pair_angle(first: &Point, second: &Point) -> f64 {
+    (second.y - first.y).atan2(second.x - first.x)
+}
+
+struct ZoomInner {
+    begin: RefCell<Option<Box<FnMut()>>>,
+    scale_changed: RefCell<Option<Box<FnMut(f64)>>>,
+    end: RefCell<Option<Box<FnMut()>>>
+}
+
+/// Recognizes pinch to zoom with two points, reporting the ratio between the current distance
+/// of the points and their distance when the gesture began.
+#[derive(Clone)]
+pub struct GestureZoom {
+    inner: Rc<ZoomInner>
+}
+
+impl GestureZoom {
+    pub fn new() -> GestureZoom {
+        GestureZoom {
+            inner: Rc::new(ZoomInner {
+                begin: RefCell::new(None),
+                scale_changed: RefCell::new(None),
+                end: RefCell::new(None)
+            })
+        }
+    }
+
+    /// Sets the callback called when the second point touches.
+    pub fn connect_begin<F: FnMut() + 'static>(&self, callback: F) {
+        *self.inner.begin.borrow_mut() = Some(Box::new(callback));
+    }
+
+    pub fn connect_scale_changed<F: FnMut(f64) + 'static>(&self, callback: F) {
+        *self.inner.scale_changed.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback called when one of the two points is released.
+    pub fn connect_end<F: FnMut() + 'static>(&self, callback: F) {
+        *self.inner.end.borrow_mut() = Some(Box::new(callback));
+    }
+
+    pub fn attach<W: GestureWidget>(&self, widget: &W) {
+        connect_recognizer(widget, self.clone());
+    }
+}
+
+impl Recognizer for GestureZoom {
+    // the distance between the points when the gesture began
+    type State = RefCell<Option<f64>>;
+
+    fn new_state(&self) -> RefCell<Option<f64>> {
+        RefCell::new(None)
+    }
+
+    fn handle(&self, state: &RefCell<Option<f64>>, phase: Phase, point: &Point, points: &[Point]) {
+        let initial_distance = *state.borrow();
+
+        match (phase, initial_distance, get_pair(points)) {
+            (Phase::Begin, None, Some((first, second))) => {
+                let distance = get_pair_distance(first, second);
+
+                if distance > 0. {
+                    *state.borrow_mut() = Some(distance);
+                    emit(&self.inner.begin, &mut |callback| callback());
+                }
+            }
+            (Phase::Update, Some(initial), Some((first, second))) => {
+                if is_in_pair(point, points) {
+                    let scale = get_pair_distance(first, second) / initial;
+
+                    emit(&self.inner.scale_changed, &mut |callback| callback(scale));
+                }
+            }
+            (Phase::End, Some(_), _) | (Phase::Cancel, Some(_), _) => {
+                if is_in_pair(point, points) {
+                    *state.borrow_mut() = None;
+                    emit(&self.inner.end, &mut |callback| callback());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+struct RotateInner {
+    begin: RefCell<Option<Box<FnMut()>>>,
+    angle_changed: RefCell<Option<Box<FnMut(f64, f64)>>>,
+    end: RefCell<Option<Box<FnMut()>>>
+}
+
+/// Recognizes the rotation of two points, reporting the angle of the line between them and its
+/// difference with the angle when the gesture began, in radians.
+#[derive(Clone)]
+pub struct GestureRotate {
+    inner: Rc<RotateInner>
+}
+
+impl GestureRotate {
+    pub fn new() -> GestureRotate {
+        GestureRotate {
+            inner: Rc::new(RotateInner {
+                begin: RefCell::new(None),
+                angle_changed: RefCell::new(None),
+                end: RefCell::new(None)
+            })
+        }
+    }
+
+    /// Sets the callback called when the second point touches.
+    pub fn connect_begin<F: FnMut() + 'static>(&self, callback: F) {
+        *self.inner.begin.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback called with the current angle and the delta since the gesture began,
+    /// between -π and π.
+    pub fn connect_angle_changed<F: FnMut(f64, f64) + 'static>(&self, callback: F) {
+        *self.inner.angle_changed.borrow_mut() = Some(Box::new(callback));
+    }
+
+    /// Sets the callback called when one of the two points is released.
+    pub fn connect_end<F: FnMut() + 'static>(&self, callback: F) {
+        *self.inner.end.borrow_mut() = Some(Box::new(callback));
+    }
+
+    pub fn attach<W: GestureWidget>(&self, widget: &W) {
+        connect_recognizer(widget, self.clone());
+    }
+}
+
+impl Recognizer for GestureRotate {
+    // the angle between the points when the gesture began
+    type State = RefCell<Option<f64>>;
+
+    fn new_state(&self) -> RefCell<Option<f64>> {
+        RefCell::new(None)
+    }
+
+    fn handle(&self, state: &RefCell<Option<f64>>, phase: Phase, point: &Point, points: &[Point]) {
+        let initial_angle = *state.borrow();
+
+        match (phase, initial_angle, get_pair(points)) {
+            (Phase::Begin, None, Some((first, second))) => {
+                *state.borrow_mut() = Some(get_pair_angle(first, second));
+                emit(&self.inner.begin, &mut |callback| callback());
+            }
+            (Phase::Update, Some(initial), Some((first, second))) => {
+                if is_in_pair(point, points) {
+                    let angle = get_pair_angle(first, second);
+                    let mut delta = angle - initial;
+
+                    if delta > PI {
+                        delta -= 2. * PI;
+                    } else if delta < -PI {
+                        delta += 2. * PI;
+                    }
+                    emit(&self.inner.angle_changed, &mut |callback| callback(angle, delta));
+                }
+            }
+            (Phase::End, Some(_), _) | (Phase::Cancel, Some(_), _) => {
+                if is_in_pair(point, points) {
+                    *state.borrow_mut() = None;
+                    emit(&self.inner.end, &mut |callback| callback());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use std::f64::consts::PI;
+    use gdk;
+    use super::{Tracker, Phase, PointId, GestureTap, GestureDrag, GestureSwipe, GestureZoom, GestureRotate};
+
+    fn touch(n: usize) -> PointId {
+        PointId::Touch(gdk::EventSequence::wrap_pointer(n as *mut _))
+    }
+
+    #[test]
+    fn tap_counts_multiple_taps() {
+        let gesture = GestureTap::new();
+        let taps = Rc::new(RefCell::new(Vec::new()));
+        let recorded = taps.clone();
+
+        gesture.connect_tapped(move |n_press, x, y| recorded.borrow_mut().push((n_press, x, y)));
+
+        let tracker = Tracker::new(gesture.clone());
+
+        tracker.process(Phase::Begin, PointId::Pointer, 10., 10., 0);
+        tracker.process(Phase::End, PointId::Pointer, 11., 10., 50);
+        tracker.process(Phase::Begin, PointId::Pointer, 10., 11., 200);
+        tracker.process(Phase::End, PointId::Pointer, 10., 11., 250);
+        tracker.process(Phase::Begin, PointId::Pointer, 10., 10., 1000);
+        tracker.process(Phase::End, PointId::Pointer, 10., 10., 1050);
+        assert_eq!(*taps.borrow(), vec![(1, 11., 10.), (2, 10., 11.), (1, 10., 10.)]);
+    }
+
+    #[test]
+    fn tap_cancelled_by_movement_and_second_point() {
+        let gesture = GestureTap::new();
+        let taps = Rc::new(RefCell::new(0));
+        let recorded = taps.clone();
+
+        gesture.connect_tapped(move |_, _, _| *recorded.borrow_mut() += 1);
+
+        let tracker = Tracker::new(gesture.clone());
+
+        tracker.process(Phase::Begin, PointId::Pointer, 0., 0., 0);
+        tracker.process(Phase::Update, PointId::Pointer, 20., 0., 10);
+        tracker.process(Phase::End, PointId::Pointer, 0., 0., 20);
+
+        tracker.process(Phase::Begin, touch(1), 0., 0., 100);
+        tracker.process(Phase::Begin, touch(2), 50., 0., 110);
+        tracker.process(Phase::End, touch(1), 0., 0., 120);
+        tracker.process(Phase::End, touch(2), 50., 0., 130);
+        assert_eq!(*taps.borrow(), 0);
+    }
+
+    #[test]
+    fn tap_state_is_per_widget() {
+        let gesture = GestureTap::new();
+        let taps = Rc::new(RefCell::new(Vec::new()));
+        let recorded = taps.clone();
+
+        gesture.connect_tapped(move |n_press, _, _| recorded.borrow_mut().push(n_press));
+
+        let first = Tracker::new(gesture.clone());
+        let second = Tracker::new(gesture.clone());
+
+        first.process(Phase::Begin, PointId::Pointer, 0., 0., 0);
+        second.process(Phase::Begin, PointId::Pointer, 0., 0., 10);
+        first.process(Phase::End, PointId::Pointer, 0., 0., 20);
+        second.process(Phase::End, PointId::Pointer, 0., 0., 30);
+        assert_eq!(*taps.borrow(), vec![1, 1]);
+    }
+
+    #[test]
+    fn drag_reports_offsets_of_the_first_point() {
+        let gesture = GestureDrag::new();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let begin = events.clone();
+        let update = events.clone();
+        let end = events.clone();
+
+        gesture.connect_drag_begin(move |x, y| begin.borrow_mut().push(("begin", x, y)));
+        gesture.connect_drag_update(move |x, y| update.borrow_mut().push(("update", x, y)));
+        gesture.connect_drag_end(move |x, y| end.borrow_mut().push(("end", x, y)));
+
+        let tracker = Tracker::new(gesture.clone());
+
+        tracker.process(Phase::Begin, touch(1), 5., 5., 0);
+        tracker.process(Phase::Begin, touch(2), 50., 50., 10);
+        tracker.process(Phase::Update, touch(2), 60., 60., 20);
+        tracker.process(Phase::Update, touch(1), 15., 25., 30);
+        tracker.process(Phase::End, touch(1), 20., 30., 40);
+        tracker.process(Phase::End, touch(2), 60., 60., 50);
+        assert_eq!(*events.borrow(), vec![("begin", 5., 5.), ("update", 10., 20.), ("end", 15., 25.)]);
+    }
+
+    #[test]
+    fn swipe_reports_velocity() {
+        let gesture = GestureSwipe::new();
+        let velocity = Rc::new(RefCell::new(None));
+        let recorded = velocity.clone();
+
+        gesture.connect_swipe(move |x, y| *recorded.borrow_mut() = Some((x, y)));
+
+        let tracker = Tracker::new(gesture.clone());
+
+        tracker.process(Phase::Begin, PointId::Pointer, 0., 0., 1000);
+        tracker.process(Phase::Update, PointId::Pointer, 500., 0., 1200);
+        tracker.process(Phase::End, PointId::Pointer, 750., 125., 1325);
+        // the positions older than the capture period are ignored
+        assert_eq!(*velocity.borrow(), Some((2000., 1000.)));
+    }
+
+    #[test]
+    fn zoom_reports_scale() {
+        let gesture = GestureZoom::new();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let begin = events.clone();
+        let scale_changed = events.clone();
+        let end = events.clone();
+
+        gesture.connect_begin(move || begin.borrow_mut().push(0.));
+        gesture.connect_scale_changed(move |scale| scale_changed.borrow_mut().push(scale));
+        gesture.connect_end(move || end.borrow_mut().push(-1.));
+
+        let tracker = Tracker::new(gesture.clone());
+
+        tracker.process(Phase::Begin, touch(1), 0., 0., 0);
+        tracker.process(Phase::Begin, touch(2), 10., 0., 10);
+        tracker.process(Phase::Update, touch(2), 20., 0., 20);
+        tracker.process(Phase::Update, touch(1), 15., 0., 30);
+        tracker.process(Phase::End, touch(1), 15., 0., 40);
+        tracker.process(Phase::Update, touch(2), 40., 0., 50);
+        assert_eq!(*events.borrow(), vec![0., 2., 0.5, -1.]);
+    }
+
+    #[test]
+    fn rotate_reports_angle_and_delta() {
+        let gesture = GestureRotate::new();
+        let angles = Rc::new(RefCell::new(Vec::new()));
+        let recorded = angles.clone();
+
+        gesture.connect_angle_changed(move |angle, delta| recorded.borrow_mut().push((angle, delta)));
+
+        let tracker = Tracker::new(gesture.clone());
+
+        tracker.process(Phase::Begin, touch(1), 0., 0., 0);
+        tracker.process(Phase::Begin, touch(2), -10., 1e-9, 10);
+        tracker.process(Phase::Update, touch(2), -10., -1e-9, 20);
+        tracker.process(Phase::Update, touch(2), 0., 10., 30);
+
+        let angles = angles.borrow();
+
+        assert_eq!(angles.len(), 2);
+        // crossing -π..π doesn't make the delta jump
+        assert!(angles[0].1.abs() < 1e-6);
+        assert!((angles[1].0 - PI / 2.).abs() < 1e-9);
+        assert!((angles[1].1 + PI / 2.).abs() < 1e-6);
+    }
+}
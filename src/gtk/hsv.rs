@@ -13,15 +13,26 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Keyboard Handling Functions
+//! HSV — Conversions between the RGB and HSV color models
 
-use glib::translate::FromGlibPtr;
-use gdk::ffi;
-use libc::c_uint;
+use gtk::ffi;
 
-pub fn keyval_name(keyval: u32) -> Option<String> {
-    unsafe {
-        FromGlibPtr::borrow(
-            ffi::gdk_keyval_name(keyval as c_uint))
-    }
+/// Converts a color from HSV to RGB, all the components go from 0. to 1.
+pub fn hsv_to_rgb(h: f64, s: f64, v: f64) -> (f64, f64, f64) {
+    let mut r = 0.;
+    let mut g = 0.;
+    let mut b = 0.;
+
+    unsafe { ffi::gtk_hsv_to_rgb(h, s, v, &mut r, &mut g, &mut b) };
+    (r, g, b)
+}
+
+/// Converts a color from RGB to HSV, all the components go from 0. to 1.
+pub fn rgb_to_hsv(r: f64, g: f64, b: f64) -> (f64, f64, f64) {
+    let mut h = 0.;
+    let mut s = 0.;
+    let mut v = 0.;
+
+    unsafe { ffi::gtk_rgb_to_hsv(r, g, b, &mut h, &mut s, &mut v) };
+    (h, s, v)
 }
@@ -184,14 +184,16 @@ macro_rules! impl_widget_events(
                                      QueryTooltip, Realize, ScreenChanged, ScrollEvent,
                                      Show, SizeAllocate,
                                      StateFlagsChanged, StyleUpdated,
-                                     TouchEvent, WindowStateEvent);
+                                     TouchEvent, WindowStateEvent,
+                                     DragBegin, DragDataDelete, DragDataGet, DragDataReceived, DragDrop,
+                                     DragEnd, DragFailed, DragLeave, DragMotion,
+                                     SelectionGet, SelectionReceived, SelectionClearEvent,
+                                     SelectionRequestEvent, SelectionNotifyEvent);
 
 
         // not implemented:
-        // DragBegin, DragDataDelete, DragDataGet, DragDataReceived, DragDrop
-        // DragEnd, DragFailed, DragLeave, DragMotion, HierarchyChanged, ParentSet, PopuMenu,
-        // UnRealize, VisibilityNotifyEvent, UnMap, UnMapEvent, StyleSet, StateChanged, SelectionRequestEvent,
-        // SelectionReceived, SelectionClearEvent, SelectionGet, SelectionNotifyEvent,
+        // HierarchyChanged, ParentSet, PopuMenu,
+        // UnRealize, VisibilityNotifyEvent, UnMap, UnMapEvent, StyleSet, StateChanged,
     )
 );
 
@@ -67,7 +67,51 @@ pub use self::rt::{
     get_binary_age,
     get_interface_age,
     check_version,
-    events_pending
+    events_pending,
+    get_current_event_time,
+    get_current_event_device,
+    grab_get_current
+};
+
+pub use self::accelerator::{
+    accelerator_valid,
+    accelerator_parse,
+    accelerator_name,
+    accelerator_get_label,
+    accelerator_set_default_mod_mask,
+    accelerator_get_default_mod_mask
+};
+
+pub use self::hsv::{
+    hsv_to_rgb,
+    rgb_to_hsv
+};
+
+pub use self::drag_and_drop::{
+    drag_finish,
+    drag_set_icon_default,
+    drag_set_icon_pixbuf,
+    drag_set_icon_name
+};
+
+pub use self::gestures::{
+    GestureWidget,
+    PointId,
+    Point,
+    GestureTap,
+    GestureLongPress,
+    GestureDrag,
+    GestureSwipe,
+    GestureZoom,
+    GestureRotate
+};
+
+#[cfg(feature = "GTK_3_8")]
+pub use self::animation::{
+    TickCallbackId,
+    Easing,
+    Transition,
+    Animation
 };
 
 
@@ -159,6 +203,11 @@ pub use self::widgets::{
     EntryCompletion,
     IconView,
     TreeSelection,
+    Clipboard,
+    SelectionData,
+    OwnedSelectionData,
+    TargetEntry,
+    TargetList,
     RecentChooserWidget,
     ComboBox,
     //g_type,
@@ -175,11 +224,14 @@ pub use self::widgets::{
     FileChooserWidget,
     ColorChooserWidget,
     FontChooserWidget,
-    EventBox
+    EventBox,
+    OffscreenWindow
 };
 
 #[cfg(target_os = "linux")]
 pub use self::widgets::{Socket};
+#[cfg(all(target_os = "linux", feature = "X11"))]
+pub use self::widgets::{Plug};
 
 #[cfg(feature = "GTK_3_6")]
 /// GTK Widgets for versions since GTK 3.6
@@ -267,7 +319,7 @@ pub use gtk_ffi::enums::StackTransitionType;
 pub use gtk_ffi::enums::RevealerTransitionType;
 pub use gtk_ffi::enums::ScrollablePolicy;
 pub use gtk_ffi::enums::FileFilterFlags;
-pub use gtk_ffi::enums::AppInfoCreateFlags;
+pub use gtk_ffi::enums::app_info_create_flags::AppInfoCreateFlags;
 pub use gtk_ffi::enums::SizeRequestMode;
 pub use gtk_ffi::enums::Align;
 pub use gtk_ffi::enums::GConnectFlags;
@@ -292,8 +344,9 @@ pub use gtk_ffi::enums::GType;
 pub use gtk_ffi::enums::TextSearchFlags;
 pub use gtk_ffi::enums::PlacesOpenFlags;
 pub use gtk_ffi::enums::ToolPaletteDragTargets;
-pub use gtk_ffi::enums::DestDefaults;
+pub use gtk_ffi::enums::dest_defaults::DestDefaults;
 pub use gtk_ffi::enums::SizeGroupMode;
+pub use gtk_ffi::enums::target_flags::TargetFlags;
 pub use self::traits::GObjectTrait;
 
 /// Gtk Traits
@@ -343,6 +396,12 @@ pub use self::types::{
 mod macros;
 mod cast;
 mod rt;
+mod accelerator;
+mod drag_and_drop;
+mod hsv;
+pub mod gestures;
+#[cfg(feature = "GTK_3_8")]
+mod animation;
 
 mod traits;
 pub mod signals;
@@ -15,7 +15,8 @@
 
 use libc::c_uint;
 use std::ptr;
-use gtk::ffi;
+use gtk::{self, ffi};
+use gdk;
 use glib::translate::{FromGlibPtr};
 use glib::{to_bool, to_gboolean};
 
@@ -57,6 +58,34 @@ pub fn events_pending() -> bool {
     }
 }
 
+/// Returns the time stamp of the event being processed, 0 (`GDK_CURRENT_TIME`) if there is none.
+/// It's the time to give to grabs started from an event handler.
+pub fn get_current_event_time() -> u32 {
+    unsafe { ffi::gtk_get_current_event_time() }
+}
+
+/// Returns the device of the event being processed, if any.
+pub fn get_current_event_device() -> Option<gdk::Device> {
+    let tmp = unsafe { ffi::gtk_get_current_event_device() };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(gdk::Device::wrap_pointer(tmp))
+    }
+}
+
+/// Returns the widget holding the current GTK grab, if any.
+pub fn grab_get_current<T: gtk::FFIWidget>() -> Option<T> {
+    let tmp = unsafe { ffi::gtk_grab_get_current() };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(gtk::FFIWidget::wrap_widget(tmp))
+    }
+}
+
 
 pub fn get_major_version() -> u32 {
     unsafe {
@@ -249,69 +249,87 @@ signal!(parent_set,    ParentSet(old_parent : *gtk::Widget) -> ())
 */
 
 //GtkWidget: GDK events
-signal!(button_press_event,     ButtonPressEvent(event : *mut gdk::EventButton) -> bool);
-signal!(button_release_event,   ButtonReleaseEvent(event : *mut gdk::EventButton) -> bool);
-signal!(configure_event,        ConfigureEvent(event : *mut gdk::EventConfigure) -> bool);
-signal!(damage_event,           DamageEvent(event : *mut gdk::EventExpose) -> bool);
-signal!(delete_event,           DeleteEvent(event : *mut gdk::EventAny) -> bool);
-signal!(destroy_event,          DestroyEvent(event : *mut gdk::EventAny) -> bool);
-signal!(enter_notify_event,     EnterNotifyEvent(event : *mut gdk::EventCrossing) -> bool);
-signal!(leave_notify_event,     LeaveNotifyEvent(event : *mut gdk::EventCrossing) -> bool);
-signal!(event,                  Event(event : *mut gdk::EventAny) -> bool);
-signal!(event_after,            EventAfter(event : *mut gdk::EventAny) -> bool);
-signal!(focus_in_event,         FocusInEvent(event : *mut gdk::EventFocus) -> bool);
-signal!(focus_out_event,        FocusOutEvent(event : *mut gdk::EventFocus) -> bool);
-signal!(grab_broken_event,      GrabBrokenEvent(event : *mut gdk::EventGrabBroken) -> bool);
-signal!(key_press_event,        KeyPressEvent(event : *mut gdk::EventKey) -> bool);
-signal!(key_release_event,      KeyReleaseEvent(event : *mut gdk::EventKey) -> bool);
-signal!(map_event,              MapEvent(event : *mut gdk::EventAny) -> bool);
-signal!(motion_notify_event,    MotionNotifyEvent(event : *mut gdk::EventMotion) -> bool);
-signal!(property_notify_event,  PropertyNotifyEvent(event : *mut gdk::EventProperty) -> bool);
-signal!(proximity_in_event,     ProximityInEvent(event : *mut gdk::EventProximity) -> bool);
-signal!(proximity_out_event,    ProximityOutEvent(event : *mut gdk::EventProximity) -> bool);
-signal!(scroll_event,           ScrollEvent(event : *mut gdk::EventScroll) -> bool);
-signal!(touch_event,            TouchEvent(event : *mut gdk::EventTouch) -> bool);
-signal!(unmap_event,            UnmapEvent(event : *mut gdk::EventAny) -> bool);
-signal!(window_state_event,     WindowStateEvent(event : *mut gdk::EventWindowState) -> bool);
+signal!(button_press_event,     ButtonPressEvent(event : &gdk::EventButton) -> bool);
+signal!(button_release_event,   ButtonReleaseEvent(event : &gdk::EventButton) -> bool);
+signal!(configure_event,        ConfigureEvent(event : &gdk::EventConfigure) -> bool);
+signal!(damage_event,           DamageEvent(event : &gdk::EventExpose) -> bool);
+signal!(delete_event,           DeleteEvent(event : &gdk::EventAny) -> bool);
+signal!(destroy_event,          DestroyEvent(event : &gdk::EventAny) -> bool);
+signal!(enter_notify_event,     EnterNotifyEvent(event : &gdk::EventCrossing) -> bool);
+signal!(leave_notify_event,     LeaveNotifyEvent(event : &gdk::EventCrossing) -> bool);
+signal!(event,                  Event(event : &gdk::Event) -> bool);
+signal!(event_after,            EventAfter(event : &gdk::Event) -> bool);
+signal!(focus_in_event,         FocusInEvent(event : &gdk::EventFocus) -> bool);
+signal!(focus_out_event,        FocusOutEvent(event : &gdk::EventFocus) -> bool);
+signal!(grab_broken_event,      GrabBrokenEvent(event : &gdk::EventGrabBroken) -> bool);
+signal!(key_press_event,        KeyPressEvent(event : &gdk::EventKey) -> bool);
+signal!(key_release_event,      KeyReleaseEvent(event : &gdk::EventKey) -> bool);
+signal!(map_event,              MapEvent(event : &gdk::EventAny) -> bool);
+signal!(motion_notify_event,    MotionNotifyEvent(event : &gdk::EventMotion) -> bool);
+signal!(property_notify_event,  PropertyNotifyEvent(event : &gdk::EventProperty) -> bool);
+signal!(proximity_in_event,     ProximityInEvent(event : &gdk::EventProximity) -> bool);
+signal!(proximity_out_event,    ProximityOutEvent(event : &gdk::EventProximity) -> bool);
+signal!(scroll_event,           ScrollEvent(event : &gdk::EventScroll) -> bool);
+signal!(touch_event,            TouchEvent(event : &gdk::EventTouch) -> bool);
+signal!(unmap_event,            UnmapEvent(event : &gdk::EventAny) -> bool);
+signal!(window_state_event,     WindowStateEvent(event : &gdk::EventWindowState) -> bool);
 
 //GtkWidget: Drag-drop
-/*
-signal!(drag_begin,         DragBegin(context : *mut gdk::DragContext) -> ())
-signal!(drag_data_delete,   DragDataDelete(context : *mut gdk::DragContext) -> ())
-signal!(drag_data_get,      DragDataGet(context : *mut gdk::DragContext,
-                                        data : *gtk::SelectionData,
-                                        info: usize,
-                                        time: usize) -> ())
-signal!(drag_data_received, DragDataReceived(context : *mut gdk::DragContext,
-                                             x: isize,
-                                             y: isize,
-                                             data: *gtk::SelectionData,
-                                             info: usize,
-                                             time: usize) -> ())
-signal!(drag_drop,          DragDrop(context : *mut gdk::DragContext,
-                                     x: isize,
-                                     y: isize,
-                                     time: usize) -> ())
-signal!(drag_end,           DragEnd(context : *mut gdk::DragContext) -> ())
-signal!(drag_failed,        DragFailed(context : *mut gdk::DragContext, result: gtk::DragResult) -> ())
-signal!(drag_leave,         DragLeave(context : *mut gdk::DragContext, time : usize) -> ())
-signal!(drag_motion,        DragMotion(context : *mut gdk::DragContext,
-                                       x : isize,
-                                       y : isize,
-                                       time : usize) -> bool)
+signal!(drag_begin,         DragBegin(context : gdk::DragContext) -> ());
+signal!(drag_data_delete,   DragDataDelete(context : gdk::DragContext) -> ());
+signal!(drag_data_get,      DragDataGet(context : gdk::DragContext,
+                                        data : &gtk::SelectionData,
+                                        info : u32,
+                                        time : u32) -> ());
+signal!(drag_data_received, DragDataReceived(context : gdk::DragContext,
+                                             x : i32,
+                                             y : i32,
+                                             data : &gtk::SelectionData,
+                                             info : u32,
+                                             time : u32) -> ());
+signal!(drag_drop,          DragDrop(context : gdk::DragContext,
+                                     x : i32,
+                                     y : i32,
+                                     time : u32) -> bool);
+signal!(drag_end,           DragEnd(context : gdk::DragContext) -> ());
+signal!(drag_failed,        DragFailed(context : gdk::DragContext, result : gtk::DragResult) -> bool);
+signal!(drag_leave,         DragLeave(context : gdk::DragContext, time : u32) -> ());
+signal!(drag_motion,        DragMotion(context : gdk::DragContext,
+                                       x : i32,
+                                       y : i32,
+                                       time : u32) -> bool);
 
 //GtkWidget: Selection
-signal!(selection_get,          SelectionGet(data : *gtk::SelectionData, info: usize, time: usize) -> ())
-signal!(selection_received,     SelectionReceived(data : *gtk::SelectionData, time: usize) -> ())
+signal!(selection_get,          SelectionGet(data : &gtk::SelectionData, info : u32, time : u32) -> ());
+signal!(selection_received,     SelectionReceived(data : &gtk::SelectionData, time : u32) -> ());
 
-signal!(selection_clear_event,  SelectionClearEvent(event : *mut gdk::EventSelection) -> bool)
-signal!(selection_request_event,SelectionRequestEvent(event : *mut gdk::EventSelection) -> bool)
-signal!(selection_notify_event, SelectionNotifyEvent(event : *mut gdk::EventSelection) -> bool)
-*/
+signal!(selection_clear_event,  SelectionClearEvent(event : &gdk::EventSelection) -> bool);
+signal!(selection_request_event,SelectionRequestEvent(event : &gdk::EventSelection) -> bool);
+signal!(selection_notify_event, SelectionNotifyEvent(event : &gdk::EventSelection) -> bool);
 
 //GtkTreeSelection
 signal!(changed,            Changed() -> ());
 
+//GdkScreen
+signal!(monitors_changed,   MonitorsChanged() -> ());
+signal!(size_changed,       SizeChanged() -> ());
+
+//GdkDisplay
+signal!(opened,             Opened() -> ());
+signal!(closed,             Closed(is_error : bool) -> ());
+
+//GdkFrameClock
+signal!(flush_events,       FlushEvents() -> ());
+signal!(before_paint,       BeforePaint() -> ());
+signal!(update,             Update() -> ());
+signal!(layout,             Layout() -> ());
+signal!(paint,              Paint() -> ());
+signal!(after_paint,        AfterPaint() -> ());
+signal!(resume_events,      ResumeEvents() -> ());
+
+//GtkClipboard
+signal!(owner_change,       OwnerChange(event : &gdk::EventOwnerChange) -> ());
+
 //GtkTreeView
 signal!(columns_changed,            ColumnsChanged() -> ());
 signal!(cursor_changed,             CursorChanged() -> ());
@@ -355,6 +373,13 @@ signal!(wrapped, Wrapped() -> ());
 //gint    input   Run Last
 //gboolean    output  Run Last
 
+// GtkSocket
+signal!(plug_added, PlugAdded() -> ());
+signal!(plug_removed, PlugRemoved() -> bool);
+
+// GtkPlug
+signal!(embedded, Embedded() -> ());
+
 // Range
 signal!(adjust_bounds, AdjustBounds() -> ());
 signal!(move_slider, MoveSlider() -> ());
@@ -24,7 +24,7 @@ pub trait AppChooserTrait: gtk::WidgetTrait {
         if tmp_pointer.is_null() {
             None
         } else {
-            Some(gtk::FFIWidget::wrap_widget(tmp_pointer as *mut ffi::C_GtkWidget))
+            Some(gtk::AppInfo::wrap_pointer(tmp_pointer))
         }
     }
 
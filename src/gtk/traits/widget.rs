@@ -13,7 +13,7 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
 
-use libc::{c_char, c_int};
+use libc::{c_char, c_int, c_uint};
 use glib::translate::{FromGlibPtr, ToGlibPtr};
 use gtk::ffi;
 use glib::{to_bool, to_gboolean};
@@ -21,6 +21,8 @@ use gdk;
 use gdk_ffi;
 use gtk;
 use glib;
+use pango;
+use cairo;
 use glib::ffi::GType;
 
 pub trait WidgetTrait: gtk::FFIWidget + gtk::GObjectTrait {
@@ -58,6 +60,13 @@ pub trait WidgetTrait: gtk::FFIWidget + gtk::GObjectTrait {
         unsafe { ffi::gtk_widget_queue_draw(self.unwrap_widget()) }
     }
 
+    /// Draws the widget and its children to `cr`, with the top left corner of the widget at the
+    /// origin of `cr`. The widget must be realized and allocated, for example in a shown
+    /// `OffscreenWindow`.
+    fn draw(&self, cr: &cairo::Context) {
+        unsafe { ffi::gtk_widget_draw(self.unwrap_widget(), cr.get_ptr()) }
+    }
+
     fn queue_resize(&self) {
         unsafe { ffi::gtk_widget_queue_resize(self.unwrap_widget()) }
     }
@@ -70,6 +79,39 @@ pub trait WidgetTrait: gtk::FFIWidget + gtk::GObjectTrait {
         unsafe { ffi::gtk_widget_get_scale_factor(self.unwrap_widget()) }
     }
 
+    /// Returns the frame clock of the widget, None if the widget isn't realized.
+    #[cfg(feature = "GTK_3_8")]
+    fn get_frame_clock(&self) -> Option<gdk::FrameClock> {
+        let tmp = unsafe { ffi::gtk_widget_get_frame_clock(self.unwrap_widget()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::FrameClock::wrap_pointer(tmp))
+        }
+    }
+
+    /// Queues a callback called before each frame drawn while the widget is realized. The
+    /// callback returns false to be removed. It is dropped when removed or when the widget is
+    /// destroyed.
+    #[cfg(feature = "GTK_3_8")]
+    fn add_tick_callback<F>(&self, callback: F) -> gtk::TickCallbackId where F: FnMut(&gdk::FrameClock) -> bool + 'static {
+        let callback : Box<Box<FnMut(&gdk::FrameClock) -> bool>> = Box::new(Box::new(callback));
+
+        let id = unsafe {
+            ffi::gtk_widget_add_tick_callback(self.unwrap_widget(), tick_callback_trampoline, ::std::mem::transmute(callback),
+                tick_callback_destroy)
+        };
+
+        gtk::TickCallbackId::new(id)
+    }
+
+    /// Removes a tick callback, it must not have been removed already.
+    #[cfg(feature = "GTK_3_8")]
+    fn remove_tick_callback(&self, id: gtk::TickCallbackId) {
+        unsafe { ffi::gtk_widget_remove_tick_callback(self.unwrap_widget(), id.get_id() as c_uint) }
+    }
+
     fn activate(&self) -> bool {
         unsafe { to_bool(ffi::gtk_widget_activate(self.unwrap_widget())) }
     }
@@ -194,6 +236,98 @@ pub trait WidgetTrait: gtk::FFIWidget + gtk::GObjectTrait {
         unsafe { ffi::gtk_widget_override_cursor(self.unwrap_widget(), cursor, secondary_cursor) }
     }
 
+    /// Sets the shape of the window of the widget, the widget must have its own window (a
+    /// toplevel for example). None removes the shape.
+    fn shape_combine_region(&self, region: Option<&cairo::Region>) {
+        unsafe {
+            ffi::gtk_widget_shape_combine_region(self.unwrap_widget(),
+                match region { Some(r) => r.get_ptr(), None => ::std::ptr::null_mut() })
+        }
+    }
+
+    /// Sets the input shape of the window of the widget: input events outside of `region` go to
+    /// the windows below. None removes the input shape.
+    fn input_shape_combine_region(&self, region: Option<&cairo::Region>) {
+        unsafe {
+            ffi::gtk_widget_input_shape_combine_region(self.unwrap_widget(),
+                match region { Some(r) => r.get_ptr(), None => ::std::ptr::null_mut() })
+        }
+    }
+
+    /// Sets the foreground color of the widget for `state`, None undoes the change. Deprecated
+    /// in GTK, use `override_color`.
+    fn modify_fg(&self, state: gtk::StateType, color: Option<&gdk::Color>) {
+        unsafe {
+            ffi::gtk_widget_modify_fg(self.unwrap_widget(), state,
+                match color { Some(c) => c as *const gdk::Color, None => ::std::ptr::null() })
+        }
+    }
+
+    /// Sets the background color of the widget for `state`, None undoes the change. Deprecated
+    /// in GTK, use `override_background_color`.
+    fn modify_bg(&self, state: gtk::StateType, color: Option<&gdk::Color>) {
+        unsafe {
+            ffi::gtk_widget_modify_bg(self.unwrap_widget(), state,
+                match color { Some(c) => c as *const gdk::Color, None => ::std::ptr::null() })
+        }
+    }
+
+    /// Sets the text color of editable widgets such as `Entry` for `state`. Deprecated in GTK, use
+    /// `override_color`.
+    fn modify_text(&self, state: gtk::StateType, color: Option<&gdk::Color>) {
+        unsafe {
+            ffi::gtk_widget_modify_text(self.unwrap_widget(), state,
+                match color { Some(c) => c as *const gdk::Color, None => ::std::ptr::null() })
+        }
+    }
+
+    /// Sets the base color of editable widgets such as `Entry` for `state`. Deprecated in GTK,
+    /// use `override_background_color`.
+    fn modify_base(&self, state: gtk::StateType, color: Option<&gdk::Color>) {
+        unsafe {
+            ffi::gtk_widget_modify_base(self.unwrap_widget(), state,
+                match color { Some(c) => c as *const gdk::Color, None => ::std::ptr::null() })
+        }
+    }
+
+    fn override_font(&self, font_desc: &pango::FontDescription) {
+        unsafe { pango::ffi::gtk_widget_override_font(self.unwrap_widget(), font_desc.unwrap_pointer()) }
+    }
+
+    fn create_pango_context(&self) -> Option<pango::Context> {
+        let tmp = unsafe { pango::ffi::gtk_widget_create_pango_context(self.unwrap_widget()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::Context::wrap_pointer(tmp))
+        }
+    }
+
+    /// Gets a PangoContext with the appropriate font map, font description, and base direction
+    /// for this widget. The context is owned by the widget and updated to match any changes to
+    /// the widget's attributes, like its screen or style.
+    fn get_pango_context(&self) -> Option<pango::Context> {
+        let tmp = unsafe { pango::ffi::gtk_widget_get_pango_context(self.unwrap_widget()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { glib::ffi::g_object_ref(tmp as *mut glib::ffi::C_GObject) };
+            Some(pango::Context::wrap_pointer(tmp))
+        }
+    }
+
+    fn create_pango_layout(&self, text: &str) -> Option<pango::Layout> {
+        let tmp = unsafe { pango::ffi::gtk_widget_create_pango_layout(self.unwrap_widget(), text.borrow_to_glib().0) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::Layout::wrap_pointer(tmp))
+        }
+    }
+
     fn queue_draw_area(&self, x: i32, y: i32, width: i32, height: i32) {
         unsafe { ffi::gtk_widget_queue_draw_area(self.unwrap_widget(), x, y, width, height) }
     }
@@ -214,13 +348,27 @@ pub trait WidgetTrait: gtk::FFIWidget + gtk::GObjectTrait {
         unsafe { to_bool(ffi::gtk_widget_mnemonic_activate(self.unwrap_widget(), to_gboolean(group_cycling))) }
     }
 
-    /*fn send_expose(&self, event: &mut gdk::Event) -> i32 {
-        unsafe { ffi::gtk_widget_send_expose(self.unwrap_widget(), event) }
+    /// Rarely-used function. Sends the event to the widget as a key/button event, the return
+    /// value tells whether the event was handled.
+    fn event(&self, event: &gdk::Event) -> bool {
+        unsafe { to_bool(ffi::gtk_widget_event(self.unwrap_widget(), event.unwrap_pointer())) }
     }
 
-    fn send_focus_change(&self, event: &mut gdk::Event) -> bool {
-        unsafe { to_bool(ffi::gtk_widget_send_expose(self.unwrap_widget(), event)) }
-    }*/
+    fn send_expose(&self, event: &gdk::Event) -> i32 {
+        unsafe { ffi::gtk_widget_send_expose(self.unwrap_widget(), event.unwrap_pointer()) }
+    }
+
+    fn send_focus_change(&self, event: &gdk::Event) -> bool {
+        unsafe { to_bool(ffi::gtk_widget_send_focus_change(self.unwrap_widget(), event.unwrap_pointer())) }
+    }
+
+    /// Returns the clipboard of the widget's display for the selection. The widget must be
+    /// attached to a toplevel window.
+    fn get_clipboard(&self, selection: &gdk::Atom) -> Option<gtk::Clipboard> {
+        gtk::Clipboard::borrow_pointer(unsafe {
+            ffi::gtk_widget_get_clipboard(self.unwrap_widget(), selection.unwrap_pointer())
+        })
+    }
 
     fn child_focus(&self, direction: gtk::DirectionType) -> bool {
         unsafe { to_bool(ffi::gtk_widget_child_focus(self.unwrap_widget(), direction)) }
@@ -431,6 +579,20 @@ pub trait WidgetTrait: gtk::FFIWidget + gtk::GObjectTrait {
         unsafe { to_bool(ffi::gtk_widget_has_grab(self.unwrap_widget())) }
     }
 
+    /// Sets the events the widget receives, the widget must not be realized yet.
+    fn set_events(&self, events: gdk::EventMask) {
+        unsafe { ffi::gtk_widget_set_events(self.unwrap_widget(), events) }
+    }
+
+    fn get_events(&self) -> gdk::EventMask {
+        unsafe { ffi::gtk_widget_get_events(self.unwrap_widget()) }
+    }
+
+    /// Adds events to the event mask of the widget, works on realized widgets too.
+    fn add_events(&self, events: gdk::EventMask) {
+        unsafe { ffi::gtk_widget_add_events(self.unwrap_widget(), events) }
+    }
+
     fn is_drawable(&self) -> bool {
         unsafe { to_bool(ffi::gtk_widget_is_drawable(self.unwrap_widget())) }
     }
@@ -674,4 +836,232 @@ pub trait WidgetTrait: gtk::FFIWidget + gtk::GObjectTrait {
 
         unsafe { ffi::gtk_widget_destroyed(self.unwrap_widget(), &mut tmp) }
     }
+
+    /// Sets the widget up as a drag source: a drag starts when the user presses one of the
+    /// buttons of `start_button_mask` and moves the pointer.
+    fn drag_source_set(&self, start_button_mask: gdk::ModifierType, targets: &[gtk::TargetEntry], actions: gdk::DragAction) {
+        let (entries, _storage) = gtk::TargetEntry::to_glib_array(targets);
+
+        unsafe {
+            ffi::gtk_drag_source_set(self.unwrap_widget(), start_button_mask, entries.as_ptr(), entries.len() as c_int,
+                actions)
+        }
+    }
+
+    fn drag_source_unset(&self) {
+        unsafe { ffi::gtk_drag_source_unset(self.unwrap_widget()) }
+    }
+
+    fn drag_source_set_target_list(&self, target_list: Option<&gtk::TargetList>) {
+        unsafe {
+            ffi::gtk_drag_source_set_target_list(self.unwrap_widget(), match target_list {
+                Some(l) => l.unwrap_pointer(),
+                None => ::std::ptr::null_mut()
+            })
+        }
+    }
+
+    fn drag_source_get_target_list(&self) -> Option<gtk::TargetList> {
+        let tmp = unsafe { ffi::gtk_drag_source_get_target_list(self.unwrap_widget()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            gtk::TargetList::wrap_pointer(unsafe { ffi::gtk_target_list_ref(tmp) })
+        }
+    }
+
+    fn drag_source_add_text_targets(&self) {
+        unsafe { ffi::gtk_drag_source_add_text_targets(self.unwrap_widget()) }
+    }
+
+    fn drag_source_add_image_targets(&self) {
+        unsafe { ffi::gtk_drag_source_add_image_targets(self.unwrap_widget()) }
+    }
+
+    fn drag_source_add_uri_targets(&self) {
+        unsafe { ffi::gtk_drag_source_add_uri_targets(self.unwrap_widget()) }
+    }
+
+    fn drag_source_set_icon_pixbuf(&self, pixbuf: &gdk::Pixbuf) {
+        unsafe { ffi::gtk_drag_source_set_icon_pixbuf(self.unwrap_widget(), pixbuf.unwrap_pointer()) }
+    }
+
+    fn drag_source_set_icon_name(&self, icon_name: &str) {
+        unsafe { ffi::gtk_drag_source_set_icon_name(self.unwrap_widget(), icon_name.borrow_to_glib().0) }
+    }
+
+    /// Sets the widget up as a drop site, `flags` selects the default behaviours GTK+ takes
+    /// care of.
+    fn drag_dest_set(&self, flags: gtk::DestDefaults, targets: &[gtk::TargetEntry], actions: gdk::DragAction) {
+        let (entries, _storage) = gtk::TargetEntry::to_glib_array(targets);
+
+        unsafe { ffi::gtk_drag_dest_set(self.unwrap_widget(), flags, entries.as_ptr(), entries.len() as c_int, actions) }
+    }
+
+    fn drag_dest_unset(&self) {
+        unsafe { ffi::gtk_drag_dest_unset(self.unwrap_widget()) }
+    }
+
+    /// Looks for a match between the targets of the drag and `target_list` (the widget's list
+    /// if None). Returns None if no target matches.
+    fn drag_dest_find_target(&self, context: &gdk::DragContext, target_list: Option<&gtk::TargetList>) -> Option<gdk::Atom> {
+        let tmp = unsafe {
+            ffi::gtk_drag_dest_find_target(self.unwrap_widget(), context.unwrap_pointer(), match target_list {
+                Some(l) => l.unwrap_pointer(),
+                None => ::std::ptr::null_mut()
+            })
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Atom::wrap_pointer(tmp))
+        }
+    }
+
+    fn drag_dest_set_target_list(&self, target_list: Option<&gtk::TargetList>) {
+        unsafe {
+            ffi::gtk_drag_dest_set_target_list(self.unwrap_widget(), match target_list {
+                Some(l) => l.unwrap_pointer(),
+                None => ::std::ptr::null_mut()
+            })
+        }
+    }
+
+    fn drag_dest_get_target_list(&self) -> Option<gtk::TargetList> {
+        let tmp = unsafe { ffi::gtk_drag_dest_get_target_list(self.unwrap_widget()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            gtk::TargetList::wrap_pointer(unsafe { ffi::gtk_target_list_ref(tmp) })
+        }
+    }
+
+    fn drag_dest_add_text_targets(&self) {
+        unsafe { ffi::gtk_drag_dest_add_text_targets(self.unwrap_widget()) }
+    }
+
+    fn drag_dest_add_image_targets(&self) {
+        unsafe { ffi::gtk_drag_dest_add_image_targets(self.unwrap_widget()) }
+    }
+
+    fn drag_dest_add_uri_targets(&self) {
+        unsafe { ffi::gtk_drag_dest_add_uri_targets(self.unwrap_widget()) }
+    }
+
+    /// Requests the data of the drag for the target, it is delivered through the
+    /// "drag-data-received" signal.
+    fn drag_get_data(&self, context: &gdk::DragContext, target: &gdk::Atom, time_: u32) {
+        unsafe { ffi::gtk_drag_get_data(self.unwrap_widget(), context.unwrap_pointer(), target.unwrap_pointer(), time_) }
+    }
+
+    fn drag_highlight(&self) {
+        unsafe { ffi::gtk_drag_highlight(self.unwrap_widget()) }
+    }
+
+    fn drag_unhighlight(&self) {
+        unsafe { ffi::gtk_drag_unhighlight(self.unwrap_widget()) }
+    }
+
+    /// Returns whether the pointer moved far enough from the start position to start a drag.
+    fn drag_check_threshold(&self, start_x: i32, start_y: i32, current_x: i32, current_y: i32) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_drag_check_threshold(self.unwrap_widget(), start_x as c_int, start_y as c_int,
+                current_x as c_int, current_y as c_int))
+        }
+    }
+
+    /// Claims ownership of the selection for the widget. Returns false if the claim failed.
+    fn selection_owner_set(&self, selection: &gdk::Atom, time_: u32) -> bool {
+        unsafe { to_bool(ffi::gtk_selection_owner_set(self.unwrap_widget(), selection.unwrap_pointer(), time_)) }
+    }
+
+    fn selection_owner_set_for_display(&self, display: &gdk::Display, selection: &gdk::Atom, time_: u32) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_selection_owner_set_for_display(display.unwrap_pointer(), self.unwrap_widget(),
+                selection.unwrap_pointer(), time_))
+        }
+    }
+
+    /// Adds a target the widget can provide for the selection, `info` is passed back to the
+    /// "selection-get" handler.
+    fn selection_add_target(&self, selection: &gdk::Atom, target: &gdk::Atom, info: u32) {
+        unsafe {
+            ffi::gtk_selection_add_target(self.unwrap_widget(), selection.unwrap_pointer(), target.unwrap_pointer(),
+                info as c_uint)
+        }
+    }
+
+    fn selection_add_targets(&self, selection: &gdk::Atom, targets: &[gtk::TargetEntry]) {
+        let (entries, _storage) = gtk::TargetEntry::to_glib_array(targets);
+
+        unsafe {
+            ffi::gtk_selection_add_targets(self.unwrap_widget(), selection.unwrap_pointer(), entries.as_ptr(),
+                entries.len() as c_uint)
+        }
+    }
+
+    fn selection_clear_targets(&self, selection: &gdk::Atom) {
+        unsafe { ffi::gtk_selection_clear_targets(self.unwrap_widget(), selection.unwrap_pointer()) }
+    }
+
+    /// Requests the contents of the selection in the target format, they are delivered through
+    /// the "selection-received" signal. Returns false if the request couldn't be made.
+    fn selection_convert(&self, selection: &gdk::Atom, target: &gdk::Atom, time_: u32) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_selection_convert(self.unwrap_widget(), selection.unwrap_pointer(), target.unwrap_pointer(),
+                time_))
+        }
+    }
+
+    /// Removes all the handlers and unsets the ownership of all the selections of the widget.
+    fn selection_remove_all(&self) {
+        unsafe { ffi::gtk_selection_remove_all(self.unwrap_widget()) }
+    }
+
+    /// Makes the widget the current grab widget, events of the other widgets of the application
+    /// are blocked. Used for popups.
+    fn grab_add(&self) {
+        unsafe { ffi::gtk_grab_add(self.unwrap_widget()) }
+    }
+
+    fn grab_remove(&self) {
+        unsafe { ffi::gtk_grab_remove(self.unwrap_widget()) }
+    }
+
+    /// Adds a GTK grab for `device` only, the events of `device` are only delivered to the widget.
+    /// If `block_others` is true, the other devices can't interact with the application either.
+    fn device_grab_add(&self, device: &gdk::Device, block_others: bool) {
+        unsafe { ffi::gtk_device_grab_add(self.unwrap_widget(), device.unwrap_pointer(), to_gboolean(block_others)) }
+    }
+
+    fn device_grab_remove(&self, device: &gdk::Device) {
+        unsafe { ffi::gtk_device_grab_remove(self.unwrap_widget(), device.unwrap_pointer()) }
+    }
+
+    /// Returns true if `device` has been shadowed by a GTK device grab on another widget, or
+    /// would be shadowed by a grab if the device was over the widget.
+    fn device_is_shadowed(&self, device: &gdk::Device) -> bool {
+        unsafe { to_bool(ffi::gtk_widget_device_is_shadowed(self.unwrap_widget(), device.unwrap_pointer())) }
+    }
+}
+
+#[cfg(feature = "GTK_3_8")]
+extern "C" fn tick_callback_trampoline(_widget: *mut ffi::C_GtkWidget, frame_clock: *mut gdk_ffi::C_GdkFrameClock,
+    user_data: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let callback : &mut Box<FnMut(&gdk::FrameClock) -> bool> = ::std::mem::transmute(user_data);
+
+        to_gboolean(callback(&gdk::FrameClock::wrap_pointer(frame_clock)))
+    }
+}
+
+#[cfg(feature = "GTK_3_8")]
+extern "C" fn tick_callback_destroy(user_data: ffi::gpointer) {
+    unsafe {
+        let callback : Box<Box<FnMut(&gdk::FrameClock) -> bool>> = ::std::mem::transmute(user_data);
+        drop(callback);
+    }
 }
@@ -15,6 +15,7 @@
 
 use glib::translate::{FromGlibPtr, ToGlibPtr};
 use gtk::{self, ffi};
+use gdk;
 use glib::to_gboolean;
 use gtk::cast::GTK_WINDOW;
 use gtk::WindowPosition;
@@ -51,6 +52,24 @@ pub trait WindowTrait : gtk::WidgetTrait {
         }
     }
 
+    /// Sets the size constraints of the window. `geometry_widget` is the widget the base size and
+    /// the increments apply to, None for the window itself.
+    fn set_geometry_hints<T: gtk::WidgetTrait>(&self, geometry_widget: Option<&T>, geometry: &gdk::Geometry) {
+        unsafe {
+            ffi::gtk_window_set_geometry_hints(GTK_WINDOW(self.unwrap_widget()), unwrap_widget!(geometry_widget),
+                geometry.get_geometry(), geometry.get_hints())
+        }
+    }
+
+    /// Sets the reference point of the window for `move` and the geometry hints.
+    fn set_gravity(&self, gravity: gdk::Gravity) {
+        unsafe { ffi::gtk_window_set_gravity(GTK_WINDOW(self.unwrap_widget()), gravity) }
+    }
+
+    fn get_gravity(&self) -> gdk::Gravity {
+        unsafe { ffi::gtk_window_get_gravity(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
     #[cfg(feature = "GTK_3_10")]
     fn set_titlebar<T: gtk::WidgetTrait>(&self, titlebar: &T) {
         unsafe {
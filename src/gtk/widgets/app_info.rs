@@ -13,133 +13,197 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
 
-#![allow(unused_imports)]
+//! GAppInfo — Application information and launch contexts
 
 use gtk::ffi;
-use gtk::cast::{GTK_APP_INFO, GTK_APP_LAUNCH_CONTEXT};
 use gtk;
+use gdk;
 use glib;
-use glib::GlibContainer;
-use std::str;
-use std::string;
+use glib::{to_bool, to_gboolean, GlibContainer};
+use glib::translate::{FromGlibPtr, ToGlibPtr};
+use std::ptr;
 
-struct_Widget!(AppInfo);
+pub struct AppInfo {
+    pointer: *mut ffi::C_GAppInfo
+}
 
-impl AppInfo {/*
-    pub fn create_from_commandline(commande_line: &str, application_name: &str, flag: gtk::AppInfoCreateFlags, error: &mut glib::Error) -> Option<AppInfo> {
+impl AppInfo {
+    /// Creates an AppInfo from a command line. Include `SupportsStartupNotification` in `flag` for
+    /// the launched application to get a startup ID.
+    pub fn create_from_commandline(command_line: &str, application_name: Option<&str>,
+                                   flag: gtk::AppInfoCreateFlags) -> Result<AppInfo, glib::Error> {
+        let mut error = ptr::null_mut();
         let tmp_pointer = unsafe {
-            commande_line.with_c_str(|c_command_line| {
-                application_name.with_c_str(|c_application_name| {
-                    ffi::g_app_info_create_from_commandline(c_command_line, c_application_name, flag, &mut error.unwrap())
-                })
-            })
+            ffi::g_app_info_create_from_commandline(command_line.borrow_to_glib().0,
+                application_name.borrow_to_glib().0, flag, &mut error)
         };
 
         if tmp_pointer.is_null() {
-            None
+            Err(GlibContainer::wrap(error))
         } else {
-            Some(gtk::FFIWidget::wrap_widget(tmp_pointer as *mut gtk::ffi::C_GtkWidget))
+            Ok(AppInfo { pointer: tmp_pointer })
         }
     }
 
-    pub fn equals(&self, other: &AppInfo) -> bool {
-        unsafe { to_bool(ffi::g_app_info_equal(GTK_APP_INFO(self.unwrap_widget()), GTK_APP_INFO(other.unwrap_widget()))) }
-    }
-
-    pub fn get_id(&self) -> Option<String> {
-        let tmp_pointer = unsafe { ffi::g_app_info_get_id(GTK_APP_INFO(self.unwrap_widget())) };
+    /// Gets the default application for handling `content_type`, like "text/plain".
+    pub fn get_default_for_type(content_type: &str, must_support_uris: bool) -> Option<AppInfo> {
+        let tmp_pointer = unsafe {
+            ffi::g_app_info_get_default_for_type(content_type.borrow_to_glib().0, to_gboolean(must_support_uris))
+        };
 
         if tmp_pointer.is_null() {
             None
         } else {
-            Some(unsafe {FromCStr::from_raw_buf(tmp_pointer) })
+            Some(AppInfo { pointer: tmp_pointer })
         }
     }
 
-    pub fn get_name(&self) -> Option<String> {
-        let tmp_pointer = unsafe { ffi::g_app_info_get_name(GTK_APP_INFO(self.unwrap_widget())) };
+    /// Gets the default application for handling URIs with the scheme `uri_scheme`, like "http".
+    pub fn get_default_for_uri_scheme(uri_scheme: &str) -> Option<AppInfo> {
+        let tmp_pointer = unsafe { ffi::g_app_info_get_default_for_uri_scheme(uri_scheme.borrow_to_glib().0) };
 
         if tmp_pointer.is_null() {
             None
         } else {
-            Some(unsafe { FromCStr::from_raw_buf(tmp_pointer) })
+            Some(AppInfo { pointer: tmp_pointer })
         }
     }
 
-    pub fn get_display_name(&self) -> Option<String> {
-        let tmp_pointer = unsafe { ffi::g_app_info_get_display_name(GTK_APP_INFO(self.unwrap_widget())) };
+    /// Opens `uri` with the default application for its scheme.
+    pub fn launch_default_for_uri(uri: &str, launch_context: Option<&gdk::AppLaunchContext>) -> Result<(), glib::Error> {
+        let mut error = ptr::null_mut();
+        let ret = unsafe {
+            to_bool(ffi::g_app_info_launch_default_for_uri(uri.borrow_to_glib().0, unwrap_context(launch_context),
+                &mut error))
+        };
 
-        if tmp_pointer.is_null() {
-            None
+        if ret {
+            Ok(())
         } else {
-            Some(unsafe { FromCStr::from_raw_buf(tmp_pointer) })
+            Err(GlibContainer::wrap(error))
         }
     }
 
-    pub fn get_description(&self) -> Option<String> {
-        let tmp_pointer = unsafe { ffi::g_app_info_get_description(GTK_APP_INFO(self.unwrap_widget())) };
+    pub fn equals(&self, other: &AppInfo) -> bool {
+        unsafe { to_bool(ffi::g_app_info_equal(self.pointer, other.pointer)) }
+    }
 
-        if tmp_pointer.is_null() {
-            None
-        } else {
-            Some(unsafe { FromCStr::from_raw_buf(tmp_pointer) })
-        }
+    pub fn get_id(&self) -> Option<String> {
+        unsafe { FromGlibPtr::borrow(ffi::g_app_info_get_id(self.pointer)) }
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        unsafe { FromGlibPtr::borrow(ffi::g_app_info_get_name(self.pointer)) }
+    }
+
+    pub fn get_display_name(&self) -> Option<String> {
+        unsafe { FromGlibPtr::borrow(ffi::g_app_info_get_display_name(self.pointer)) }
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        unsafe { FromGlibPtr::borrow(ffi::g_app_info_get_description(self.pointer)) }
     }
 
     pub fn get_executable(&self) -> Option<String> {
-        let tmp_pointer = unsafe { ffi::g_app_info_get_executable(GTK_APP_INFO(self.unwrap_widget())) };
+        unsafe { FromGlibPtr::borrow(ffi::g_app_info_get_executable(self.pointer)) }
+    }
 
-        if tmp_pointer.is_null() {
-            None
+    pub fn get_commandline(&self) -> Option<String> {
+        unsafe { FromGlibPtr::borrow(ffi::g_app_info_get_commandline(self.pointer)) }
+    }
+
+    /// Launches the application with the files at `paths`. The timestamp, screen, desktop and
+    /// icon set on `launch_context` are used for the startup notification of the application,
+    /// so the launcher can show a busy cursor until the application window appears.
+    ///
+    /// ```ignore
+    /// let context = gdk::AppLaunchContext::new().unwrap();
+    /// context.set_timestamp(gtk::get_current_event_time());
+    /// context.set_icon_name("text-editor");
+    /// try!(app_info.launch(&["/tmp/notes.txt"], Some(&context)));
+    /// ```
+    pub fn launch(&self, paths: &[&str], launch_context: Option<&gdk::AppLaunchContext>) -> Result<(), glib::Error> {
+        let files = files_to_glist(paths);
+        let mut error = ptr::null_mut();
+        let ret = unsafe {
+            to_bool(ffi::g_app_info_launch(self.pointer, files,
+                unwrap_context(launch_context), &mut error))
+        };
+
+        free_files_glist(files);
+        if ret {
+            Ok(())
         } else {
-            Some(unsafe { FromCStr::from_raw_buf(tmp_pointer) })
+            Err(GlibContainer::wrap(error))
         }
     }
 
-    pub fn get_commandline(&self) -> Option<String> {
-        let tmp_pointer = unsafe { ffi::g_app_info_get_commandline(GTK_APP_INFO(self.unwrap_widget())) };
+    /// Launches the application with `uris`, see `launch`.
+    pub fn launch_uris(&self, uris: &[&str], launch_context: Option<&gdk::AppLaunchContext>) -> Result<(), glib::Error> {
+        let stashes: Vec<_> = uris.iter().map(|uri| uri.borrow_to_glib()).collect();
+        let mut list = ptr::null_mut();
+        let mut error = ptr::null_mut();
 
-        if tmp_pointer.is_null() {
-            None
+        for stash in stashes.iter() {
+            list = unsafe { glib::ffi::g_list_append(list, stash.0 as *mut _) };
+        }
+        let ret = unsafe {
+            to_bool(ffi::g_app_info_launch_uris(self.pointer, list,
+                unwrap_context(launch_context), &mut error))
+        };
+
+        unsafe { glib::ffi::g_list_free(list) }
+        if ret {
+            Ok(())
         } else {
-            Some(unsafe { FromCStr::from_raw_buf(tmp_pointer) })
+            Err(GlibContainer::wrap(error))
         }
     }
 
-    pub fn launch(&self, files: &mut glib::List, launch_context: &mut gtk::AppLaunchContext, error: &mut glib::Error) -> bool {
-        unsafe { to_bool({ ffi::g_app_info_launch(GTK_APP_INFO(self.unwrap_widget()),
-            files.unwrap(),
-            GTK_APP_LAUNCH_CONTEXT(launch_context.unwrap_widget()),
-            &mut error.unwrap()) }) }
+    /// Gets the startup notification ID that `launch_context` would give to the application
+    /// launched with the files at `paths`, None if the application doesn't support startup
+    /// notification.
+    pub fn get_startup_notify_id(&self, launch_context: &gdk::AppLaunchContext, paths: &[&str]) -> Option<String> {
+        let files = files_to_glist(paths);
+        let ret = unsafe {
+            FromGlibPtr::take(ffi::g_app_launch_context_get_startup_notify_id(unwrap_context(Some(launch_context)),
+                self.pointer, files))
+        };
+
+        free_files_glist(files);
+        ret
     }
 
-    pub fn supports_files(&self) -> bool {
-        unsafe { to_bool(ffi::g_app_info_supports_files(GTK_APP_INFO(self.unwrap_widget()))) }
+    /// Cancels the startup notification `startup_notify_id`, for when the launch failed after
+    /// `get_startup_notify_id` was called.
+    pub fn launch_failed(launch_context: &gdk::AppLaunchContext, startup_notify_id: &str) {
+        unsafe {
+            ffi::g_app_launch_context_launch_failed(unwrap_context(Some(launch_context)),
+                startup_notify_id.borrow_to_glib().0)
+        }
     }
 
-    pub fn supports_uris(&self) -> bool {
-        unsafe { to_bool(ffi::g_app_info_supports_uris(GTK_APP_INFO(self.unwrap_widget()))) }
+    pub fn supports_files(&self) -> bool {
+        unsafe { to_bool(ffi::g_app_info_supports_files(self.pointer)) }
     }
 
-    pub fn launch_uris(&self, uris: &mut glib::List, launch_context: &mut gtk::AppLaunchContext, error: &mut glib::Error) -> bool {
-        unsafe { to_bool({ ffi::g_app_info_launch_uris(GTK_APP_INFO(self.unwrap_widget()),
-            uris.unwrap(),
-            GTK_APP_LAUNCH_CONTEXT(launch_context.unwrap_widget()),
-            &mut error.unwrap()) }) }
+    pub fn supports_uris(&self) -> bool {
+        unsafe { to_bool(ffi::g_app_info_supports_uris(self.pointer)) }
     }
 
     pub fn should_show(&self) -> bool {
-        unsafe { to_bool(ffi::g_app_info_should_show(GTK_APP_INFO(self.unwrap_widget()))) }
+        unsafe { to_bool(ffi::g_app_info_should_show(self.pointer)) }
     }
 
     pub fn can_delete(&self) -> bool {
-        unsafe { to_bool(ffi::g_app_info_can_delete(GTK_APP_INFO(self.unwrap_widget()))) }
+        unsafe { to_bool(ffi::g_app_info_can_delete(self.pointer)) }
     }
 
     pub fn delete(&self) -> bool {
-        unsafe { to_bool(ffi::g_app_info_delete(GTK_APP_INFO(self.unwrap_widget()))) }
+        unsafe { to_bool(ffi::g_app_info_delete(self.pointer)) }
     }
 
+    /*
     pub fn reset_type_associations(&self, content_type: &str) -> () {
         unsafe {
             let c_str = CString::from_slice(content_type.as_bytes());
@@ -151,7 +215,7 @@ impl AppInfo {/*
     pub fn set_as_default_for_type(&self, content_type: &str, error: &mut glib::Error) -> bool {
         unsafe { to_bool({
             content_type.with_c_str(|c_str| {
-                ffi::g_app_info_set_as_default_for_type(GTK_APP_INFO(self.unwrap_widget()), c_str, &mut error.unwrap())
+                ffi::g_app_info_set_as_default_for_type(self.pointer, c_str, &mut error.unwrap())
             })
         }) }
     }
@@ -159,7 +223,7 @@ impl AppInfo {/*
     pub fn set_as_default_for_extension(&self, extension: &str, error: &mut glib::Error) -> bool {
         unsafe { to_bool({
             extension.with_c_str(|c_str| {
-                ffi::g_app_info_set_as_default_for_extension(GTK_APP_INFO(self.unwrap_widget()), c_str, &mut error.unwrap())
+                ffi::g_app_info_set_as_default_for_extension(self.pointer, c_str, &mut error.unwrap())
             })
         }) }
     }
@@ -167,7 +231,7 @@ impl AppInfo {/*
     pub fn set_as_last_used_for_type(&self, content_type: &str, error: &mut glib::Error) -> bool {
         unsafe { to_bool({
             content_type.with_c_str(|c_str| {
-                ffi::g_app_info_set_as_last_used_for_type(GTK_APP_INFO(self.unwrap_widget()), c_str, &mut error.unwrap())
+                ffi::g_app_info_set_as_last_used_for_type(self.pointer, c_str, &mut error.unwrap())
             })
         }) }
     }
@@ -175,25 +239,25 @@ impl AppInfo {/*
     pub fn add_supports_type(&self, content_type: &str, error: &mut glib::Error) -> bool {
         unsafe { to_bool({
             content_type.with_c_str(|c_str| {
-                ffi::g_app_info_add_supports_type(GTK_APP_INFO(self.unwrap_widget()), c_str, &mut error.unwrap())
+                ffi::g_app_info_add_supports_type(self.pointer, c_str, &mut error.unwrap())
             })
         }) }
     }
 
     pub fn can_remove_supports_type(&self) -> bool {
-        unsafe { to_bool(ffi::g_app_info_can_remove_supports_type(GTK_APP_INFO(self.unwrap_widget()))) }
+        unsafe { to_bool(ffi::g_app_info_can_remove_supports_type(self.pointer)) }
     }
 
     pub fn remove_supports_type(&self, content_type: &str, error: &mut glib::Error) -> bool {
         unsafe { to_bool({
             content_type.with_c_str(|c_str| {
-                ffi::g_app_info_remove_supports_type(GTK_APP_INFO(self.unwrap_widget()), c_str, &mut error.unwrap())
+                ffi::g_app_info_remove_supports_type(self.pointer, c_str, &mut error.unwrap())
             })
         }) }
     }
 
     pub fn get_supported_types(&self) -> Vec<String> {
-        let types = unsafe { ffi::g_app_info_get_supported_types(GTK_APP_INFO(self.unwrap_widget())) };
+        let types = unsafe { ffi::g_app_info_get_supported_types(self.pointer) };
         let mut ret = Vec::new();
 
         if types.is_not_null() {
@@ -234,33 +298,6 @@ impl AppInfo {/*
         }
     }
 
-    pub fn get_default_for_type(content_type: &str, must_support_uris: bool) -> Option<AppInfo> {
-        let tmp_pointer = unsafe {
-            content_type.with_c_str(|c_str| {
-                ffi::g_app_info_get_default_for_type(c_str, to_gboolean(must_support_uris)) })
-        };
-
-        if tmp_pointer.is_null() {
-            None
-        } else {
-            Some(gtk::FFIWidget::wrap_widget(tmp_pointer as *mut gtk::ffi::C_GtkWidget))
-        }
-    }
-
-    pub fn get_default_for_uri_scheme(uri_scheme: &str) -> Option<AppInfo> {
-        let tmp_pointer = unsafe {
-            uri_scheme.with_c_str(|c_str| {
-                ffi::g_app_info_get_default_for_uri_scheme(c_str)
-            })
-        };
-
-        if tmp_pointer.is_null() {
-            None
-        } else {
-            Some(gtk::FFIWidget::wrap_widget(tmp_pointer as *mut gtk::ffi::C_GtkWidget))
-        }
-    }
-
     pub fn get_fallback_for_type(content_type: &str) -> Option<glib::List> {
         let tmp_pointer = unsafe {
             content_type.with_c_str(|c_str| {
@@ -287,21 +324,32 @@ impl AppInfo {/*
         } else {
             glib::GlibContainer::wrap(tmp_pointer)
         }
+    }*/
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GAppInfo {
+        self.pointer
     }
 
-    pub fn launch_default_for_uri(uri: &str, launch_context: &gtk::AppLaunchContext, error: &glib::Error) -> bool {
-        unsafe { to_bool({
-            uri.with_c_str(|c_str| {
-                ffi::g_app_info_launch_default_for_uri(c_str, GTK_APP_LAUNCH_CONTEXT(launch_context.unwrap_widget()), &mut error.unwrap())
-        }) }
-    }*/
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GAppInfo) -> AppInfo {
+        AppInfo {
+            pointer: pointer
+        }
+    }
 }
 
-/*impl Clone for AppInfo {
+impl Clone for AppInfo {
     fn clone(&self) -> AppInfo {
-        let tmp_pointer = unsafe { ffi::g_app_info_dup(GTK_APP_INFO(self.unwrap_widget())) };
+        unsafe { glib::ffi::g_object_ref(self.pointer as *mut glib::ffi::C_GObject) };
+
+        AppInfo { pointer: self.pointer }
+    }
+}
 
-        gtk::FFIWidget::wrap_widget(tmp_pointer as *mut gtk::ffi::C_GtkWidget)
+impl Drop for AppInfo {
+    fn drop(&mut self) {
+        unsafe { glib::ffi::g_object_unref(self.pointer as *mut glib::ffi::C_GObject) }
     }
 }
 
@@ -309,11 +357,36 @@ impl PartialEq for AppInfo {
     fn eq(&self, other: &AppInfo) -> bool {
         self.equals(other)
     }
+}
+
+// GdkAppLaunchContext is a subclass of GAppLaunchContext
+fn unwrap_context(launch_context: Option<&gdk::AppLaunchContext>) -> *mut ffi::C_GAppLaunchContext {
+    match launch_context {
+        Some(context) => context.unwrap_pointer() as *mut ffi::C_GAppLaunchContext,
+        None => ptr::null_mut()
+    }
+}
+
+fn files_to_glist(paths: &[&str]) -> *mut glib::ffi::C_GList {
+    let mut list = ptr::null_mut();
 
-    fn ne(&self, other: &AppInfo) -> bool {
-        !self.equals(other)
+    for path in paths.iter() {
+        unsafe {
+            let file = ffi::g_file_new_for_path(path.borrow_to_glib().0);
+            list = glib::ffi::g_list_append(list, file as *mut _);
+        }
     }
-}*/
+    list
+}
+
+fn free_files_glist(list: *mut glib::ffi::C_GList) {
+    let mut it = list;
 
-impl_drop!(AppInfo);
-impl_TraitWidget!(AppInfo);
+    unsafe {
+        while !it.is_null() {
+            glib::ffi::g_object_unref((*it).data as *mut glib::ffi::C_GObject);
+            it = (*it).next;
+        }
+        glib::ffi::g_list_free(list)
+    }
+}
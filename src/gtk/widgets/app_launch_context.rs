@@ -74,7 +74,7 @@ impl AppLaunchContext {/*
     }
 
     pub fn get_display(&self, info: &gtk::AppInfo, files: &glib::List) -> Option<String> {
-        let tmp_pointer = unsafe { ffi::g_app_launch_context_get_display(GTK_APP_LAUNCH_CONTEXT(self.unwrap_widget()), GTK_APP_INFO(info.unwrap_widget()), files.unwrap()) };
+        let tmp_pointer = unsafe { ffi::g_app_launch_context_get_display(GTK_APP_LAUNCH_CONTEXT(self.unwrap_widget()), info.unwrap_pointer(), files.unwrap()) };
 
         if tmp_pointer.is_null() {
             None
@@ -84,7 +84,7 @@ impl AppLaunchContext {/*
     }
 
     pub fn get_startup_notify_id(&self, app_info: &gtk::AppInfo, files: &glib::List) -> Option<String> {
-        let tmp_pointer = unsafe { ffi::g_app_launch_context_get_startup_notify_id(GTK_APP_LAUNCH_CONTEXT(self.unwrap_widget()), GTK_APP_INFO(app_info.unwrap_widget()), files.unwrap()) };
+        let tmp_pointer = unsafe { ffi::g_app_launch_context_get_startup_notify_id(GTK_APP_LAUNCH_CONTEXT(self.unwrap_widget()), app_info.unwrap_pointer(), files.unwrap()) };
 
         if tmp_pointer.is_null() {
             None
@@ -0,0 +1,296 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! GtkClipboard — Storing data on clipboards
+
+use gtk::{self, ffi};
+use gdk;
+use glib;
+use glib::to_bool;
+use glib::translate::{FromGlibPtr, ToGlibPtr};
+use libc::{c_char, c_int, c_uint};
+use std::{mem, ptr};
+use super::selection_data::{take_atoms, take_strv};
+
+/// A clipboard, identified by a selection atom ("CLIPBOARD" for the usual clipboard, "PRIMARY"
+/// for the selected text).
+pub struct Clipboard {
+    pointer: *mut ffi::C_GtkClipboard
+}
+
+impl Clipboard {
+    /// Returns the clipboard of the default display for the selection.
+    pub fn get(selection: &gdk::Atom) -> Option<Clipboard> {
+        Clipboard::borrow_pointer(unsafe { ffi::gtk_clipboard_get(selection.unwrap_pointer()) })
+    }
+
+    pub fn get_for_display(display: &gdk::Display, selection: &gdk::Atom) -> Option<Clipboard> {
+        Clipboard::borrow_pointer(unsafe {
+            ffi::gtk_clipboard_get_for_display(display.unwrap_pointer(), selection.unwrap_pointer())
+        })
+    }
+
+    pub fn get_display(&self) -> Option<gdk::Display> {
+        let tmp = unsafe { ffi::gtk_clipboard_get_display(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Display::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn set_text(&self, text: &str) {
+        unsafe { ffi::gtk_clipboard_set_text(self.pointer, text.borrow_to_glib().0, text.len() as c_int) }
+    }
+
+    pub fn set_image(&self, pixbuf: &gdk::Pixbuf) {
+        unsafe { ffi::gtk_clipboard_set_image(self.pointer, pixbuf.unwrap_pointer()) }
+    }
+
+    /// Claims the clipboard and offers the targets. `provider` is called each time an application
+    /// requests the contents, with the selection data to fill and the `info` of the requested
+    /// target. It is dropped when the clipboard contents are replaced or cleared.
+    ///
+    /// Returns false if the clipboard couldn't be claimed.
+    pub fn set_with_data<F>(&self, targets: &[gtk::TargetEntry], provider: F) -> bool
+        where F: FnMut(&gtk::SelectionData, u32) + 'static {
+        let (entries, _storage) = gtk::TargetEntry::to_glib_array(targets);
+        let provider : Box<Box<FnMut(&gtk::SelectionData, u32)>> = Box::new(Box::new(provider));
+        let data : glib::ffi::gpointer = unsafe { mem::transmute(provider) };
+
+        let ret = unsafe {
+            to_bool(ffi::gtk_clipboard_set_with_data(self.pointer, entries.as_ptr(), entries.len() as c_uint,
+                clipboard_get_func, clipboard_clear_func, data))
+        };
+
+        if !ret {
+            let _provider : Box<Box<FnMut(&gtk::SelectionData, u32)>> = unsafe { mem::transmute(data) };
+        }
+        ret
+    }
+
+    /// Clears the contents of the clipboard, if it was set by this application.
+    pub fn clear(&self) {
+        unsafe { ffi::gtk_clipboard_clear(self.pointer) }
+    }
+
+    /// Hints the clipboard manager that the targets can be stored when the application exits.
+    pub fn set_can_store(&self, targets: &[gtk::TargetEntry]) {
+        let (entries, _storage) = gtk::TargetEntry::to_glib_array(targets);
+
+        unsafe { ffi::gtk_clipboard_set_can_store(self.pointer, entries.as_ptr(), entries.len() as c_int) }
+    }
+
+    /// Stores the current contents in the clipboard manager.
+    pub fn store(&self) {
+        unsafe { ffi::gtk_clipboard_store(self.pointer) }
+    }
+
+    /// Requests the contents for the target. The callback is called with None if the retrieval
+    /// failed.
+    pub fn request_contents<F>(&self, target: &gdk::Atom, callback: F)
+        where F: FnOnce(&Clipboard, Option<&gtk::SelectionData>) + 'static {
+        unsafe {
+            ffi::gtk_clipboard_request_contents(self.pointer, target.unwrap_pointer(), request_contents_trampoline::<F>,
+                mem::transmute(Box::new(callback)))
+        }
+    }
+
+    pub fn request_text<F>(&self, callback: F) where F: FnOnce(&Clipboard, Option<String>) + 'static {
+        unsafe {
+            ffi::gtk_clipboard_request_text(self.pointer, request_text_trampoline::<F>, mem::transmute(Box::new(callback)))
+        }
+    }
+
+    pub fn request_image<F>(&self, callback: F) where F: FnOnce(&Clipboard, Option<gdk::Pixbuf>) + 'static {
+        unsafe {
+            ffi::gtk_clipboard_request_image(self.pointer, request_image_trampoline::<F>, mem::transmute(Box::new(callback)))
+        }
+    }
+
+    pub fn request_uris<F>(&self, callback: F) where F: FnOnce(&Clipboard, Vec<String>) + 'static {
+        unsafe {
+            ffi::gtk_clipboard_request_uris(self.pointer, request_uris_trampoline::<F>, mem::transmute(Box::new(callback)))
+        }
+    }
+
+    pub fn request_targets<F>(&self, callback: F) where F: FnOnce(&Clipboard, Vec<gdk::Atom>) + 'static {
+        unsafe {
+            ffi::gtk_clipboard_request_targets(self.pointer, request_targets_trampoline::<F>, mem::transmute(Box::new(callback)))
+        }
+    }
+
+    /// Requests the contents for the target and waits for them, running a nested main loop.
+    pub fn wait_for_contents(&self, target: &gdk::Atom) -> Option<gtk::OwnedSelectionData> {
+        gtk::OwnedSelectionData::wrap_pointer(unsafe {
+            ffi::gtk_clipboard_wait_for_contents(self.pointer, target.unwrap_pointer())
+        })
+    }
+
+    pub fn wait_for_text(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::take(
+                ffi::gtk_clipboard_wait_for_text(self.pointer))
+        }
+    }
+
+    pub fn wait_for_image(&self) -> Option<gdk::Pixbuf> {
+        let tmp = unsafe { ffi::gtk_clipboard_wait_for_image(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Pixbuf::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn wait_for_uris(&self) -> Vec<String> {
+        unsafe { take_strv(ffi::gtk_clipboard_wait_for_uris(self.pointer)) }
+    }
+
+    pub fn wait_for_targets(&self) -> Vec<gdk::Atom> {
+        let mut targets = ptr::null_mut();
+        let mut n_targets : c_int = 0;
+
+        unsafe {
+            if to_bool(ffi::gtk_clipboard_wait_for_targets(self.pointer, &mut targets, &mut n_targets)) {
+                take_atoms(targets, n_targets)
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn wait_is_text_available(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_clipboard_wait_is_text_available(self.pointer)) }
+    }
+
+    pub fn wait_is_image_available(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_clipboard_wait_is_image_available(self.pointer)) }
+    }
+
+    pub fn wait_is_uris_available(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_clipboard_wait_is_uris_available(self.pointer)) }
+    }
+
+    pub fn wait_is_target_available(&self, target: &gdk::Atom) -> bool {
+        unsafe { to_bool(ffi::gtk_clipboard_wait_is_target_available(self.pointer, target.unwrap_pointer())) }
+    }
+
+    /// Wraps a clipboard owned by GTK, taking a reference.
+    pub fn borrow_pointer(pointer: *mut ffi::C_GtkClipboard) -> Option<Clipboard> {
+        if pointer.is_null() {
+            None
+        } else {
+            unsafe { glib::ffi::g_object_ref(pointer as *mut glib::ffi::C_GObject) };
+            Some(Clipboard { pointer: pointer })
+        }
+    }
+}
+
+impl_GObjectFunctions!(Clipboard, C_GtkClipboard);
+impl_TraitObject!(Clipboard, C_GtkClipboard);
+impl_drop!(Clipboard, GTK_CLIPBOARD);
+impl_connect!(Clipboard -> OwnerChange);
+
+extern "C" fn clipboard_get_func(_clipboard: *mut ffi::C_GtkClipboard, selection_data: *mut ffi::C_GtkSelectionData,
+    info: c_uint, user_data: glib::ffi::gpointer) {
+    unsafe {
+        let provider : &mut Box<FnMut(&gtk::SelectionData, u32)> = mem::transmute(user_data);
+
+        provider(gtk::SelectionData::from_pointer(selection_data), info)
+    }
+}
+
+extern "C" fn clipboard_clear_func(_clipboard: *mut ffi::C_GtkClipboard, user_data: glib::ffi::gpointer) {
+    unsafe {
+        let provider : Box<Box<FnMut(&gtk::SelectionData, u32)>> = mem::transmute(user_data);
+        drop(provider);
+    }
+}
+
+extern "C" fn request_contents_trampoline<F>(clipboard: *mut ffi::C_GtkClipboard, selection_data: *mut ffi::C_GtkSelectionData,
+    user_data: glib::ffi::gpointer) where F: FnOnce(&Clipboard, Option<&gtk::SelectionData>) {
+    unsafe {
+        let callback : Box<F> = mem::transmute(user_data);
+        let data = gtk::SelectionData::from_pointer(selection_data);
+
+        match Clipboard::borrow_pointer(clipboard) {
+            Some(clipboard) => (*callback)(&clipboard, if data.get_length() < 0 { None } else { Some(data) }),
+            None => {}
+        }
+    }
+}
+
+extern "C" fn request_text_trampoline<F>(clipboard: *mut ffi::C_GtkClipboard, text: *const c_char,
+    user_data: glib::ffi::gpointer) where F: FnOnce(&Clipboard, Option<String>) {
+    unsafe {
+        let callback : Box<F> = mem::transmute(user_data);
+
+        match Clipboard::borrow_pointer(clipboard) {
+            Some(clipboard) => (*callback)(&clipboard, FromGlibPtr::borrow(text)),
+            None => {}
+        }
+    }
+}
+
+extern "C" fn request_image_trampoline<F>(clipboard: *mut ffi::C_GtkClipboard, pixbuf: *mut gdk::ffi::C_GdkPixbuf,
+    user_data: glib::ffi::gpointer) where F: FnOnce(&Clipboard, Option<gdk::Pixbuf>) {
+    unsafe {
+        let callback : Box<F> = mem::transmute(user_data);
+        let pixbuf = if pixbuf.is_null() {
+            None
+        } else {
+            glib::ffi::g_object_ref(pixbuf as *mut glib::ffi::C_GObject);
+            Some(gdk::Pixbuf::wrap_pointer(pixbuf))
+        };
+
+        match Clipboard::borrow_pointer(clipboard) {
+            Some(clipboard) => (*callback)(&clipboard, pixbuf),
+            None => {}
+        }
+    }
+}
+
+extern "C" fn request_uris_trampoline<F>(clipboard: *mut ffi::C_GtkClipboard, uris: *mut *mut c_char,
+    user_data: glib::ffi::gpointer) where F: FnOnce(&Clipboard, Vec<String>) {
+    unsafe {
+        let callback : Box<F> = mem::transmute(user_data);
+        let uris : Vec<String> = ::glib::translate::FromGlibPtrContainer::borrow(uris as *const *const c_char);
+
+        match Clipboard::borrow_pointer(clipboard) {
+            Some(clipboard) => (*callback)(&clipboard, uris),
+            None => {}
+        }
+    }
+}
+
+extern "C" fn request_targets_trampoline<F>(clipboard: *mut ffi::C_GtkClipboard, atoms: *mut gdk::ffi::C_GdkAtom,
+    n_atoms: c_int, user_data: glib::ffi::gpointer) where F: FnOnce(&Clipboard, Vec<gdk::Atom>) {
+    unsafe {
+        let callback : Box<F> = mem::transmute(user_data);
+        let targets = if atoms.is_null() {
+            Vec::new()
+        } else {
+            ::std::slice::from_raw_parts(atoms, n_atoms as usize).iter().map(|&a| gdk::Atom::wrap_pointer(a)).collect()
+        };
+
+        match Clipboard::borrow_pointer(clipboard) {
+            Some(clipboard) => (*callback)(&clipboard, targets),
+            None => {}
+        }
+    }
+}
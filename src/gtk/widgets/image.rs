@@ -18,6 +18,7 @@
 use gtk::{self, ffi};
 use gtk::cast::GTK_IMAGE;
 use gtk::FFIWidget;
+use gdk;
 use glib::translate::ToGlibPtr;
 
 /// Image — A widget displaying an image
@@ -38,6 +39,29 @@ impl Image {
         check_pointer!(tmp_pointer, Image)
     }
 
+    pub fn new_from_pixbuf(pixbuf: &gdk::Pixbuf) -> Option<Image> {
+        let tmp_pointer = unsafe {
+            ffi::gtk_image_new_from_pixbuf(pixbuf.unwrap_pointer())
+        };
+        check_pointer!(tmp_pointer, Image)
+    }
+
+    /// Creates an Image displaying the given animation. A static image loaded as an animation
+    /// is displayed as a plain pixbuf.
+    pub fn new_from_animation(animation: &gdk::PixbufAnimation) -> Option<Image> {
+        let tmp_pointer = unsafe {
+            ffi::gtk_image_new_from_animation(animation.unwrap_pointer())
+        };
+        check_pointer!(tmp_pointer, Image)
+    }
+
+    pub fn new_from_resource(resource_path: &str) -> Option<Image> {
+        let tmp_pointer = unsafe {
+            ffi::gtk_image_new_from_resource(resource_path.borrow_to_glib().0)
+        };
+        check_pointer!(tmp_pointer, Image)
+    }
+
     pub fn set_from_file(&self, filename: &str) {
         unsafe {
             ffi::gtk_image_set_from_file(GTK_IMAGE(self.unwrap_widget()),
@@ -51,6 +75,55 @@ impl Image {
                                               icon_name.borrow_to_glib().0, size)
         };
     }
+
+    pub fn set_from_pixbuf(&self, pixbuf: &gdk::Pixbuf) {
+        unsafe { ffi::gtk_image_set_from_pixbuf(GTK_IMAGE(self.unwrap_widget()), pixbuf.unwrap_pointer()) }
+    }
+
+    pub fn set_from_animation(&self, animation: &gdk::PixbufAnimation) {
+        unsafe { ffi::gtk_image_set_from_animation(GTK_IMAGE(self.unwrap_widget()), animation.unwrap_pointer()) }
+    }
+
+    pub fn set_from_resource(&self, resource_path: &str) {
+        unsafe { ffi::gtk_image_set_from_resource(GTK_IMAGE(self.unwrap_widget()), resource_path.borrow_to_glib().0) }
+    }
+
+    /// Gets the pixbuf being displayed, None if the image is empty or doesn't display a pixbuf.
+    pub fn get_pixbuf(&self) -> Option<gdk::Pixbuf> {
+        let tmp = unsafe { ffi::gtk_image_get_pixbuf(GTK_IMAGE(self.unwrap_widget())) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
+            Some(gdk::Pixbuf::wrap_pointer(tmp))
+        }
+    }
+
+    /// Gets the animation being displayed, None if the image doesn't display an animation.
+    pub fn get_animation(&self) -> Option<gdk::PixbufAnimation> {
+        let tmp = unsafe { ffi::gtk_image_get_animation(GTK_IMAGE(self.unwrap_widget())) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
+            Some(gdk::PixbufAnimation::wrap_pointer(tmp))
+        }
+    }
+
+    /// Resets the image to be empty.
+    pub fn clear(&self) {
+        unsafe { ffi::gtk_image_clear(GTK_IMAGE(self.unwrap_widget())) }
+    }
+
+    pub fn set_pixel_size(&self, pixel_size: i32) {
+        unsafe { ffi::gtk_image_set_pixel_size(GTK_IMAGE(self.unwrap_widget()), pixel_size) }
+    }
+
+    pub fn get_pixel_size(&self) -> i32 {
+        unsafe { ffi::gtk_image_get_pixel_size(GTK_IMAGE(self.unwrap_widget())) }
+    }
 }
 
 impl_drop!(Image);
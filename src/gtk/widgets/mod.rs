@@ -122,6 +122,10 @@ pub use self::lock_button::LockButton;
 pub use self::entry_completion::EntryCompletion;
 pub use self::icon_view::IconView;
 pub use self::tree_selection::TreeSelection;
+pub use self::clipboard::Clipboard;
+pub use self::selection_data::{SelectionData, OwnedSelectionData};
+pub use self::target_entry::TargetEntry;
+pub use self::target_list::TargetList;
 pub use self::recent_chooser_widget::RecentChooserWidget;
 pub use self::combo_box::ComboBox;
 #[cfg(feature = "GTK_3_12")]
@@ -144,7 +148,10 @@ pub use self::color_chooser_widget::ColorChooserWidget;
 pub use self::font_chooser_widget::FontChooserWidget;
 #[cfg(target_os = "linux")]
 pub use self::socket::Socket;
+#[cfg(all(target_os = "linux", feature = "X11"))]
+pub use self::plug::Plug;
 pub use self::event_box::EventBox;
+pub use self::offscreen_window::OffscreenWindow;
 
 mod builder;
 mod window;
@@ -255,6 +262,10 @@ mod lock_button;
 mod entry_completion;
 mod icon_view;
 mod tree_selection;
+mod clipboard;
+mod selection_data;
+mod target_entry;
+mod target_list;
 mod recent_chooser_widget;
 mod combo_box;
 #[cfg(feature = "GTK_3_12")]
@@ -277,4 +288,7 @@ mod color_chooser_widget;
 mod font_chooser_widget;
 #[cfg(target_os = "linux")]
 mod socket;
+#[cfg(all(target_os = "linux", feature = "X11"))]
+mod plug;
 mod event_box;
+mod offscreen_window;
@@ -0,0 +1,71 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! GtkOffscreenWindow — A toplevel to manage offscreen rendering of child widgets
+
+use gtk::cast::GTK_OFFSCREEN_WINDOW;
+use gtk::{self, ffi};
+use gdk;
+use cairo;
+
+/// A toplevel window which is never shown on screen, its child is rendered to an image surface.
+/// Show it, and its child, then process the pending events before taking a snapshot:
+///
+/// ```ignore
+/// let offscreen = gtk::OffscreenWindow::new().unwrap();
+/// offscreen.add(&widget);
+/// offscreen.show_all();
+/// while gtk::events_pending() { gtk::main_iteration(); }
+/// let pixbuf = offscreen.get_pixbuf();
+/// ```
+struct_Widget!(OffscreenWindow);
+
+impl OffscreenWindow {
+    pub fn new() -> Option<OffscreenWindow> {
+        let tmp_pointer = unsafe { ffi::gtk_offscreen_window_new() };
+        check_pointer!(tmp_pointer, OffscreenWindow)
+    }
+
+    /// Returns the surface the child is rendered to, None if the window isn't realized.
+    pub fn get_surface(&self) -> Option<cairo::Surface> {
+        let tmp = unsafe { ffi::gtk_offscreen_window_get_surface(GTK_OFFSCREEN_WINDOW(self.pointer)) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(cairo::Surface::wrap(unsafe { cairo::ffi::cairo_surface_reference(tmp) }))
+        }
+    }
+
+    /// Returns a copy of the current contents of the window.
+    pub fn get_pixbuf(&self) -> Option<gdk::Pixbuf> {
+        let tmp = unsafe { ffi::gtk_offscreen_window_get_pixbuf(GTK_OFFSCREEN_WINDOW(self.pointer)) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Pixbuf::wrap_pointer(tmp))
+        }
+    }
+}
+
+impl_drop!(OffscreenWindow);
+impl_TraitWidget!(OffscreenWindow);
+
+impl gtk::ContainerTrait for OffscreenWindow {}
+impl gtk::WindowTrait for OffscreenWindow {}
+impl gtk::BinTrait for OffscreenWindow {}
+
+impl_widget_events!(OffscreenWindow);
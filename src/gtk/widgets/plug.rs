@@ -0,0 +1,65 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! GtkPlug — Toplevel for embedding into other processes
+
+use gtk::cast::GTK_PLUG;
+use gtk::{self, ffi};
+use gdk;
+use glib::to_bool;
+
+/// The other side of a `gtk::Socket`: a toplevel window whose contents are shown in a socket of
+/// another process, the XID of the socket is usually given on the command line.
+///
+/// ```ignore
+/// let plug = gtk::Plug::new(socket_id).unwrap();
+/// plug.add(&widget);
+/// plug.show_all();
+/// ```
+struct_Widget!(Plug);
+
+impl Plug {
+    /// Creates a plug embedded in the socket `socket_id`, 0 creates a plug which isn't embedded
+    /// yet, its `get_id` can then be given to `gtk::Socket::add_id`.
+    pub fn new(socket_id: gdk::x11::XID) -> Option<Plug> {
+        let tmp_pointer = unsafe { ffi::gtk_plug_new(socket_id) };
+        check_pointer!(tmp_pointer, Plug)
+    }
+
+    pub fn new_for_display(display: &gdk::Display, socket_id: gdk::x11::XID) -> Option<Plug> {
+        let tmp_pointer = unsafe { ffi::gtk_plug_new_for_display(display.unwrap_pointer(), socket_id) };
+        check_pointer!(tmp_pointer, Plug)
+    }
+
+    /// Gets the XID of the window of the plug, the plug must be realized.
+    pub fn get_id(&self) -> gdk::x11::XID {
+        unsafe { ffi::gtk_plug_get_id(GTK_PLUG(self.pointer)) }
+    }
+
+    /// Returns true if the plug is embedded in a socket.
+    pub fn get_embedded(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_plug_get_embedded(GTK_PLUG(self.pointer))) }
+    }
+}
+
+impl_drop!(Plug);
+impl_TraitWidget!(Plug);
+
+impl gtk::ContainerTrait for Plug {}
+impl gtk::WindowTrait for Plug {}
+impl gtk::BinTrait for Plug {}
+
+impl_widget_events!(Plug);
+impl_connect!(Plug -> Embedded);
@@ -0,0 +1,219 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! GtkSelectionData — The data exchanged through a clipboard, a selection or a drag and drop
+
+use gtk::ffi;
+use gdk;
+use glib::to_bool;
+use glib::translate::{FromGlibPtr, FromGlibPtrContainer, ToGlibPtr};
+use libc::{c_char, c_int};
+use std::ops::Deref;
+use std::{ptr, slice};
+
+/// Selection data borrowed from GTK, as passed to the data providers and the receiving callbacks.
+#[repr(C)]
+pub struct SelectionData {
+    _selection: gdk::ffi::C_GdkAtom
+}
+
+impl SelectionData {
+    /// Stores new data. `format` is the number of bits per unit of data (8 for strings).
+    pub fn set(&self, type_: &gdk::Atom, format: i32, data: &[u8]) {
+        unsafe { ffi::gtk_selection_data_set(self.unwrap_pointer(), type_.unwrap_pointer(), format as c_int, data.as_ptr(),
+            data.len() as c_int) }
+    }
+
+    /// Sets the contents from an UTF-8 string, converted to the form determined by the target.
+    /// Returns true if the conversion succeeded.
+    pub fn set_text(&self, text: &str) -> bool {
+        unsafe { to_bool(ffi::gtk_selection_data_set_text(self.unwrap_pointer(), text.borrow_to_glib().0, text.len() as c_int)) }
+    }
+
+    pub fn get_text(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::take(
+                ffi::gtk_selection_data_get_text(self.unwrap_pointer()))
+        }
+    }
+
+    pub fn set_pixbuf(&self, pixbuf: &gdk::Pixbuf) -> bool {
+        unsafe { to_bool(ffi::gtk_selection_data_set_pixbuf(self.unwrap_pointer(), pixbuf.unwrap_pointer())) }
+    }
+
+    pub fn get_pixbuf(&self) -> Option<gdk::Pixbuf> {
+        let tmp = unsafe { ffi::gtk_selection_data_get_pixbuf(self.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Pixbuf::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn set_uris(&self, uris: &[&str]) -> bool {
+        unsafe { to_bool(ffi::gtk_selection_data_set_uris(self.unwrap_pointer(), uris.borrow_to_glib().0)) }
+    }
+
+    pub fn get_uris(&self) -> Vec<String> {
+        unsafe { take_strv(ffi::gtk_selection_data_get_uris(self.unwrap_pointer())) }
+    }
+
+    /// Returns the targets of a selection data holding the "TARGETS" target.
+    pub fn get_targets(&self) -> Vec<gdk::Atom> {
+        let mut targets = ptr::null_mut();
+        let mut n_atoms : c_int = 0;
+
+        unsafe {
+            if to_bool(ffi::gtk_selection_data_get_targets(self.unwrap_pointer(), &mut targets, &mut n_atoms)) {
+                take_atoms(targets, n_atoms)
+            } else {
+                Vec::new()
+            }
+        }
+    }
+
+    pub fn targets_include_text(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_selection_data_targets_include_text(self.unwrap_pointer())) }
+    }
+
+    /// Returns whether the targets include an image format, writable restricts the check to the
+    /// formats GdkPixbuf can save.
+    pub fn targets_include_image(&self, writable: bool) -> bool {
+        unsafe { to_bool(ffi::gtk_selection_data_targets_include_image(self.unwrap_pointer(), ::glib::to_gboolean(writable))) }
+    }
+
+    pub fn targets_include_uri(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_selection_data_targets_include_uri(self.unwrap_pointer())) }
+    }
+
+    pub fn get_selection(&self) -> gdk::Atom {
+        gdk::Atom::wrap_pointer(unsafe { ffi::gtk_selection_data_get_selection(self.unwrap_pointer()) })
+    }
+
+    pub fn get_target(&self) -> gdk::Atom {
+        gdk::Atom::wrap_pointer(unsafe { ffi::gtk_selection_data_get_target(self.unwrap_pointer()) })
+    }
+
+    pub fn get_data_type(&self) -> gdk::Atom {
+        gdk::Atom::wrap_pointer(unsafe { ffi::gtk_selection_data_get_data_type(self.unwrap_pointer()) })
+    }
+
+    pub fn get_format(&self) -> i32 {
+        unsafe { ffi::gtk_selection_data_get_format(self.unwrap_pointer()) }
+    }
+
+    /// Returns the raw data, empty if the retrieval failed.
+    pub fn get_data(&self) -> &[u8] {
+        let mut length : c_int = 0;
+
+        unsafe {
+            let data = ffi::gtk_selection_data_get_data_with_length(self.unwrap_pointer(), &mut length);
+
+            if data.is_null() || length <= 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(data, length as usize)
+            }
+        }
+    }
+
+    /// Returns the length of the data, a negative value if the retrieval failed.
+    pub fn get_length(&self) -> i32 {
+        unsafe { ffi::gtk_selection_data_get_length(self.unwrap_pointer()) }
+    }
+
+    pub fn get_display(&self) -> Option<gdk::Display> {
+        let tmp = unsafe { ffi::gtk_selection_data_get_display(self.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(gdk::Display::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn copy(&self) -> Option<OwnedSelectionData> {
+        OwnedSelectionData::wrap_pointer(unsafe { ffi::gtk_selection_data_copy(self.unwrap_pointer()) })
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkSelectionData {
+        self as *const SelectionData as *mut ffi::C_GtkSelectionData
+    }
+
+    /// Views a GtkSelectionData pointer as a SelectionData.
+    pub unsafe fn from_pointer<'a>(pointer: *mut ffi::C_GtkSelectionData) -> &'a SelectionData {
+        &*(pointer as *const SelectionData)
+    }
+}
+
+/// Selection data owned by Rust, freed when dropped. It dereferences to `SelectionData`.
+pub struct OwnedSelectionData {
+    pointer: *mut ffi::C_GtkSelectionData
+}
+
+impl OwnedSelectionData {
+    /// Takes ownership of a GtkSelectionData.
+    pub fn wrap_pointer(pointer: *mut ffi::C_GtkSelectionData) -> Option<OwnedSelectionData> {
+        if pointer.is_null() {
+            None
+        } else {
+            Some(OwnedSelectionData { pointer: pointer })
+        }
+    }
+}
+
+impl Deref for OwnedSelectionData {
+    type Target = SelectionData;
+
+    fn deref(&self) -> &SelectionData {
+        unsafe { SelectionData::from_pointer(self.pointer) }
+    }
+}
+
+impl Clone for OwnedSelectionData {
+    fn clone(&self) -> OwnedSelectionData {
+        OwnedSelectionData {
+            pointer: unsafe { ffi::gtk_selection_data_copy(self.pointer) }
+        }
+    }
+}
+
+impl Drop for OwnedSelectionData {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::gtk_selection_data_free(self.pointer) };
+            self.pointer = ptr::null_mut();
+        }
+    }
+}
+
+pub unsafe fn take_strv(strv: *mut *mut c_char) -> Vec<String> {
+    let ret: Vec<String> = FromGlibPtrContainer::borrow(strv as *const *const c_char);
+
+    ::glib::ffi::g_strfreev(strv);
+    ret
+}
+
+pub unsafe fn take_atoms(atoms: *mut gdk::ffi::C_GdkAtom, n_atoms: c_int) -> Vec<gdk::Atom> {
+    if atoms.is_null() {
+        return Vec::new();
+    }
+
+    let ret = slice::from_raw_parts(atoms, n_atoms as usize).iter().map(|&a| gdk::Atom::wrap_pointer(a)).collect();
+
+    ::glib::ffi::g_free(atoms as ::glib::ffi::gpointer);
+    ret
+}
@@ -17,7 +17,10 @@
 
 use gtk::{self, ffi};
 use gtk::FFIWidget;
-//use gtk::cast::GTK_SOCKET;
+#[cfg(feature = "X11")]
+use gtk::cast::GTK_SOCKET;
+#[cfg(feature = "X11")]
+use gdk;
 
 struct_Widget!(Socket);
 
@@ -28,15 +31,20 @@ impl Socket {
         check_pointer!(tmp_pointer, Socket)
     }
 
-    /*pub fn add_id(&self, window: Window) {
+    /// Embeds the X window `window`, usually the `get_id` of a `gtk::Plug`. The socket must be
+    /// in a toplevel window.
+    #[cfg(feature = "X11")]
+    pub fn add_id(&self, window: gdk::x11::XID) {
         unsafe { ffi::gtk_socket_add_id(GTK_SOCKET(self.unwrap_widget()), window) };
     }
 
-    pub fn get_id(&self) -> Window {
-        unsafe { ffi::gtk_socket_get_id(GTK_SOCKET(self.unwrap_widget())) };
+    /// Gets the XID to give to `gtk::Plug::new`, the socket must be realized.
+    #[cfg(feature = "X11")]
+    pub fn get_id(&self) -> gdk::x11::XID {
+        unsafe { ffi::gtk_socket_get_id(GTK_SOCKET(self.unwrap_widget())) }
     }
 
-    pub fn get_plug_window(&self) -> GdkWindow {
+    /*pub fn get_plug_window(&self) -> GdkWindow {
         let tmp_pointer = unsafe { ffi::gtk_socket_get_plug_window(GTK_SOCKET(self.unwrap_widget())) };
 
         // add end of code
@@ -48,4 +56,5 @@ impl_TraitWidget!(Socket);
 
 impl gtk::ContainerTrait for Socket {}
 
-impl_widget_events!(Socket);
\ No newline at end of file
+impl_widget_events!(Socket);
+impl_connect!(Socket -> PlugAdded, PlugRemoved);
\ No newline at end of file
@@ -0,0 +1,66 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! GtkTargetEntry — A target that can be offered by a clipboard, a selection or a drag source
+
+use gtk::{self, ffi};
+use libc::{c_char, c_uint};
+use glib::translate::{ToGlibPtr, Stash};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TargetEntry {
+    target: String,
+    flags: gtk::TargetFlags,
+    info: u32
+}
+
+impl TargetEntry {
+    /// Creates a target entry. `target` is the name of the target ("text/html" for instance) and
+    /// `info` is an application assigned id passed back to the data providers.
+    pub fn new(target: &str, flags: gtk::TargetFlags, info: u32) -> TargetEntry {
+        TargetEntry {
+            target: target.to_string(),
+            flags: flags,
+            info: info
+        }
+    }
+
+    pub fn get_target(&self) -> &str {
+        &self.target
+    }
+
+    pub fn get_flags(&self) -> gtk::TargetFlags {
+        self.flags
+    }
+
+    pub fn get_info(&self) -> u32 {
+        self.info
+    }
+
+    /// Builds the C array of the entries. The strings are owned by the returned storage, which
+    /// must outlive the use of the array.
+    #[doc(hidden)]
+    pub fn to_glib_array<'a>(targets: &'a [TargetEntry])
+        -> (Vec<ffi::C_GtkTargetEntry>, Vec<Stash<'a, *const c_char, String>>) {
+        let storage : Vec<_> = targets.iter().map(|t| t.target.borrow_to_glib()).collect();
+        let array = targets.iter().zip(storage.iter()).map(|(t, s)| ffi::C_GtkTargetEntry {
+            target: s.0 as *mut c_char,
+            flags: t.flags,
+            info: t.info as c_uint
+        }).collect();
+
+        (array, storage)
+    }
+}
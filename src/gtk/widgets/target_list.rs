@@ -0,0 +1,104 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! GtkTargetList — A reference counted list of targets
+
+use gtk::{self, ffi};
+use gdk;
+use glib::{to_bool, to_gboolean};
+use libc::c_uint;
+use std::ptr;
+
+pub struct TargetList {
+    pointer: *mut ffi::C_GtkTargetList
+}
+
+impl TargetList {
+    pub fn new(targets: &[gtk::TargetEntry]) -> Option<TargetList> {
+        let (entries, _storage) = gtk::TargetEntry::to_glib_array(targets);
+
+        TargetList::wrap_pointer(unsafe { ffi::gtk_target_list_new(entries.as_ptr(), entries.len() as c_uint) })
+    }
+
+    pub fn add(&self, target: &gdk::Atom, flags: gtk::TargetFlags, info: u32) {
+        unsafe { ffi::gtk_target_list_add(self.pointer, target.unwrap_pointer(), flags.bits() as c_uint, info as c_uint) }
+    }
+
+    pub fn add_table(&self, targets: &[gtk::TargetEntry]) {
+        let (entries, _storage) = gtk::TargetEntry::to_glib_array(targets);
+
+        unsafe { ffi::gtk_target_list_add_table(self.pointer, entries.as_ptr(), entries.len() as c_uint) }
+    }
+
+    /// Adds the text targets supported by GtkSelectionData, all with the same `info`.
+    pub fn add_text_targets(&self, info: u32) {
+        unsafe { ffi::gtk_target_list_add_text_targets(self.pointer, info as c_uint) }
+    }
+
+    /// Adds the image targets supported by GtkSelectionData, `writable` restricts them to the
+    /// formats GdkPixbuf can save.
+    pub fn add_image_targets(&self, info: u32, writable: bool) {
+        unsafe { ffi::gtk_target_list_add_image_targets(self.pointer, info as c_uint, to_gboolean(writable)) }
+    }
+
+    pub fn add_uri_targets(&self, info: u32) {
+        unsafe { ffi::gtk_target_list_add_uri_targets(self.pointer, info as c_uint) }
+    }
+
+    pub fn remove(&self, target: &gdk::Atom) {
+        unsafe { ffi::gtk_target_list_remove(self.pointer, target.unwrap_pointer()) }
+    }
+
+    /// Returns the `info` of the target, None if it isn't in the list.
+    pub fn find(&self, target: &gdk::Atom) -> Option<u32> {
+        let mut info : c_uint = 0;
+
+        if unsafe { to_bool(ffi::gtk_target_list_find(self.pointer, target.unwrap_pointer(), &mut info)) } {
+            Some(info)
+        } else {
+            None
+        }
+    }
+
+    /// Takes ownership of a target list reference.
+    pub fn wrap_pointer(pointer: *mut ffi::C_GtkTargetList) -> Option<TargetList> {
+        if pointer.is_null() {
+            None
+        } else {
+            Some(TargetList { pointer: pointer })
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkTargetList {
+        self.pointer
+    }
+}
+
+impl Clone for TargetList {
+    fn clone(&self) -> TargetList {
+        TargetList {
+            pointer: unsafe { ffi::gtk_target_list_ref(self.pointer) }
+        }
+    }
+}
+
+impl Drop for TargetList {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::gtk_target_list_unref(self.pointer) };
+            self.pointer = ptr::null_mut();
+        }
+    }
+}
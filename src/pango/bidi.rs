@@ -0,0 +1,37 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Bidirectional Text — Types and functions to help with handling bidirectional text
+
+use pango::{self, ffi};
+use libc::{c_char, c_int};
+
+/// Searches a string for the first character that has a strong direction, according to the
+/// Unicode bidirectional algorithm. Returns `Neutral` if no such character is found.
+pub fn find_base_dir(text: &str) -> pango::Direction {
+    unsafe { ffi::pango_find_base_dir(text.as_ptr() as *const c_char, text.len() as c_int) }
+}
+
+/// Determines the inherent direction of a character; either `StrongLeftToRight`,
+/// `StrognRightToLeft`, or `Neutral`.
+pub fn unichar_direction(ch: char) -> pango::Direction {
+    unsafe { ffi::pango_unichar_direction(ch as u32) }
+}
+
+/// Determines the normative bidirectional character type of a character, as specified in the
+/// Unicode Character Database.
+pub fn bidi_type_for_unichar(ch: char) -> pango::BidiType {
+    unsafe { ffi::pango_bidi_type_for_unichar(ch as u32) }
+}
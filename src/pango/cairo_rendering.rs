@@ -0,0 +1,96 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Cairo Rendering — Font handling and rendering with the Cairo backend
+
+use pango::{self, ffi};
+use cairo;
+
+/// Creates a context object set up to match the current transformation and target surface of
+/// the Cairo context.
+pub fn cairo_create_context(cr: &cairo::Context) -> Option<pango::Context> {
+    let tmp = unsafe { ffi::pango_cairo_create_context(cr.get_ptr()) };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(pango::Context::wrap_pointer(tmp))
+    }
+}
+
+/// Updates a Context previously created for use with Cairo to match the current transformation
+/// and target surface of a Cairo context. The matrix of the context is replaced by the current
+/// transformation of `cr`, and with `Gravity::Auto` the resolved gravity follows it, so rotated
+/// and vertical text keeps the right glyph orientation. Layouts created from the context must
+/// then be notified with `Layout::context_changed`.
+pub fn cairo_update_context(cr: &cairo::Context, context: &pango::Context) {
+    unsafe { ffi::pango_cairo_update_context(cr.get_ptr(), context.unwrap_pointer()) }
+}
+
+/// Sets the resolution for the context, in dots per inch. This is a scale factor between points
+/// specified in a FontDescription and Cairo units. A negative value unsets the resolution.
+pub fn cairo_context_set_resolution(context: &pango::Context, dpi: f64) {
+    unsafe { ffi::pango_cairo_context_set_resolution(context.unwrap_pointer(), dpi) }
+}
+
+/// Gets the resolution for the context, a negative value is returned if no resolution was set.
+pub fn cairo_context_get_resolution(context: &pango::Context) -> f64 {
+    unsafe { ffi::pango_cairo_context_get_resolution(context.unwrap_pointer()) }
+}
+
+/// Creates a layout object set up to match the current transformation and target surface of the
+/// Cairo context.
+pub fn cairo_create_layout(cr: &cairo::Context) -> Option<pango::Layout> {
+    let tmp = unsafe { ffi::pango_cairo_create_layout(cr.get_ptr()) };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(pango::Layout::wrap_pointer(tmp))
+    }
+}
+
+/// Updates the private context of a layout created with `cairo_create_layout` to match the
+/// current transformation and target surface of a Cairo context, and notifies the layout.
+pub fn cairo_update_layout(cr: &cairo::Context, layout: &pango::Layout) {
+    unsafe { ffi::pango_cairo_update_layout(cr.get_ptr(), layout.unwrap_pointer()) }
+}
+
+/// Draws a layout in the specified Cairo context. The top-left corner of the layout will be
+/// drawn at the current point of the Cairo context.
+pub fn cairo_show_layout(cr: &cairo::Context, layout: &pango::Layout) {
+    unsafe { ffi::pango_cairo_show_layout(cr.get_ptr(), layout.unwrap_pointer()) }
+}
+
+/// Draws a layout line in the specified Cairo context. The origin of the glyphs (the left edge
+/// of the line) will be drawn at the current point of the Cairo context.
+pub fn cairo_show_layout_line(cr: &cairo::Context, line: &pango::LayoutLine) {
+    unsafe { ffi::pango_cairo_show_layout_line(cr.get_ptr(), line.unwrap_pointer()) }
+}
+
+/// Draws the glyphs in `glyphs` in the specified Cairo context.
+pub fn cairo_show_glyph_string(cr: &cairo::Context, font: &pango::Font, glyphs: &pango::GlyphString) {
+    unsafe { ffi::pango_cairo_show_glyph_string(cr.get_ptr(), font.unwrap_pointer(), glyphs.unwrap_pointer()) }
+}
+
+/// Adds the text in a layout to the current path in the specified Cairo context.
+pub fn cairo_layout_path(cr: &cairo::Context, layout: &pango::Layout) {
+    unsafe { ffi::pango_cairo_layout_path(cr.get_ptr(), layout.unwrap_pointer()) }
+}
+
+/// Adds the text in a layout line to the current path in the specified Cairo context.
+pub fn cairo_layout_line_path(cr: &cairo::Context, line: &pango::LayoutLine) {
+    unsafe { ffi::pango_cairo_layout_line_path(cr.get_ptr(), line.unwrap_pointer()) }
+}
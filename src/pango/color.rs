@@ -0,0 +1,39 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Colors — Parsing and serialization of the colors of text attributes
+
+use pango::{self, ffi};
+use glib::to_bool;
+use glib::translate::{FromGlibPtr, ToGlibPtr};
+
+/// Parses a color name ("red", from the X11 rgb.txt file) or a hexadecimal color of the form
+/// `#rgb`, `#rrggbb`, `#rrrgggbbb` or `#rrrrggggbbbb`.
+pub fn color_parse(spec: &str) -> Option<pango::Color> {
+    let mut color = pango::Color { red: 0, green: 0, blue: 0 };
+
+    if unsafe { to_bool(ffi::pango_color_parse(&mut color, spec.borrow_to_glib().0)) } {
+        Some(color)
+    } else {
+        None
+    }
+}
+
+/// Returns the color as a hexadecimal string of the form `#rrrrggggbbbb`.
+pub fn color_to_string(color: &pango::Color) -> Option<String> {
+    unsafe {
+        FromGlibPtr::take(ffi::pango_color_to_string(color))
+    }
+}
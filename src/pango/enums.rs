@@ -15,6 +15,9 @@
 
 //! Enumeration used with pango types
 
+use libc::c_int;
+use glib::translate::FromGlib;
+
 /// The PangoGravity type represents the orientation of glyphs in a segment of text. This is useful when rendering vertical text
 /// layouts. In those situations, the layout is rotated using a non-identity PangoMatrix, and then glyph orientation is controlled
 /// using PangoGravity. Not every value in this enumeration makes sense for every usage of PangoGravity; for example, PANGO_GRAVITY_AUTO
@@ -56,9 +59,9 @@ pub enum GravityHint {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 pub enum Script {
     /// a value never returned from pango_script_for_unichar()
-    InvalidCode,
+    InvalidCode = -1,
     /// a character used by multiple different scripts
-    Common,
+    Common = 0,
     /// a mark glyph that takes its script from the base glyph to which it is attached
     Inherited,
     /// Arabic
@@ -235,6 +238,19 @@ pub enum Script {
     Takri
 }
 
+// Scripts added after Takri by newer versions of Pango are mapped to Unknown
+impl FromGlib for Script {
+    type GlibType = c_int;
+
+    fn from_glib(val: c_int) -> Script {
+        if val >= Script::InvalidCode as c_int && val <= Script::Takri as c_int {
+            unsafe { ::std::mem::transmute(val) }
+        } else {
+            Script::Unknown
+        }
+    }
+}
+
 /// The PangoDirection type represents a direction in the Unicode bidirectional algorithm; not every value in this enumeration makes sense for
 /// every usage of PangoDirection; for example, the return value of pango_unichar_direction() and pango_find_base_dir() cannot be PANGO_DIRECTION_WEAK_LTR
 /// or PANGO_DIRECTION_WEAK_RTL, since every character is either neutral or has a strong direction; on the other hand PANGO_DIRECTION_NEUTRAL doesn't
@@ -261,6 +277,18 @@ pub enum Direction {
     Neutral
 }
 
+impl FromGlib for Direction {
+    type GlibType = c_int;
+
+    fn from_glib(val: c_int) -> Direction {
+        if val >= Direction::StrongLeftToRight as c_int && val <= Direction::Neutral as c_int {
+            unsafe { ::std::mem::transmute(val) }
+        } else {
+            Direction::Neutral
+        }
+    }
+}
+
 /// The PangoBidiType type represents the bidirectional character type of a Unicode character as specified by the
 /// [Unicode bidirectional algorithm](http://www.unicode.org/reports/tr9/).
 #[repr(C)]
@@ -324,29 +352,29 @@ pub enum Style {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
 pub enum Weight {
     /// the thin weight (= 100; Since: 1.24)
-    Thin,
+    Thin = 100,
     /// the ultralight weight (= 200)
-    UltraLight,
+    UltraLight = 200,
     /// the light weight (= 300)
-    Light,
+    Light = 300,
     /// the semilight weight (= 350; Since: 1.36.7)
-    Semilight,
+    Semilight = 350,
     /// the book weight (= 380; Since: 1.24)
-    Book,
+    Book = 380,
     /// the default weight (= 400)
-    Normal,
+    Normal = 400,
     /// the normal weight (= 500; Since: 1.24)
-    Medium,
+    Medium = 500,
     /// the semibold weight (= 600)
-    Semibold,
+    Semibold = 600,
     /// the bold weight (= 700)
-    Bold,
+    Bold = 700,
     /// the ultrabold weight (= 800)
-    Ultrabold,
+    Ultrabold = 800,
     /// the heavy weight (= 900)
-    Heavy,
+    Heavy = 900,
     /// the ultraheavy weight (= 1000; Since: 1.24)
-    Ultraheavy
+    Ultraheavy = 1000
 }
 
 /// An enumeration specifying capitalization variant of the font.
@@ -401,4 +429,52 @@ pub enum FontMask {
     Size,
     /// the font gravity is specified (Since: 1.16.)
     Gravity
-}
\ No newline at end of file
+}
+
+/// A PangoAlignment describes how to align the lines of a PangoLayout within the available space. If the PangoLayout is set to justify
+/// using pango_layout_set_justify(), this only has effect for partial lines.
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum Alignment {
+    /// Put all available space on the right
+    Left,
+    /// Center the line within the available space
+    Center,
+    /// Put all available space on the left
+    Right
+}
+
+/// A PangoWrapMode describes how to wrap the lines of a PangoLayout to the desired width.
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum WrapMode {
+    /// wrap lines at word boundaries.
+    Word,
+    /// wrap lines at character boundaries.
+    Char,
+    /// wrap lines at word boundaries, but fall back to character boundaries if there is not enough space for a full word.
+    WordChar
+}
+
+/// The PangoEllipsizeMode type describes what sort of (if any) ellipsization should be applied to a line of text. In the ellipsization
+/// process characters are removed from the text in order to make it fit to a given width and replaced with an ellipsis.
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum EllipsizeMode {
+    /// No ellipsization
+    None,
+    /// Omit characters at the start of the text
+    Start,
+    /// Omit characters in the middle of the text
+    Middle,
+    /// Omit characters at the end of the text
+    End
+}
+
+/// A PangoTabAlign specifies where a tab stop appears relative to the text.
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum TabAlign {
+    /// the tab stop appears to the left of the text.
+    Left
+}
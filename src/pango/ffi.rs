@@ -16,8 +16,10 @@
 #![allow(non_camel_case_types)]
 #![allow(dead_code)]
 
-use libc::{c_int, c_uint, c_char, c_double};
-use gtk::ffi::{Gboolean};
+use libc::{c_int, c_uint, c_char, c_double, c_void};
+use gtk::ffi::{Gboolean, C_GtkWidget};
+use glib::ffi::{C_GList, C_GSList};
+use cairo::ffi::cairo_t;
 use pango;
 
 #[repr(C)]
@@ -27,12 +29,32 @@ pub struct C_PangoAttrList;
 #[repr(C)]
 pub struct C_PangoAttrIterator;
 #[repr(C)]
-pub struct C_PangoItem;
+pub struct C_PangoItem {
+    pub offset: c_int,
+    pub length: c_int,
+    pub num_chars: c_int,
+    pub analysis: C_PangoAnalysis
+}
 #[repr(C)]
 pub struct C_PangoFontMap;
 #[repr(C)]
 pub struct C_PangoFontDescription;
 #[repr(C)]
+pub struct C_PangoLayout;
+#[repr(C)]
+pub struct C_PangoLayoutIter;
+#[repr(C)]
+pub struct C_PangoLayoutLine {
+    pub layout: *mut C_PangoLayout,
+    pub start_index: c_int,
+    pub length: c_int,
+    pub runs: *mut C_GSList,
+    // is_paragraph_start: 1 bit, resolved_dir: 3 bits
+    pub bits: c_uint
+}
+#[repr(C)]
+pub struct C_PangoTabArray;
+#[repr(C)]
 pub struct C_PangoLanguage;
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -53,14 +75,44 @@ pub struct C_PangoFontMetrics;
 #[repr(C)]
 pub struct C_PangoFontFamily;
 #[repr(C)]
-pub struct C_PangoAnalysis;
+pub struct C_PangoAnalysis {
+    pub shape_engine: *mut c_void,
+    pub lang_engine: *mut c_void,
+    pub font: *mut C_PangoFont,
+    pub level: u8,
+    pub gravity: u8,
+    pub flags: u8,
+    pub script: u8,
+    pub language: *mut C_PangoLanguage,
+    pub extra_attrs: *mut C_GSList
+}
+// PangoLogAttr is a set of one bit fields packed in a single guint
 #[repr(C)]
-pub struct C_PangoLogAttr;
+#[derive(Clone, Copy)]
+pub struct C_PangoLogAttr {
+    pub bits: c_uint
+}
 #[repr(C)]
 pub struct C_PangoGlyphString;
 #[repr(C)]
 pub struct C_PangoScript;
 #[repr(C)]
+pub struct C_PangoScriptIter;
+/// A color in the format of pango attributes, each channel goes from 0 to 65535.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct C_PangoColor {
+    pub red: u16,
+    pub green: u16,
+    pub blue: u16
+}
+#[repr(C)]
+pub struct C_PangoAttribute {
+    klass: *const c_void,
+    pub start_index: c_uint,
+    pub end_index: c_uint
+}
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct PangoRectangle {
     pub x: c_int,
@@ -78,6 +130,38 @@ extern "C" {
     pub fn pango_item_new                 () -> *mut C_PangoItem;
     pub fn pango_item_split               (item: *mut C_PangoItem, split_index: c_int, split_offset: c_int) -> *mut C_PangoItem;
 
+    //=========================================================================
+    // PangoAttrList                                                     NOT OK
+    //=========================================================================
+    pub fn pango_attr_list_new            () -> *mut C_PangoAttrList;
+    pub fn pango_attr_list_ref            (list: *mut C_PangoAttrList) -> *mut C_PangoAttrList;
+    pub fn pango_attr_list_unref          (list: *mut C_PangoAttrList);
+    pub fn pango_attr_list_copy           (list: *mut C_PangoAttrList) -> *mut C_PangoAttrList;
+    pub fn pango_attr_list_insert         (list: *mut C_PangoAttrList, attr: *mut C_PangoAttribute);
+
+    //=========================================================================
+    // PangoAttribute                                                    NOT OK
+    //=========================================================================
+    pub fn pango_attr_foreground_new      (red: u16, green: u16, blue: u16) -> *mut C_PangoAttribute;
+    pub fn pango_attr_background_new      (red: u16, green: u16, blue: u16) -> *mut C_PangoAttribute;
+
+    //=========================================================================
+    // PangoColor                                                        NOT OK
+    //=========================================================================
+    pub fn pango_color_parse              (color: *mut C_PangoColor, spec: *const c_char) -> Gboolean;
+    pub fn pango_color_to_string          (color: *const C_PangoColor) -> *mut c_char;
+
+    //=========================================================================
+    // PangoLanguage                                                     NOT OK
+    //=========================================================================
+    pub fn pango_language_from_string     (language: *const c_char) -> *mut C_PangoLanguage;
+    pub fn pango_language_to_string       (language: *mut C_PangoLanguage) -> *const c_char;
+    pub fn pango_language_get_default     () -> *mut C_PangoLanguage;
+    pub fn pango_language_get_sample_string(language: *mut C_PangoLanguage) -> *const c_char;
+    pub fn pango_language_matches         (language: *mut C_PangoLanguage, range_list: *const c_char) -> Gboolean;
+    pub fn pango_language_includes_script (language: *mut C_PangoLanguage, script: pango::Script) -> Gboolean;
+    pub fn pango_language_get_scripts     (language: *mut C_PangoLanguage, num_scripts: *mut c_int) -> *const c_int;
+
     //=========================================================================
     // PangoContext                                                      NOT OK
     //=========================================================================
@@ -122,7 +206,7 @@ extern "C" {
     pub fn pango_font_description_set_variant(desc: *mut C_PangoFontDescription, variant: pango::Variant);
     pub fn pango_font_description_get_variant(desc: *const C_PangoFontDescription) -> pango::Variant;
     pub fn pango_font_description_set_weight(desc: *mut C_PangoFontDescription, weight: pango::Weight);
-    pub fn pango_font_description_get_weight(desc: *const C_PangoFontDescription) -> pango::Weight;
+    pub fn pango_font_description_get_weight(desc: *const C_PangoFontDescription) -> c_int;
     pub fn pango_font_description_set_stretch(desc: *mut C_PangoFontDescription, stretch: pango::Stretch);
     pub fn pango_font_description_get_stretch(desc: *const C_PangoFontDescription) -> pango::Stretch;
     pub fn pango_font_description_set_size(desc: *mut C_PangoFontDescription, size: c_int);
@@ -143,6 +227,113 @@ extern "C" {
     pub fn pango_font_description_to_string(desc: *const C_PangoFontDescription) -> *mut c_char;
     pub fn pango_font_description_to_filename(desc: *const C_PangoFontDescription) -> *mut c_char;
 
+    //=========================================================================
+    // PangoLayout                                                       NOT OK
+    //=========================================================================
+    pub fn pango_layout_new                (context: *mut C_PangoContext) -> *mut C_PangoLayout;
+    pub fn pango_layout_copy               (src: *mut C_PangoLayout) -> *mut C_PangoLayout;
+    pub fn pango_layout_get_context        (layout: *mut C_PangoLayout) -> *mut C_PangoContext;
+    pub fn pango_layout_context_changed    (layout: *mut C_PangoLayout);
+    pub fn pango_layout_get_serial         (layout: *mut C_PangoLayout) -> c_uint;
+    pub fn pango_layout_set_text           (layout: *mut C_PangoLayout, text: *const c_char, length: c_int);
+    pub fn pango_layout_get_text           (layout: *mut C_PangoLayout) -> *const c_char;
+    pub fn pango_layout_get_character_count(layout: *mut C_PangoLayout) -> c_int;
+    pub fn pango_layout_set_markup         (layout: *mut C_PangoLayout, markup: *const c_char, length: c_int);
+    pub fn pango_layout_set_attributes     (layout: *mut C_PangoLayout, attrs: *mut C_PangoAttrList);
+    pub fn pango_layout_get_attributes     (layout: *mut C_PangoLayout) -> *mut C_PangoAttrList;
+    pub fn pango_layout_set_font_description(layout: *mut C_PangoLayout, desc: *const C_PangoFontDescription);
+    pub fn pango_layout_get_font_description(layout: *mut C_PangoLayout) -> *const C_PangoFontDescription;
+    pub fn pango_layout_set_width          (layout: *mut C_PangoLayout, width: c_int);
+    pub fn pango_layout_get_width          (layout: *mut C_PangoLayout) -> c_int;
+    pub fn pango_layout_set_height         (layout: *mut C_PangoLayout, height: c_int);
+    pub fn pango_layout_get_height         (layout: *mut C_PangoLayout) -> c_int;
+    pub fn pango_layout_set_wrap           (layout: *mut C_PangoLayout, wrap: pango::WrapMode);
+    pub fn pango_layout_get_wrap           (layout: *mut C_PangoLayout) -> pango::WrapMode;
+    pub fn pango_layout_is_wrapped         (layout: *mut C_PangoLayout) -> Gboolean;
+    pub fn pango_layout_set_ellipsize      (layout: *mut C_PangoLayout, ellipsize: pango::EllipsizeMode);
+    pub fn pango_layout_get_ellipsize      (layout: *mut C_PangoLayout) -> pango::EllipsizeMode;
+    pub fn pango_layout_is_ellipsized      (layout: *mut C_PangoLayout) -> Gboolean;
+    pub fn pango_layout_set_indent         (layout: *mut C_PangoLayout, indent: c_int);
+    pub fn pango_layout_get_indent         (layout: *mut C_PangoLayout) -> c_int;
+    pub fn pango_layout_set_spacing        (layout: *mut C_PangoLayout, spacing: c_int);
+    pub fn pango_layout_get_spacing        (layout: *mut C_PangoLayout) -> c_int;
+    pub fn pango_layout_set_justify        (layout: *mut C_PangoLayout, justify: Gboolean);
+    pub fn pango_layout_get_justify        (layout: *mut C_PangoLayout) -> Gboolean;
+    pub fn pango_layout_set_auto_dir       (layout: *mut C_PangoLayout, auto_dir: Gboolean);
+    pub fn pango_layout_get_auto_dir       (layout: *mut C_PangoLayout) -> Gboolean;
+    pub fn pango_layout_set_alignment      (layout: *mut C_PangoLayout, alignment: pango::Alignment);
+    pub fn pango_layout_get_alignment      (layout: *mut C_PangoLayout) -> pango::Alignment;
+    pub fn pango_layout_set_tabs           (layout: *mut C_PangoLayout, tabs: *mut C_PangoTabArray);
+    pub fn pango_layout_get_tabs           (layout: *mut C_PangoLayout) -> *mut C_PangoTabArray;
+    pub fn pango_layout_set_single_paragraph_mode(layout: *mut C_PangoLayout, setting: Gboolean);
+    pub fn pango_layout_get_single_paragraph_mode(layout: *mut C_PangoLayout) -> Gboolean;
+    pub fn pango_layout_get_unknown_glyphs_count(layout: *mut C_PangoLayout) -> c_int;
+    pub fn pango_layout_index_to_pos       (layout: *mut C_PangoLayout, index_: c_int, pos: *mut PangoRectangle);
+    pub fn pango_layout_get_cursor_pos     (layout: *mut C_PangoLayout, index_: c_int, strong_pos: *mut PangoRectangle,
+        weak_pos: *mut PangoRectangle);
+    pub fn pango_layout_xy_to_index        (layout: *mut C_PangoLayout, x: c_int, y: c_int, index_: *mut c_int,
+        trailing: *mut c_int) -> Gboolean;
+    pub fn pango_layout_get_extents        (layout: *mut C_PangoLayout, ink_rect: *mut PangoRectangle, logical_rect: *mut PangoRectangle);
+    pub fn pango_layout_get_pixel_extents  (layout: *mut C_PangoLayout, ink_rect: *mut PangoRectangle, logical_rect: *mut PangoRectangle);
+    pub fn pango_layout_get_size           (layout: *mut C_PangoLayout, width: *mut c_int, height: *mut c_int);
+    pub fn pango_layout_get_pixel_size     (layout: *mut C_PangoLayout, width: *mut c_int, height: *mut c_int);
+    pub fn pango_layout_get_baseline       (layout: *mut C_PangoLayout) -> c_int;
+    pub fn pango_layout_get_line_count     (layout: *mut C_PangoLayout) -> c_int;
+    pub fn pango_layout_get_line           (layout: *mut C_PangoLayout, line: c_int) -> *mut C_PangoLayoutLine;
+    pub fn pango_layout_get_line_readonly  (layout: *mut C_PangoLayout, line: c_int) -> *mut C_PangoLayoutLine;
+    pub fn pango_layout_get_iter           (layout: *mut C_PangoLayout) -> *mut C_PangoLayoutIter;
+
+    //=========================================================================
+    // PangoLayoutIter                                                   NOT OK
+    //=========================================================================
+    pub fn pango_layout_iter_free          (iter: *mut C_PangoLayoutIter);
+    pub fn pango_layout_iter_next_run      (iter: *mut C_PangoLayoutIter) -> Gboolean;
+    pub fn pango_layout_iter_next_char     (iter: *mut C_PangoLayoutIter) -> Gboolean;
+    pub fn pango_layout_iter_next_cluster  (iter: *mut C_PangoLayoutIter) -> Gboolean;
+    pub fn pango_layout_iter_next_line     (iter: *mut C_PangoLayoutIter) -> Gboolean;
+    pub fn pango_layout_iter_at_last_line  (iter: *mut C_PangoLayoutIter) -> Gboolean;
+    pub fn pango_layout_iter_get_index     (iter: *mut C_PangoLayoutIter) -> c_int;
+    pub fn pango_layout_iter_get_baseline  (iter: *mut C_PangoLayoutIter) -> c_int;
+    pub fn pango_layout_iter_get_line_readonly(iter: *mut C_PangoLayoutIter) -> *mut C_PangoLayoutLine;
+    pub fn pango_layout_iter_get_char_extents(iter: *mut C_PangoLayoutIter, logical_rect: *mut PangoRectangle);
+    pub fn pango_layout_iter_get_cluster_extents(iter: *mut C_PangoLayoutIter, ink_rect: *mut PangoRectangle,
+        logical_rect: *mut PangoRectangle);
+    pub fn pango_layout_iter_get_run_extents(iter: *mut C_PangoLayoutIter, ink_rect: *mut PangoRectangle,
+        logical_rect: *mut PangoRectangle);
+    pub fn pango_layout_iter_get_line_yrange(iter: *mut C_PangoLayoutIter, y0_: *mut c_int, y1_: *mut c_int);
+    pub fn pango_layout_iter_get_line_extents(iter: *mut C_PangoLayoutIter, ink_rect: *mut PangoRectangle,
+        logical_rect: *mut PangoRectangle);
+    pub fn pango_layout_iter_get_layout_extents(iter: *mut C_PangoLayoutIter, ink_rect: *mut PangoRectangle,
+        logical_rect: *mut PangoRectangle);
+
+    //=========================================================================
+    // PangoLayoutLine                                                   NOT OK
+    //=========================================================================
+    pub fn pango_layout_line_ref           (line: *mut C_PangoLayoutLine) -> *mut C_PangoLayoutLine;
+    pub fn pango_layout_line_unref         (line: *mut C_PangoLayoutLine);
+    pub fn pango_layout_line_get_extents   (line: *mut C_PangoLayoutLine, ink_rect: *mut PangoRectangle, logical_rect: *mut PangoRectangle);
+    pub fn pango_layout_line_get_pixel_extents(line: *mut C_PangoLayoutLine, ink_rect: *mut PangoRectangle,
+        logical_rect: *mut PangoRectangle);
+    pub fn pango_layout_line_index_to_x    (line: *mut C_PangoLayoutLine, index_: c_int, trailing: Gboolean, x_pos: *mut c_int);
+    pub fn pango_layout_line_x_to_index    (line: *mut C_PangoLayoutLine, x_pos: c_int, index_: *mut c_int,
+        trailing: *mut c_int) -> Gboolean;
+    pub fn pango_layout_line_get_x_ranges  (line: *mut C_PangoLayoutLine, start_index: c_int, end_index: c_int,
+        ranges: *mut *mut c_int, n_ranges: *mut c_int);
+
+    //=========================================================================
+    // PangoTabArray                                                     NOT OK
+    //=========================================================================
+    pub fn pango_tab_array_new             (initial_size: c_int, positions_in_pixels: Gboolean) -> *mut C_PangoTabArray;
+    pub fn pango_tab_array_copy            (src: *mut C_PangoTabArray) -> *mut C_PangoTabArray;
+    pub fn pango_tab_array_free            (tab_array: *mut C_PangoTabArray);
+    pub fn pango_tab_array_get_size        (tab_array: *mut C_PangoTabArray) -> c_int;
+    pub fn pango_tab_array_resize          (tab_array: *mut C_PangoTabArray, new_size: c_int);
+    pub fn pango_tab_array_set_tab         (tab_array: *mut C_PangoTabArray, tab_index: c_int, alignment: pango::TabAlign,
+        location: c_int);
+    pub fn pango_tab_array_get_tab         (tab_array: *mut C_PangoTabArray, tab_index: c_int, alignment: *mut pango::TabAlign,
+        location: *mut c_int);
+    pub fn pango_tab_array_get_positions_in_pixels(tab_array: *mut C_PangoTabArray) -> Gboolean;
+
     //=========================================================================
     // PangoMatrix                                                       NOT OK
     //=========================================================================
@@ -171,16 +362,23 @@ extern "C" {
     pub fn pango_glyph_string_extents_range(string: *mut C_PangoGlyphString, start: c_int, end: c_int, font: *mut C_PangoFont,
         ink_rect: *mut PangoRectangle, logical_rect: *mut PangoRectangle);
     pub fn pango_glyph_string_get_width    (string: *mut C_PangoGlyphString) -> c_int;
-    pub fn pango_glyph_string_index_to_x   (string: *mut C_PangoGlyphString, text: *mut c_char, length: c_int, analysis: *mut C_PangoAnalysis,
+    pub fn pango_glyph_string_index_to_x   (string: *mut C_PangoGlyphString, text: *const c_char, length: c_int, analysis: *const C_PangoAnalysis,
         index_: c_int, trailing: Gboolean, x_pos: *mut c_int);
-    pub fn pango_glyph_string_x_to_index   (string: *mut C_PangoGlyphString, text: *mut c_char, length: c_int, analysis: *mut C_PangoAnalysis,
+    pub fn pango_glyph_string_x_to_index   (string: *mut C_PangoGlyphString, text: *const c_char, length: c_int, analysis: *const C_PangoAnalysis,
         x_pos: c_int, index_: *mut c_int, trailing: *mut c_int);
-    pub fn pango_glyph_string_get_logical_widths(glyphs: *mut C_PangoGlyphString, text: *mut c_char, length: c_int, embedding_level: c_int,
+    pub fn pango_glyph_string_get_logical_widths(glyphs: *mut C_PangoGlyphString, text: *const c_char, length: c_int, embedding_level: c_int,
         logical_widths: *mut c_int);
 
     //=========================================================================
     // PangoScript                                                       NOT OK
     //=========================================================================
+    pub fn pango_script_for_unichar        (ch: u32) -> c_int;
+    pub fn pango_script_get_sample_language(script: pango::Script) -> *mut C_PangoLanguage;
+    pub fn pango_script_iter_new           (text: *const c_char, length: c_int) -> *mut C_PangoScriptIter;
+    pub fn pango_script_iter_get_range     (iter: *mut C_PangoScriptIter, start: *mut *const c_char, end: *mut *const c_char,
+        script: *mut c_int);
+    pub fn pango_script_iter_next          (iter: *mut C_PangoScriptIter) -> Gboolean;
+    pub fn pango_script_iter_free          (iter: *mut C_PangoScriptIter);
     pub fn pango_gravity_get_for_script    (script: pango::Script, base_gravity: pango::Gravity, hint: pango::GravityHint) -> pango::Gravity;
     pub fn pango_gravity_get_for_script_and_width(script: pango::Script, wide: Gboolean, base_gravity: pango::Gravity,
         hint: pango::GravityHint) -> pango::Gravity;
@@ -201,11 +399,14 @@ extern "C" {
     //=========================================================================
     pub fn pango_bidi_type_for_unichar      (ch: u32) -> pango::BidiType;
 
-    //pub fn pango_itemize                  (context: *mut C_PangoContext, text: *const c_char, start_index: c_int, length: c_int,
-    //    attrs: *mut C_PangoAttrList, cached_iter: *mut PangoAttrIterator) -> *mut GList;
-    //pub fn pango_itemize_with_base_dir    (context: *mut C_PangoContext, direction: pango::Direction, text: *const c_char, start_index: c_int, length: c_int,
-    //    attrs: *mut C_PangoAttrList, cached_iter: *mut PangoAttrIterator) -> *mut GList;
-    //pub fn pango_reorder_items            (logical_items: *mut GList) -> *mut GList;
+    //=========================================================================
+    // Rendering                                                         NOT OK
+    //=========================================================================
+    pub fn pango_itemize                  (context: *mut C_PangoContext, text: *const c_char, start_index: c_int, length: c_int,
+        attrs: *mut C_PangoAttrList, cached_iter: *mut C_PangoAttrIterator) -> *mut C_GList;
+    pub fn pango_itemize_with_base_dir    (context: *mut C_PangoContext, direction: pango::Direction, text: *const c_char, start_index: c_int, length: c_int,
+        attrs: *mut C_PangoAttrList, cached_iter: *mut C_PangoAttrIterator) -> *mut C_GList;
+    pub fn pango_reorder_items            (logical_items: *mut C_GList) -> *mut C_GList;
     pub fn pango_break                    (text: *const c_char, length: c_int, analysis: *mut C_PangoAnalysis, attrs: *mut C_PangoLogAttr,
         attrs_len: c_int);
     pub fn pango_get_log_attrs            (text: *const c_char, length: c_int, level: c_int, language: *mut C_PangoLanguage, log_attrs: *mut C_PangoLogAttr,
@@ -217,4 +418,27 @@ extern "C" {
     pub fn pango_shape                    (text: *const c_char, length: c_int, analysis: *const C_PangoAnalysis, glyphs: *mut C_PangoGlyphString);
     pub fn pango_shape_full               (item_text: *const c_char, item_length: c_int, paragraph_text: *const c_char, paragraph_length: c_int,
         analysis: *const C_PangoAnalysis, glyphs: *mut C_PangoGlyphString);
-}
\ No newline at end of file
+
+    //=========================================================================
+    // PangoCairo                                                        NOT OK
+    //=========================================================================
+    pub fn pango_cairo_create_context     (cr: *mut cairo_t) -> *mut C_PangoContext;
+    pub fn pango_cairo_update_context     (cr: *mut cairo_t, context: *mut C_PangoContext);
+    pub fn pango_cairo_create_layout      (cr: *mut cairo_t) -> *mut C_PangoLayout;
+    pub fn pango_cairo_update_layout      (cr: *mut cairo_t, layout: *mut C_PangoLayout);
+    pub fn pango_cairo_show_layout        (cr: *mut cairo_t, layout: *mut C_PangoLayout);
+    pub fn pango_cairo_show_layout_line   (cr: *mut cairo_t, line: *mut C_PangoLayoutLine);
+    pub fn pango_cairo_show_glyph_string  (cr: *mut cairo_t, font: *mut C_PangoFont, glyphs: *mut C_PangoGlyphString);
+    pub fn pango_cairo_layout_path        (cr: *mut cairo_t, layout: *mut C_PangoLayout);
+    pub fn pango_cairo_layout_line_path   (cr: *mut cairo_t, line: *mut C_PangoLayoutLine);
+    pub fn pango_cairo_context_set_resolution(context: *mut C_PangoContext, dpi: c_double);
+    pub fn pango_cairo_context_get_resolution(context: *mut C_PangoContext) -> c_double;
+
+    //=========================================================================
+    // GtkWidget pango helpers                                           NOT OK
+    //=========================================================================
+    pub fn gtk_widget_create_pango_context (widget: *mut C_GtkWidget) -> *mut C_PangoContext;
+    pub fn gtk_widget_get_pango_context    (widget: *mut C_GtkWidget) -> *mut C_PangoContext;
+    pub fn gtk_widget_create_pango_layout  (widget: *mut C_GtkWidget, text: *const c_char) -> *mut C_PangoLayout;
+    pub fn gtk_widget_override_font        (widget: *mut C_GtkWidget, font_desc: *const C_PangoFontDescription);
+}
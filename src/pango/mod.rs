@@ -23,7 +23,68 @@ pub use self::widgets::{
     Item,
     Rectangle,
     Matrix,
-    GlyphString
+    GlyphString,
+    Context,
+    AttrList,
+    Language,
+    Font,
+    Analysis,
+    LogAttr,
+    ScriptIter,
+    FontDescription,
+    TabArray,
+    Layout,
+    LayoutLine,
+    LayoutIter,
+    LayoutCache
+};
+
+pub use self::ffi::C_PangoColor as Color;
+
+pub use self::color::{
+    color_parse,
+    color_to_string
+};
+
+pub use self::rendering::{
+    itemize,
+    itemize_with_base_dir,
+    shape,
+    break_,
+    get_log_attrs,
+    find_paragraph_boundary
+};
+
+pub use self::bidi::{
+    find_base_dir,
+    unichar_direction,
+    bidi_type_for_unichar
+};
+
+pub use self::scripts::{
+    script_for_unichar,
+    script_get_sample_language
+};
+
+pub use self::vertical::{
+    gravity_to_rotation,
+    gravity_get_for_matrix,
+    gravity_get_for_script,
+    gravity_get_for_script_and_width
+};
+
+pub use self::cairo_rendering::{
+    cairo_create_context,
+    cairo_update_context,
+    cairo_context_set_resolution,
+    cairo_context_get_resolution,
+    cairo_create_layout,
+    cairo_update_layout,
+    cairo_show_layout,
+    cairo_show_layout_line,
+    cairo_show_glyph_string,
+    cairo_layout_path,
+    cairo_layout_line_path
 };
 
 pub use self::enums::{
@@ -36,11 +97,24 @@ pub use self::enums::{
     Weight,
     Variant,
     Stretch,
-    FontMask
+    FontMask,
+    Alignment,
+    WrapMode,
+    EllipsizeMode,
+    TabAlign
 };
 
+/// The scale between dimensions used for Pango distances and device units.
+pub const SCALE: i32 = 1024;
+
 pub mod widgets;
 pub mod enums;
+mod rendering;
+mod bidi;
+mod scripts;
+mod vertical;
+mod cairo_rendering;
+mod color;
 
 #[doc(hidden)]
 pub mod ffi;
@@ -0,0 +1,103 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Rendering — Functions to run the rendering pipeline
+
+use pango::{self, ffi};
+use libc::{c_char, c_int};
+
+fn items_from_list(list: *mut ::glib::ffi::C_GList) -> Vec<pango::Item> {
+    let mut ret = Vec::new();
+    let mut tmp = list;
+
+    unsafe {
+        while !tmp.is_null() {
+            ret.push(pango::Item::wrap_pointer((*tmp).data as *mut ffi::C_PangoItem));
+            tmp = (*tmp).next;
+        }
+        if !list.is_null() {
+            ::glib::ffi::g_list_free(list);
+        }
+    }
+    ret
+}
+
+/// Breaks a piece of text into segments with consistent directional level and shaping engine.
+/// Each byte of `text` will be contained in exactly one of the items returned. The items are
+/// returned in logical order, their offsets are byte offsets into `text`.
+pub fn itemize(context: &pango::Context, text: &str, attrs: &pango::AttrList) -> Vec<pango::Item> {
+    let tmp = unsafe {
+        ffi::pango_itemize(context.unwrap_pointer(), text.as_ptr() as *const c_char, 0, text.len() as c_int,
+            attrs.unwrap_pointer(), ::std::ptr::null_mut())
+    };
+
+    items_from_list(tmp)
+}
+
+/// Like `itemize()`, but the base direction to use when computing bidirectional levels is
+/// specified explicitly rather than gotten from the context.
+pub fn itemize_with_base_dir(context: &pango::Context, base_dir: pango::Direction, text: &str,
+    attrs: &pango::AttrList) -> Vec<pango::Item> {
+    let tmp = unsafe {
+        ffi::pango_itemize_with_base_dir(context.unwrap_pointer(), base_dir, text.as_ptr() as *const c_char, 0,
+            text.len() as c_int, attrs.unwrap_pointer(), ::std::ptr::null_mut())
+    };
+
+    items_from_list(tmp)
+}
+
+/// Given a segment of text and the corresponding Analysis structure returned from `itemize()`,
+/// convert the characters into glyphs. `text` should be the text of a single item.
+pub fn shape(text: &str, analysis: &pango::Analysis, glyphs: &mut pango::GlyphString) {
+    unsafe {
+        ffi::pango_shape(text.as_ptr() as *const c_char, text.len() as c_int, analysis, glyphs.unwrap_pointer())
+    }
+}
+
+/// Determines possible line, word, and character breaks for a string of Unicode text with a
+/// single analysis. The returned vector holds one more attribute than there are characters in
+/// `text`, the last one being the position at the end of the text.
+pub fn break_(text: &str, analysis: &pango::Analysis) -> Vec<pango::LogAttr> {
+    let mut attrs: Vec<pango::LogAttr> = vec![Default::default(); text.chars().count() + 1];
+
+    unsafe {
+        ffi::pango_break(text.as_ptr() as *const c_char, text.len() as c_int,
+            analysis as *const pango::Analysis as *mut ffi::C_PangoAnalysis, attrs.as_mut_ptr(), attrs.len() as c_int)
+    };
+    attrs
+}
+
+/// Computes a LogAttr for each character in `text`, plus one for the end of the text. `text`
+/// should be a whole paragraph, `level` its embedding level (0 for a left-to-right paragraph,
+/// 1 for a right-to-left one).
+pub fn get_log_attrs(text: &str, level: i32, language: &pango::Language) -> Vec<pango::LogAttr> {
+    let mut attrs: Vec<pango::LogAttr> = vec![Default::default(); text.chars().count() + 1];
+
+    unsafe {
+        ffi::pango_get_log_attrs(text.as_ptr() as *const c_char, text.len() as c_int, level as c_int,
+            language.unwrap_pointer(), attrs.as_mut_ptr(), attrs.len() as c_int)
+    };
+    attrs
+}
+
+/// Locates a paragraph boundary in `text`. `paragraph_delimiter_index` is set to the byte index
+/// of the delimiter (or the length of the text if none was found) and `next_paragraph_start` to
+/// the byte index of the start of the next paragraph.
+pub fn find_paragraph_boundary(text: &str, paragraph_delimiter_index: &mut i32, next_paragraph_start: &mut i32) {
+    unsafe {
+        ffi::pango_find_paragraph_boundary(text.as_ptr() as *const c_char, text.len() as c_int,
+            paragraph_delimiter_index, next_paragraph_start)
+    }
+}
@@ -0,0 +1,38 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Scripts and Languages — Identifying writing systems and languages
+
+use pango::{self, ffi};
+use glib::translate::from_glib;
+
+/// Looks up the Script for a particular character (as defined by Unicode Standard Annex #24).
+/// No check is made for `ch` being a valid Unicode character; if you pass in invalid character,
+/// the result is undefined. Scripts unknown to this binding are returned as `Script::Unknown`.
+pub fn script_for_unichar(ch: char) -> pango::Script {
+    from_glib(unsafe { ffi::pango_script_for_unichar(ch as u32) })
+}
+
+/// Given a script, finds a language tag that is reasonably representative of that script. This
+/// will usually be the most widely spoken or used language written in that script.
+pub fn script_get_sample_language(script: pango::Script) -> Option<pango::Language> {
+    let tmp = unsafe { ffi::pango_script_get_sample_language(script) };
+
+    if tmp.is_null() {
+        None
+    } else {
+        Some(pango::Language::wrap_pointer(tmp))
+    }
+}
@@ -0,0 +1,43 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Vertical Text — Laying text out in vertical directions
+
+use pango::{self, ffi};
+use glib::to_gboolean;
+
+/// Converts a Gravity value to its natural rotation in radians. `gravity` should not be `Auto`.
+pub fn gravity_to_rotation(gravity: pango::Gravity) -> f64 {
+    unsafe { ffi::pango_gravity_to_rotation(gravity) }
+}
+
+/// Finds the gravity that best matches the rotation component in a Matrix.
+pub fn gravity_get_for_matrix(matrix: &pango::Matrix) -> pango::Gravity {
+    unsafe { ffi::pango_gravity_get_for_matrix(matrix) }
+}
+
+/// Based on the script, base gravity, and hint, returns actual gravity to use in laying out a
+/// single Item.
+pub fn gravity_get_for_script(script: pango::Script, base_gravity: pango::Gravity,
+    hint: pango::GravityHint) -> pango::Gravity {
+    unsafe { ffi::pango_gravity_get_for_script(script, base_gravity, hint) }
+}
+
+/// Like `gravity_get_for_script()`, but also takes into account whether the characters are
+/// wide (East Asian Width) so that upright glyphs are used for them in vertical text.
+pub fn gravity_get_for_script_and_width(script: pango::Script, wide: bool, base_gravity: pango::Gravity,
+    hint: pango::GravityHint) -> pango::Gravity {
+    unsafe { ffi::pango_gravity_get_for_script_and_width(script, to_gboolean(wide), base_gravity, hint) }
+}
@@ -0,0 +1,54 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::{self, ffi};
+use glib::translate::from_glib;
+
+/// The PangoAnalysis structure stores information about the properties of a segment of text. It is
+/// obtained from an `Item` returned by `pango::itemize()`.
+pub type Analysis = ffi::C_PangoAnalysis;
+
+impl Analysis {
+    /// The bidirectional level for this segment, even levels are left-to-right and odd levels are
+    /// right-to-left.
+    pub fn get_level(&self) -> u8 {
+        self.level
+    }
+
+    pub fn get_gravity(&self) -> pango::Gravity {
+        unsafe { ::std::mem::transmute(self.gravity as i32) }
+    }
+
+    pub fn get_script(&self) -> pango::Script {
+        from_glib(self.script as i32)
+    }
+
+    pub fn get_font(&self) -> Option<pango::Font> {
+        if self.font.is_null() {
+            None
+        } else {
+            unsafe { ::glib::ffi::g_object_ref(self.font as *mut ::glib::ffi::C_GObject) };
+            Some(pango::Font::wrap_pointer(self.font))
+        }
+    }
+
+    pub fn get_language(&self) -> Option<pango::Language> {
+        if self.language.is_null() {
+            None
+        } else {
+            Some(pango::Language::wrap_pointer(self.language))
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use libc::c_uint;
+use pango::{self, ffi};
+
+/// The PangoAttrList structure represents a list of attributes that apply to a section of text.
+pub struct AttrList {
+    pointer: *mut ffi::C_PangoAttrList
+}
+
+impl AttrList {
+    pub fn new() -> Option<AttrList> {
+        let tmp = unsafe { ffi::pango_attr_list_new() };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(AttrList {
+                pointer: tmp
+            })
+        }
+    }
+
+    pub fn copy(&self) -> Option<AttrList> {
+        let tmp = unsafe { ffi::pango_attr_list_copy(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(AttrList {
+                pointer: tmp
+            })
+        }
+    }
+
+    /// Sets the foreground color of the text between the byte indexes `start_index` (included)
+    /// and `end_index` (excluded).
+    pub fn insert_foreground(&self, color: &pango::Color, start_index: u32, end_index: u32) {
+        unsafe {
+            let attr = ffi::pango_attr_foreground_new(color.red, color.green, color.blue);
+
+            (*attr).start_index = start_index as c_uint;
+            (*attr).end_index = end_index as c_uint;
+            ffi::pango_attr_list_insert(self.pointer, attr)
+        }
+    }
+
+    /// Sets the background color of the text between the byte indexes `start_index` (included)
+    /// and `end_index` (excluded).
+    pub fn insert_background(&self, color: &pango::Color, start_index: u32, end_index: u32) {
+        unsafe {
+            let attr = ffi::pango_attr_background_new(color.red, color.green, color.blue);
+
+            (*attr).start_index = start_index as c_uint;
+            (*attr).end_index = end_index as c_uint;
+            ffi::pango_attr_list_insert(self.pointer, attr)
+        }
+    }
+
+    /// Takes ownership of a reference to a PangoAttrList.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoAttrList) -> AttrList {
+        AttrList {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoAttrList {
+        self.pointer
+    }
+}
+
+impl Clone for AttrList {
+    fn clone(&self) -> AttrList {
+        AttrList {
+            pointer: unsafe { ffi::pango_attr_list_ref(self.pointer) }
+        }
+    }
+}
+
+impl Drop for AttrList {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::pango_attr_list_unref(self.pointer) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
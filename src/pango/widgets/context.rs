@@ -0,0 +1,152 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::{self, ffi};
+
+/// The PangoContext structure stores global information used to control the itemization process.
+pub struct Context {
+    pointer: *mut ffi::C_PangoContext
+}
+
+impl Context {
+    /// Creates a new PangoContext initialized to default values. The context has no font map, use a
+    /// context created by `pango::cairo_create_context()` or set one before itemizing text with it.
+    pub fn new() -> Option<Context> {
+        let tmp = unsafe { ffi::pango_context_new() };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Context {
+                pointer: tmp
+            })
+        }
+    }
+
+    pub fn changed(&self) {
+        unsafe { ffi::pango_context_changed(self.pointer) }
+    }
+
+    pub fn get_serial(&self) -> u32 {
+        unsafe { ffi::pango_context_get_serial(self.pointer) }
+    }
+
+    pub fn get_language(&self) -> Option<pango::Language> {
+        let tmp = unsafe { ffi::pango_context_get_language(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::Language::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn set_language(&self, language: &pango::Language) {
+        unsafe { ffi::pango_context_set_language(self.pointer, language.unwrap_pointer()) }
+    }
+
+    pub fn get_base_dir(&self) -> pango::Direction {
+        unsafe { ffi::pango_context_get_base_dir(self.pointer) }
+    }
+
+    pub fn set_base_dir(&self, direction: pango::Direction) {
+        unsafe { ffi::pango_context_set_base_dir(self.pointer, direction) }
+    }
+
+    /// Sets the base gravity for the context. The base gravity is used in laying vertical text
+    /// out. With `Gravity::Auto` the gravity is resolved from the context matrix.
+    pub fn set_base_gravity(&self, gravity: pango::Gravity) {
+        unsafe { ffi::pango_context_set_base_gravity(self.pointer, gravity) }
+    }
+
+    pub fn get_base_gravity(&self) -> pango::Gravity {
+        unsafe { ffi::pango_context_get_base_gravity(self.pointer) }
+    }
+
+    /// Retrieves the gravity for the context. This is similar to `get_base_gravity`, except for
+    /// when the base gravity is `Gravity::Auto` for which the gravity derived from the context
+    /// matrix is returned.
+    pub fn get_gravity(&self) -> pango::Gravity {
+        unsafe { ffi::pango_context_get_gravity(self.pointer) }
+    }
+
+    /// Sets the gravity hint for the context. The gravity hint is used in laying vertical text
+    /// out, and is only relevant if gravity of the context as returned by `get_gravity` is set to
+    /// `Gravity::East` or `Gravity::West`.
+    pub fn set_gravity_hint(&self, hint: pango::GravityHint) {
+        unsafe { ffi::pango_context_set_gravity_hint(self.pointer, hint) }
+    }
+
+    pub fn get_gravity_hint(&self) -> pango::GravityHint {
+        unsafe { ffi::pango_context_get_gravity_hint(self.pointer) }
+    }
+
+    /// Sets the transformation matrix that will be applied when rendering with this context, None
+    /// resets it to the identity. The matrix is copied.
+    ///
+    /// A context created by `pango::cairo_create_context` gets its matrix from the current
+    /// transformation of the Cairo context, and `pango::cairo_update_context` overwrites any matrix
+    /// set here. To rotate text drawn with Cairo, transform the Cairo context instead.
+    pub fn set_matrix(&self, matrix: Option<&pango::Matrix>) {
+        let tmp = match matrix {
+            Some(m) => m as *const pango::Matrix,
+            None => ::std::ptr::null()
+        };
+
+        unsafe { ffi::pango_context_set_matrix(self.pointer, tmp) }
+    }
+
+    /// Gets the transformation matrix that will be applied when rendering with this context, None
+    /// means the identity.
+    pub fn get_matrix(&self) -> Option<pango::Matrix> {
+        let tmp = unsafe { ffi::pango_context_get_matrix(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(unsafe { *tmp })
+        }
+    }
+
+    /// Takes ownership of a reference to a PangoContext.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoContext) -> Context {
+        Context {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoContext {
+        self.pointer
+    }
+}
+
+impl Clone for Context {
+    fn clone(&self) -> Context {
+        unsafe { ::glib::ffi::g_object_ref(self.pointer as *mut ::glib::ffi::C_GObject) };
+
+        Context {
+            pointer: self.pointer
+        }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::ffi;
+
+/// The PangoFont structure is used to represent a font in a rendering-system-independent matter.
+pub struct Font {
+    pointer: *mut ffi::C_PangoFont
+}
+
+impl Font {
+    /// Takes ownership of a reference to a PangoFont.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoFont) -> Font {
+        Font {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoFont {
+        self.pointer
+    }
+}
+
+impl Clone for Font {
+    fn clone(&self) -> Font {
+        unsafe { ::glib::ffi::g_object_ref(self.pointer as *mut ::glib::ffi::C_GObject) };
+
+        Font {
+            pointer: self.pointer
+        }
+    }
+}
+
+impl Drop for Font {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::{self, ffi};
+use glib::{to_bool, to_gboolean};
+use glib::translate::{FromGlibPtr, ToGlibPtr};
+use libc::{c_double, c_int};
+
+/// The PangoFontDescription structure represents the description of an ideal font. These
+/// structures are used both to list what fonts are available on the system and also for
+/// specifying the characteristics of a font to load.
+pub struct FontDescription {
+    pointer: *mut ffi::C_PangoFontDescription
+}
+
+impl FontDescription {
+    pub fn new() -> Option<FontDescription> {
+        let tmp = unsafe { ffi::pango_font_description_new() };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FontDescription {
+                pointer: tmp
+            })
+        }
+    }
+
+    /// Creates a new font description from a string representation in the form
+    /// "[FAMILY-LIST] [STYLE-OPTIONS] [SIZE]", for example "Sans Bold 12".
+    pub fn from_string(str_: &str) -> Option<FontDescription> {
+        let tmp = unsafe { ffi::pango_font_description_from_string(str_.borrow_to_glib().0) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FontDescription {
+                pointer: tmp
+            })
+        }
+    }
+
+    pub fn to_string(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::take(
+                ffi::pango_font_description_to_string(self.pointer))
+        }
+    }
+
+    pub fn to_filename(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::take(
+                ffi::pango_font_description_to_filename(self.pointer))
+        }
+    }
+
+    pub fn hash(&self) -> u32 {
+        unsafe { ffi::pango_font_description_hash(self.pointer) }
+    }
+
+    pub fn set_family(&self, family: &str) {
+        unsafe { ffi::pango_font_description_set_family(self.pointer, family.borrow_to_glib().0) }
+    }
+
+    pub fn get_family(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::borrow(
+                ffi::pango_font_description_get_family(self.pointer))
+        }
+    }
+
+    pub fn set_style(&self, style: pango::Style) {
+        unsafe { ffi::pango_font_description_set_style(self.pointer, style) }
+    }
+
+    pub fn get_style(&self) -> pango::Style {
+        unsafe { ffi::pango_font_description_get_style(self.pointer) }
+    }
+
+    pub fn set_variant(&self, variant: pango::Variant) {
+        unsafe { ffi::pango_font_description_set_variant(self.pointer, variant) }
+    }
+
+    pub fn get_variant(&self) -> pango::Variant {
+        unsafe { ffi::pango_font_description_get_variant(self.pointer) }
+    }
+
+    pub fn set_weight(&self, weight: pango::Weight) {
+        unsafe { ffi::pango_font_description_set_weight(self.pointer, weight) }
+    }
+
+    /// Gets the weight, from 100 to 1000. It isn't necessarily one of the predefined `Weight`
+    /// values, compare it with `pango::Weight::Bold as i32` for example.
+    pub fn get_weight(&self) -> i32 {
+        unsafe { ffi::pango_font_description_get_weight(self.pointer) }
+    }
+
+    pub fn set_stretch(&self, stretch: pango::Stretch) {
+        unsafe { ffi::pango_font_description_set_stretch(self.pointer, stretch) }
+    }
+
+    pub fn get_stretch(&self) -> pango::Stretch {
+        unsafe { ffi::pango_font_description_get_stretch(self.pointer) }
+    }
+
+    /// Sets the size field of a font description in points, scaled by `pango::SCALE`.
+    pub fn set_size(&self, size: i32) {
+        unsafe { ffi::pango_font_description_set_size(self.pointer, size as c_int) }
+    }
+
+    pub fn get_size(&self) -> i32 {
+        unsafe { ffi::pango_font_description_get_size(self.pointer) }
+    }
+
+    /// Sets the size field of a font description, in device units, scaled by `pango::SCALE`.
+    pub fn set_absolute_size(&self, size: f64) {
+        unsafe { ffi::pango_font_description_set_absolute_size(self.pointer, size as c_double) }
+    }
+
+    pub fn get_size_is_absolute(&self) -> bool {
+        unsafe { to_bool(ffi::pango_font_description_get_size_is_absolute(self.pointer)) }
+    }
+
+    pub fn set_gravity(&self, gravity: pango::Gravity) {
+        unsafe { ffi::pango_font_description_set_gravity(self.pointer, gravity) }
+    }
+
+    pub fn get_gravity(&self) -> pango::Gravity {
+        unsafe { ffi::pango_font_description_get_gravity(self.pointer) }
+    }
+
+    /// Merges the fields that are set in `desc_to_merge` into the fields in this description. If
+    /// `replace_existing` is false, only fields not already set are affected.
+    pub fn merge(&self, desc_to_merge: &FontDescription, replace_existing: bool) {
+        unsafe {
+            ffi::pango_font_description_merge(self.pointer, desc_to_merge.pointer, to_gboolean(replace_existing))
+        }
+    }
+
+    /// Takes ownership of a PangoFontDescription.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoFontDescription) -> FontDescription {
+        FontDescription {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoFontDescription {
+        self.pointer
+    }
+}
+
+impl Clone for FontDescription {
+    fn clone(&self) -> FontDescription {
+        FontDescription {
+            pointer: unsafe { ffi::pango_font_description_copy(self.pointer) }
+        }
+    }
+}
+
+impl PartialEq for FontDescription {
+    fn eq(&self, other: &FontDescription) -> bool {
+        unsafe { to_bool(ffi::pango_font_description_equal(self.pointer, other.pointer)) }
+    }
+}
+
+impl Eq for FontDescription {}
+
+impl Drop for FontDescription {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::pango_font_description_free(self.pointer) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
@@ -13,8 +13,9 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
 
-use pango::ffi;
-use libc::c_int;
+use pango::{self, ffi};
+use glib::to_gboolean;
+use libc::{c_char, c_int};
 
 /// The PangoGlyphString structure is used to store strings of glyphs with geometry and visual
 /// attribute information. The storage for the glyph information is owned by the structure which
@@ -52,6 +53,68 @@ impl GlyphString {
     pub fn set_size(&self, new_len: i32) {
         unsafe { ffi::pango_glyph_string_set_size(self.pointer, new_len as c_int) }
     }
+
+    /// Compute the logical and ink extents of a glyph string, in Pango units.
+    pub fn extents(&self, font: &pango::Font, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_glyph_string_extents(self.pointer, font.unwrap_pointer(), ink_rect, logical_rect) }
+    }
+
+    /// Computes the extents of a sub-portion of a glyph string, `start` and `end` are glyph
+    /// indices.
+    pub fn extents_range(&self, start: i32, end: i32, font: &pango::Font, ink_rect: &mut pango::Rectangle,
+        logical_rect: &mut pango::Rectangle) {
+        unsafe {
+            ffi::pango_glyph_string_extents_range(self.pointer, start as c_int, end as c_int, font.unwrap_pointer(),
+                ink_rect, logical_rect)
+        }
+    }
+
+    /// Computes the logical width of the glyph string, in Pango units.
+    pub fn get_width(&self) -> i32 {
+        unsafe { ffi::pango_glyph_string_get_width(self.pointer) }
+    }
+
+    /// Converts from a byte index within `text` to the x position, in Pango units. `text` and
+    /// `analysis` must be the ones the glyph string was shaped from.
+    pub fn index_to_x(&self, text: &str, analysis: &pango::Analysis, index_: i32, trailing: bool) -> i32 {
+        let mut x_pos = 0;
+
+        unsafe {
+            ffi::pango_glyph_string_index_to_x(self.pointer, text.as_ptr() as *const c_char, text.len() as c_int,
+                analysis, index_ as c_int, to_gboolean(trailing), &mut x_pos)
+        };
+        x_pos
+    }
+
+    /// Converts from an x position, in Pango units, to a byte index within `text`. `trailing`
+    /// is set to whether the position was in the trailing half of the character.
+    pub fn x_to_index(&self, text: &str, analysis: &pango::Analysis, x_pos: i32, trailing: &mut bool) -> i32 {
+        let mut index_ = 0;
+        let mut c_trailing = 0;
+
+        unsafe {
+            ffi::pango_glyph_string_x_to_index(self.pointer, text.as_ptr() as *const c_char, text.len() as c_int,
+                analysis, x_pos as c_int, &mut index_, &mut c_trailing)
+        };
+        *trailing = c_trailing != 0;
+        index_
+    }
+
+    /// Returns the logical width of each character of `text`, in Pango units. The width of a
+    /// cluster is divided equally among its characters.
+    pub fn get_logical_widths(&self, text: &str, embedding_level: i32) -> Vec<i32> {
+        let mut widths = vec![0; text.chars().count()];
+
+        unsafe {
+            ffi::pango_glyph_string_get_logical_widths(self.pointer, text.as_ptr() as *const c_char, text.len() as c_int,
+                embedding_level as c_int, widths.as_mut_ptr())
+        };
+        widths
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoGlyphString {
+        self.pointer
+    }
 }
 
 impl Drop for GlyphString {
@@ -13,7 +13,7 @@
 // You should have received a copy of the GNU Lesser General Public License
 // along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
 
-use pango::ffi;
+use pango::{self, ffi};
 use libc::c_int;
 
 /// The PangoItem structure stores information about a segment of text.
@@ -58,6 +58,37 @@ impl Item {
             })
         }
     }
+
+    /// Byte offset of the start of this item in the text.
+    pub fn get_offset(&self) -> i32 {
+        unsafe { (*self.pointer).offset }
+    }
+
+    /// Length of this item in bytes.
+    pub fn get_length(&self) -> i32 {
+        unsafe { (*self.pointer).length }
+    }
+
+    /// Number of Unicode characters in the item.
+    pub fn get_num_chars(&self) -> i32 {
+        unsafe { (*self.pointer).num_chars }
+    }
+
+    /// Analysis results for the item.
+    pub fn get_analysis(&self) -> &pango::Analysis {
+        unsafe { &(*self.pointer).analysis }
+    }
+
+    /// Takes ownership of a PangoItem.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoItem) -> Item {
+        Item {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoItem {
+        self.pointer
+    }
 }
 
 impl Drop for Item {
@@ -0,0 +1,115 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::{self, ffi};
+use glib::to_bool;
+use glib::translate::{from_glib, FromGlibPtr, ToGlibPtr};
+use libc::c_int;
+
+/// The PangoLanguage structure is used to represent a language. PangoLanguage pointers are owned
+/// by Pango and live as long as the program, so a Language can be freely copied.
+#[derive(Clone, Copy)]
+pub struct Language {
+    pointer: *mut ffi::C_PangoLanguage
+}
+
+impl Language {
+    /// Take a RFC-3066 format language tag as a string and convert it to a Language that can be
+    /// efficiently copied and compared with other language tags.
+    pub fn from_string(language: &str) -> Option<Language> {
+        let tmp = unsafe { ffi::pango_language_from_string(language.borrow_to_glib().0) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Language {
+                pointer: tmp
+            })
+        }
+    }
+
+    /// Returns the Language for the current locale of the process.
+    pub fn get_default() -> Option<Language> {
+        let tmp = unsafe { ffi::pango_language_get_default() };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Language {
+                pointer: tmp
+            })
+        }
+    }
+
+    pub fn to_string(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::borrow(
+                ffi::pango_language_to_string(self.pointer))
+        }
+    }
+
+    /// Get a string that is representative of the characters needed to render a particular
+    /// language, such as "The quick brown fox jumps over the lazy dog." for English.
+    pub fn get_sample_string(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::borrow(
+                ffi::pango_language_get_sample_string(self.pointer))
+        }
+    }
+
+    /// Checks if a language tag matches one of the elements in a list of language ranges. A
+    /// language tag is considered to match a range in the list if the range is '*', the range is
+    /// exactly the tag, or the range is a prefix of the tag, and the character after it in the
+    /// tag is '-'.
+    pub fn matches(&self, range_list: &str) -> bool {
+        unsafe { to_bool(ffi::pango_language_matches(self.pointer, range_list.borrow_to_glib().0)) }
+    }
+
+    /// Determines if `script` is one of the scripts used to write this language.
+    pub fn includes_script(&self, script: pango::Script) -> bool {
+        unsafe { to_bool(ffi::pango_language_includes_script(self.pointer, script)) }
+    }
+
+    /// Determines the scripts used to write this language. If nothing is known about the
+    /// language an empty vector is returned.
+    pub fn get_scripts(&self) -> Vec<pango::Script> {
+        let mut num_scripts : c_int = 0;
+        let tmp = unsafe { ffi::pango_language_get_scripts(self.pointer, &mut num_scripts) };
+        let mut ret = Vec::with_capacity(num_scripts as usize);
+
+        if !tmp.is_null() {
+            for i in 0..num_scripts {
+                ret.push(from_glib(unsafe { *tmp.offset(i as isize) }));
+            }
+        }
+        ret
+    }
+
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoLanguage) -> Language {
+        Language {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoLanguage {
+        self.pointer
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Language) -> bool {
+        self.pointer == other.pointer
+    }
+}
@@ -0,0 +1,363 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::{self, ffi};
+use glib::{to_bool, to_gboolean};
+use glib::translate::FromGlibPtr;
+use libc::{c_char, c_int};
+
+/// The PangoLayout structure represents an entire paragraph of text. It is initialized with a
+/// PangoContext, UTF-8 string and set of attributes for that string. Once that is done, the set
+/// of formatted lines can be extracted from the object, the layout can be rendered, and
+/// conversion between logical character positions within the layout's text, and the physical
+/// position of the resulting glyphs can be made.
+pub struct Layout {
+    pointer: *mut ffi::C_PangoLayout
+}
+
+impl Layout {
+    /// Creates a new PangoLayout object with attributes initialized to default values for a
+    /// particular PangoContext.
+    pub fn new(context: &pango::Context) -> Option<Layout> {
+        let tmp = unsafe { ffi::pango_layout_new(context.unwrap_pointer()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Layout {
+                pointer: tmp
+            })
+        }
+    }
+
+    /// Does a deep copy-by-value of the layout. The attribute list, tab array, and text from the
+    /// original layout are all copied by value.
+    pub fn copy(&self) -> Option<Layout> {
+        let tmp = unsafe { ffi::pango_layout_copy(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Layout {
+                pointer: tmp
+            })
+        }
+    }
+
+    pub fn get_context(&self) -> Option<pango::Context> {
+        let tmp = unsafe { ffi::pango_layout_get_context(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            unsafe { ::glib::ffi::g_object_ref(tmp as *mut ::glib::ffi::C_GObject) };
+            Some(pango::Context::wrap_pointer(tmp))
+        }
+    }
+
+    /// Forces recomputation of any state in the layout that might depend on the layout's
+    /// context. This function should be called if you make changes to the context subsequent to
+    /// creating the layout.
+    pub fn context_changed(&self) {
+        unsafe { ffi::pango_layout_context_changed(self.pointer) }
+    }
+
+    /// Returns the current serial number of the layout. The serial number is changed whenever
+    /// the layout is changed.
+    pub fn get_serial(&self) -> u32 {
+        unsafe { ffi::pango_layout_get_serial(self.pointer) }
+    }
+
+    pub fn set_text(&self, text: &str) {
+        unsafe { ffi::pango_layout_set_text(self.pointer, text.as_ptr() as *const c_char, text.len() as c_int) }
+    }
+
+    pub fn get_text(&self) -> Option<String> {
+        unsafe {
+            FromGlibPtr::borrow(
+                ffi::pango_layout_get_text(self.pointer))
+        }
+    }
+
+    pub fn get_character_count(&self) -> i32 {
+        unsafe { ffi::pango_layout_get_character_count(self.pointer) }
+    }
+
+    /// Sets the layout text and attribute list from marked-up text.
+    pub fn set_markup(&self, markup: &str) {
+        unsafe { ffi::pango_layout_set_markup(self.pointer, markup.as_ptr() as *const c_char, markup.len() as c_int) }
+    }
+
+    pub fn set_attributes(&self, attrs: &pango::AttrList) {
+        unsafe { ffi::pango_layout_set_attributes(self.pointer, attrs.unwrap_pointer()) }
+    }
+
+    pub fn get_attributes(&self) -> Option<pango::AttrList> {
+        let tmp = unsafe { ffi::pango_layout_get_attributes(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::AttrList::wrap_pointer(unsafe { ffi::pango_attr_list_ref(tmp) }))
+        }
+    }
+
+    /// Sets the default font description for the layout. If no font description is set on the
+    /// layout, the font description from the layout's context is used.
+    pub fn set_font_description(&self, desc: &pango::FontDescription) {
+        unsafe { ffi::pango_layout_set_font_description(self.pointer, desc.unwrap_pointer()) }
+    }
+
+    pub fn get_font_description(&self) -> Option<pango::FontDescription> {
+        let tmp = unsafe { ffi::pango_layout_get_font_description(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::FontDescription::wrap_pointer(unsafe {
+                ffi::pango_font_description_copy(tmp)
+            }))
+        }
+    }
+
+    /// Sets the width to which the lines of the layout should wrap or ellipsized, in Pango
+    /// units. -1 means no wrapping.
+    pub fn set_width(&self, width: i32) {
+        unsafe { ffi::pango_layout_set_width(self.pointer, width as c_int) }
+    }
+
+    pub fn get_width(&self) -> i32 {
+        unsafe { ffi::pango_layout_get_width(self.pointer) }
+    }
+
+    /// Sets the height to which the layout should be ellipsized at. A positive value is in Pango
+    /// units, a negative value is a number of lines per paragraph.
+    pub fn set_height(&self, height: i32) {
+        unsafe { ffi::pango_layout_set_height(self.pointer, height as c_int) }
+    }
+
+    pub fn get_height(&self) -> i32 {
+        unsafe { ffi::pango_layout_get_height(self.pointer) }
+    }
+
+    pub fn set_wrap(&self, wrap: pango::WrapMode) {
+        unsafe { ffi::pango_layout_set_wrap(self.pointer, wrap) }
+    }
+
+    pub fn get_wrap(&self) -> pango::WrapMode {
+        unsafe { ffi::pango_layout_get_wrap(self.pointer) }
+    }
+
+    /// Queries whether the layout had to wrap any paragraphs.
+    pub fn is_wrapped(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_is_wrapped(self.pointer)) }
+    }
+
+    pub fn set_ellipsize(&self, ellipsize: pango::EllipsizeMode) {
+        unsafe { ffi::pango_layout_set_ellipsize(self.pointer, ellipsize) }
+    }
+
+    pub fn get_ellipsize(&self) -> pango::EllipsizeMode {
+        unsafe { ffi::pango_layout_get_ellipsize(self.pointer) }
+    }
+
+    /// Queries whether the layout had to ellipsize any paragraphs.
+    pub fn is_ellipsized(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_is_ellipsized(self.pointer)) }
+    }
+
+    pub fn set_indent(&self, indent: i32) {
+        unsafe { ffi::pango_layout_set_indent(self.pointer, indent as c_int) }
+    }
+
+    pub fn get_indent(&self) -> i32 {
+        unsafe { ffi::pango_layout_get_indent(self.pointer) }
+    }
+
+    pub fn set_spacing(&self, spacing: i32) {
+        unsafe { ffi::pango_layout_set_spacing(self.pointer, spacing as c_int) }
+    }
+
+    pub fn get_spacing(&self) -> i32 {
+        unsafe { ffi::pango_layout_get_spacing(self.pointer) }
+    }
+
+    pub fn set_justify(&self, justify: bool) {
+        unsafe { ffi::pango_layout_set_justify(self.pointer, to_gboolean(justify)) }
+    }
+
+    pub fn get_justify(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_get_justify(self.pointer)) }
+    }
+
+    /// Sets whether to calculate the bidirectional base direction for the layout according to
+    /// the contents of the layout.
+    pub fn set_auto_dir(&self, auto_dir: bool) {
+        unsafe { ffi::pango_layout_set_auto_dir(self.pointer, to_gboolean(auto_dir)) }
+    }
+
+    pub fn get_auto_dir(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_get_auto_dir(self.pointer)) }
+    }
+
+    pub fn set_alignment(&self, alignment: pango::Alignment) {
+        unsafe { ffi::pango_layout_set_alignment(self.pointer, alignment) }
+    }
+
+    pub fn get_alignment(&self) -> pango::Alignment {
+        unsafe { ffi::pango_layout_get_alignment(self.pointer) }
+    }
+
+    /// Sets the tabs to use for the layout, overriding the default tabs (by default, tabs are
+    /// every 8 spaces). The tab array is copied by the layout.
+    pub fn set_tabs(&self, tabs: &pango::TabArray) {
+        unsafe { ffi::pango_layout_set_tabs(self.pointer, tabs.unwrap_pointer()) }
+    }
+
+    /// Gets a copy of the tabs of the layout. Returns None if the default tabs are in use.
+    pub fn get_tabs(&self) -> Option<pango::TabArray> {
+        let tmp = unsafe { ffi::pango_layout_get_tabs(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::TabArray::wrap_pointer(tmp))
+        }
+    }
+
+    pub fn set_single_paragraph_mode(&self, setting: bool) {
+        unsafe { ffi::pango_layout_set_single_paragraph_mode(self.pointer, to_gboolean(setting)) }
+    }
+
+    pub fn get_single_paragraph_mode(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_get_single_paragraph_mode(self.pointer)) }
+    }
+
+    /// Counts the number of unknown glyphs in the layout.
+    pub fn get_unknown_glyphs_count(&self) -> i32 {
+        unsafe { ffi::pango_layout_get_unknown_glyphs_count(self.pointer) }
+    }
+
+    /// Converts from an index within the layout to the onscreen position corresponding to the
+    /// grapheme at that index, in Pango units.
+    pub fn index_to_pos(&self, index_: i32, pos: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_index_to_pos(self.pointer, index_ as c_int, pos) }
+    }
+
+    /// Given an index within the layout, determines the positions of the strong and weak cursors
+    /// if the insertion point is at that index.
+    pub fn get_cursor_pos(&self, index_: i32, strong_pos: &mut pango::Rectangle, weak_pos: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_get_cursor_pos(self.pointer, index_ as c_int, strong_pos, weak_pos) }
+    }
+
+    /// Converts from x and y position within the layout to the byte index of the character at
+    /// that logical position. Returns false if the position was outside the layout.
+    pub fn xy_to_index(&self, x: i32, y: i32, index_: &mut i32, trailing: &mut i32) -> bool {
+        unsafe { to_bool(ffi::pango_layout_xy_to_index(self.pointer, x as c_int, y as c_int, index_, trailing)) }
+    }
+
+    /// Computes the logical and ink extents of the layout, in Pango units.
+    pub fn get_extents(&self, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_get_extents(self.pointer, ink_rect, logical_rect) }
+    }
+
+    /// Computes the logical and ink extents of the layout, in device units.
+    pub fn get_pixel_extents(&self, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_get_pixel_extents(self.pointer, ink_rect, logical_rect) }
+    }
+
+    /// Determines the logical width and height of the layout in Pango units.
+    pub fn get_size(&self, width: &mut i32, height: &mut i32) {
+        unsafe { ffi::pango_layout_get_size(self.pointer, width, height) }
+    }
+
+    /// Determines the logical width and height of the layout in device units.
+    pub fn get_pixel_size(&self, width: &mut i32, height: &mut i32) {
+        unsafe { ffi::pango_layout_get_pixel_size(self.pointer, width, height) }
+    }
+
+    /// Gets the Y position of the baseline of the first line in the layout.
+    pub fn get_baseline(&self) -> i32 {
+        unsafe { ffi::pango_layout_get_baseline(self.pointer) }
+    }
+
+    pub fn get_line_count(&self) -> i32 {
+        unsafe { ffi::pango_layout_get_line_count(self.pointer) }
+    }
+
+    /// Retrieves a particular line from the layout.
+    pub fn get_line(&self, line: i32) -> Option<pango::LayoutLine> {
+        let tmp = unsafe { ffi::pango_layout_get_line(self.pointer, line as c_int) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::LayoutLine::wrap_pointer(unsafe { ffi::pango_layout_line_ref(tmp) }))
+        }
+    }
+
+    /// Retrieves a particular line from the layout. This is a faster alternative to `get_line`,
+    /// but the user is not expected to modify the contents of the line.
+    pub fn get_line_readonly(&self, line: i32) -> Option<pango::LayoutLine> {
+        let tmp = unsafe { ffi::pango_layout_get_line_readonly(self.pointer, line as c_int) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::LayoutLine::wrap_pointer(unsafe { ffi::pango_layout_line_ref(tmp) }))
+        }
+    }
+
+    /// Returns an iterator to iterate over the visual extents of the layout.
+    pub fn get_iter<'a>(&'a self) -> Option<pango::LayoutIter<'a>> {
+        let tmp = unsafe { ffi::pango_layout_get_iter(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::LayoutIter::wrap_pointer(tmp))
+        }
+    }
+
+    /// Takes ownership of a reference to a PangoLayout.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoLayout) -> Layout {
+        Layout {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoLayout {
+        self.pointer
+    }
+}
+
+impl Clone for Layout {
+    fn clone(&self) -> Layout {
+        unsafe { ::glib::ffi::g_object_ref(self.pointer as *mut ::glib::ffi::C_GObject) };
+
+        Layout {
+            pointer: self.pointer
+        }
+    }
+}
+
+impl Drop for Layout {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ::glib::ffi::g_object_unref(self.pointer as *mut ::glib::ffi::C_GObject) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango;
+use std::collections::{HashMap, BTreeMap};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Key {
+    text: String,
+    font: Option<String>,
+    width: i32,
+    // address of the attribute list, the entry holds a reference on it so it can't be reused
+    attrs: usize
+}
+
+struct Entry {
+    layout: pango::Layout,
+    pixel_width: i32,
+    pixel_height: i32,
+    last_used: u64,
+    _attrs: Option<pango::AttrList>
+}
+
+/// A LayoutCache keeps laid out Layouts and their pixel sizes for text measured over and over,
+/// keyed by text, font description, width and attribute list. The least recently used entries
+/// are evicted once the capacity is reached.
+///
+/// The cache is flushed when the serial of its context changes, which happens when the font map
+/// or the resolution of the context change. Using the context of a widget
+/// (`WidgetTrait::get_pango_context`) and calling `invalidate` from the `StyleUpdated` and
+/// `ScreenChanged` signals of that widget keeps the cache in sync with the widget.
+///
+/// Attribute lists are compared by identity: the cache keeps a reference on the AttrList it is
+/// given, the same list must be passed again to hit the cache and it must not be modified
+/// afterwards.
+pub struct LayoutCache {
+    context: pango::Context,
+    serial: u32,
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<Key, Entry>,
+    lru: BTreeMap<u64, Key>
+}
+
+impl LayoutCache {
+    /// Creates a cache holding at most `capacity` layouts created from `context`.
+    pub fn new(context: &pango::Context, capacity: usize) -> LayoutCache {
+        LayoutCache {
+            context: context.clone(),
+            serial: context.get_serial(),
+            capacity: capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            lru: BTreeMap::new()
+        }
+    }
+
+    /// Returns the layout for `text`. `width` is in Pango units, -1 means no wrapping.
+    ///
+    /// The layout is shared with the cache, it must not be modified: use `Layout::copy` to get a
+    /// layout that can be.
+    pub fn get_layout(&mut self, text: &str, desc: Option<&pango::FontDescription>, width: i32,
+                      attrs: Option<pango::AttrList>) -> Option<pango::Layout> {
+        if self.capacity == 0 {
+            return self.create_layout(text, desc, width, attrs.as_ref());
+        }
+        self.lookup(text, desc, width, attrs).map(|entry| entry.layout.clone())
+    }
+
+    /// Returns the logical size of `text` in device units, as `Layout::get_pixel_size` would.
+    pub fn get_pixel_size(&mut self, text: &str, desc: Option<&pango::FontDescription>, width: i32,
+                          attrs: Option<pango::AttrList>) -> (i32, i32) {
+        if self.capacity == 0 {
+            let mut pixel_width = 0;
+            let mut pixel_height = 0;
+
+            if let Some(layout) = self.create_layout(text, desc, width, attrs.as_ref()) {
+                layout.get_pixel_size(&mut pixel_width, &mut pixel_height);
+            }
+            return (pixel_width, pixel_height);
+        }
+        match self.lookup(text, desc, width, attrs) {
+            Some(entry) => (entry.pixel_width, entry.pixel_height),
+            None => (0, 0)
+        }
+    }
+
+    /// Drops every cached layout. Call it when the style or the screen of the widget owning the
+    /// context changes.
+    pub fn invalidate(&mut self) {
+        self.entries.clear();
+        self.lru.clear();
+        self.serial = self.context.get_serial();
+    }
+
+    pub fn get_context(&self) -> pango::Context {
+        self.context.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn get_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the capacity of the cache, evicting the least recently used layouts if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > self.capacity {
+            self.evict();
+        }
+    }
+
+    fn lookup(&mut self, text: &str, desc: Option<&pango::FontDescription>, width: i32,
+              attrs: Option<pango::AttrList>) -> Option<&Entry> {
+        if self.context.get_serial() != self.serial {
+            self.invalidate();
+        }
+
+        let key = Key {
+            text: text.to_string(),
+            font: desc.and_then(|d| d.to_string()),
+            width: width,
+            attrs: attrs.as_ref().map(|a| a.unwrap_pointer() as usize).unwrap_or(0)
+        };
+
+        self.tick += 1;
+        let tick = self.tick;
+
+        let hit = match self.entries.get_mut(&key) {
+            Some(entry) => {
+                self.lru.remove(&entry.last_used);
+                entry.last_used = tick;
+                true
+            }
+            None => false
+        };
+
+        if !hit {
+            let layout = match self.create_layout(text, desc, width, attrs.as_ref()) {
+                Some(layout) => layout,
+                None => return None
+            };
+            let mut pixel_width = 0;
+            let mut pixel_height = 0;
+            layout.get_pixel_size(&mut pixel_width, &mut pixel_height);
+
+            while self.entries.len() >= self.capacity {
+                self.evict();
+            }
+
+            self.entries.insert(key.clone(), Entry {
+                layout: layout,
+                pixel_width: pixel_width,
+                pixel_height: pixel_height,
+                last_used: tick,
+                _attrs: attrs
+            });
+        }
+
+        self.lru.insert(tick, key.clone());
+        self.entries.get(&key)
+    }
+
+    fn create_layout(&self, text: &str, desc: Option<&pango::FontDescription>, width: i32,
+                     attrs: Option<&pango::AttrList>) -> Option<pango::Layout> {
+        let layout = match pango::Layout::new(&self.context) {
+            Some(layout) => layout,
+            None => return None
+        };
+
+        if let Some(desc) = desc {
+            layout.set_font_description(desc);
+        }
+        if let Some(attrs) = attrs {
+            layout.set_attributes(attrs);
+        }
+        layout.set_width(width);
+        layout.set_text(text);
+        Some(layout)
+    }
+
+    fn evict(&mut self) {
+        let oldest = match self.lru.keys().next() {
+            Some(tick) => *tick,
+            None => return
+        };
+
+        if let Some(key) = self.lru.remove(&oldest) {
+            self.entries.remove(&key);
+        }
+    }
+}
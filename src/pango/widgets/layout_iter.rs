@@ -0,0 +1,126 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::{self, ffi};
+use glib::to_bool;
+use std::marker::PhantomData;
+
+/// A PangoLayoutIter structure can be used to iterate over the visual extents of a PangoLayout.
+/// The iterator borrows the layout it was created from, which must not be modified while
+/// iterating.
+pub struct LayoutIter<'a> {
+    pointer: *mut ffi::C_PangoLayoutIter,
+    _marker: PhantomData<&'a pango::Layout>
+}
+
+impl<'a> LayoutIter<'a> {
+    /// Moves forward to the next run in visual order. Returns false if the iterator was already
+    /// at the end of the layout.
+    pub fn next_run(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_iter_next_run(self.pointer)) }
+    }
+
+    /// Moves forward to the next character in visual order.
+    pub fn next_char(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_iter_next_char(self.pointer)) }
+    }
+
+    /// Moves forward to the next cluster in visual order.
+    pub fn next_cluster(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_iter_next_cluster(self.pointer)) }
+    }
+
+    /// Moves forward to the start of the next line.
+    pub fn next_line(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_iter_next_line(self.pointer)) }
+    }
+
+    pub fn at_last_line(&self) -> bool {
+        unsafe { to_bool(ffi::pango_layout_iter_at_last_line(self.pointer)) }
+    }
+
+    /// Gets the current byte index. Note that iterating forward by char moves in visual order,
+    /// not logical order, so indexes may not be sequential.
+    pub fn get_index(&self) -> i32 {
+        unsafe { ffi::pango_layout_iter_get_index(self.pointer) }
+    }
+
+    /// Gets the Y position of the current line's baseline, in layout coordinates.
+    pub fn get_baseline(&self) -> i32 {
+        unsafe { ffi::pango_layout_iter_get_baseline(self.pointer) }
+    }
+
+    pub fn get_line_readonly(&self) -> Option<pango::LayoutLine> {
+        let tmp = unsafe { ffi::pango_layout_iter_get_line_readonly(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(pango::LayoutLine::wrap_pointer(unsafe { ffi::pango_layout_line_ref(tmp) }))
+        }
+    }
+
+    /// Gets the extents of the current character, in layout coordinates.
+    pub fn get_char_extents(&self, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_iter_get_char_extents(self.pointer, logical_rect) }
+    }
+
+    /// Gets the extents of the current cluster, in layout coordinates.
+    pub fn get_cluster_extents(&self, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_iter_get_cluster_extents(self.pointer, ink_rect, logical_rect) }
+    }
+
+    /// Gets the extents of the current run, in layout coordinates.
+    pub fn get_run_extents(&self, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_iter_get_run_extents(self.pointer, ink_rect, logical_rect) }
+    }
+
+    /// Divides the vertical space being iterated over between the lines in the layout, and
+    /// returns the space belonging to the current line.
+    pub fn get_line_yrange(&self, y0_: &mut i32, y1_: &mut i32) {
+        unsafe { ffi::pango_layout_iter_get_line_yrange(self.pointer, y0_, y1_) }
+    }
+
+    /// Obtains the extents of the current line, in layout coordinates.
+    pub fn get_line_extents(&self, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_iter_get_line_extents(self.pointer, ink_rect, logical_rect) }
+    }
+
+    /// Obtains the extents of the layout being iterated over.
+    pub fn get_layout_extents(&self, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_iter_get_layout_extents(self.pointer, ink_rect, logical_rect) }
+    }
+
+    /// Takes ownership of a PangoLayoutIter.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoLayoutIter) -> LayoutIter<'a> {
+        LayoutIter {
+            pointer: pointer,
+            _marker: PhantomData
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoLayoutIter {
+        self.pointer
+    }
+}
+
+impl<'a> Drop for LayoutIter<'a> {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::pango_layout_iter_free(self.pointer) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
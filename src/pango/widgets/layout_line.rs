@@ -0,0 +1,125 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use glib::{to_bool, to_gboolean};
+use glib::translate::from_glib;
+use libc::c_int;
+
+/// The PangoLayoutLine structure represents one of the lines resulting from laying out a
+/// paragraph via PangoLayout. A LayoutLine keeps a reference on the line, it remains valid until
+/// the layout is next modified.
+pub struct LayoutLine {
+    pointer: *mut ffi::C_PangoLayoutLine
+}
+
+impl LayoutLine {
+    /// Byte index of the start of the line into the layout text.
+    pub fn get_start_index(&self) -> i32 {
+        unsafe { (*self.pointer).start_index }
+    }
+
+    /// Length of the line in bytes.
+    pub fn get_length(&self) -> i32 {
+        unsafe { (*self.pointer).length }
+    }
+
+    /// Whether this is the first line of the paragraph.
+    pub fn is_paragraph_start(&self) -> bool {
+        unsafe { (*self.pointer).bits & 1 != 0 }
+    }
+
+    /// The resolved direction of the line.
+    pub fn get_resolved_direction(&self) -> pango::Direction {
+        from_glib(unsafe { ((*self.pointer).bits >> 1 & 0x7) as i32 })
+    }
+
+    /// Computes the logical and ink extents of the line, in Pango units.
+    pub fn get_extents(&self, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_line_get_extents(self.pointer, ink_rect, logical_rect) }
+    }
+
+    /// Computes the logical and ink extents of the line, in device units.
+    pub fn get_pixel_extents(&self, ink_rect: &mut pango::Rectangle, logical_rect: &mut pango::Rectangle) {
+        unsafe { ffi::pango_layout_line_get_pixel_extents(self.pointer, ink_rect, logical_rect) }
+    }
+
+    /// Converts a byte index within the line to the x position, in Pango units, of the leading
+    /// or trailing edge of the grapheme.
+    pub fn index_to_x(&self, index_: i32, trailing: bool) -> i32 {
+        let mut x_pos = 0;
+
+        unsafe { ffi::pango_layout_line_index_to_x(self.pointer, index_ as c_int, to_gboolean(trailing), &mut x_pos) };
+        x_pos
+    }
+
+    /// Converts from x offset, in Pango units, to the byte index of the corresponding character
+    /// within the text of the layout. Returns false if `x_pos` was outside the line.
+    pub fn x_to_index(&self, x_pos: i32, index_: &mut i32, trailing: &mut i32) -> bool {
+        unsafe { to_bool(ffi::pango_layout_line_x_to_index(self.pointer, x_pos as c_int, index_, trailing)) }
+    }
+
+    /// Gets a list of visual ranges corresponding to a given logical range, as `(start, end)`
+    /// x positions in Pango units relative to the left of the layout. The list is not
+    /// necessarily minimal, there may be consecutive ranges which are adjacent.
+    pub fn get_x_ranges(&self, start_index: i32, end_index: i32) -> Vec<(i32, i32)> {
+        let mut ranges = ::std::ptr::null_mut();
+        let mut n_ranges : c_int = 0;
+
+        unsafe {
+            ffi::pango_layout_line_get_x_ranges(self.pointer, start_index as c_int, end_index as c_int, &mut ranges,
+                &mut n_ranges)
+        };
+
+        let mut ret = Vec::with_capacity(n_ranges as usize);
+
+        if !ranges.is_null() {
+            unsafe {
+                for i in 0..n_ranges as isize {
+                    ret.push((*ranges.offset(2 * i), *ranges.offset(2 * i + 1)));
+                }
+                ::glib::ffi::g_free(ranges as ::glib::ffi::gpointer);
+            }
+        }
+        ret
+    }
+
+    /// Takes ownership of a reference to a PangoLayoutLine.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoLayoutLine) -> LayoutLine {
+        LayoutLine {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoLayoutLine {
+        self.pointer
+    }
+}
+
+impl Clone for LayoutLine {
+    fn clone(&self) -> LayoutLine {
+        LayoutLine {
+            pointer: unsafe { ffi::pango_layout_line_ref(self.pointer) }
+        }
+    }
+}
+
+impl Drop for LayoutLine {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::pango_layout_line_unref(self.pointer) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
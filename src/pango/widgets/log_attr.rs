@@ -0,0 +1,103 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::ffi;
+use std::default::Default;
+
+/// The PangoLogAttr structure stores information about the attributes of a single character. It
+/// is filled by `pango::break_()` and `pango::get_log_attrs()`.
+pub type LogAttr = ffi::C_PangoLogAttr;
+
+impl LogAttr {
+    fn get_bit(&self, bit: u32) -> bool {
+        self.bits & (1 << bit) != 0
+    }
+
+    /// if set, can break line in front of character
+    pub fn is_line_break(&self) -> bool {
+        self.get_bit(0)
+    }
+
+    /// if set, must break line in front of character
+    pub fn is_mandatory_break(&self) -> bool {
+        self.get_bit(1)
+    }
+
+    /// if set, can break here when doing character wrapping
+    pub fn is_char_break(&self) -> bool {
+        self.get_bit(2)
+    }
+
+    /// is whitespace character
+    pub fn is_white(&self) -> bool {
+        self.get_bit(3)
+    }
+
+    /// if set, cursor can appear in front of character, i.e. this is a grapheme boundary, or the
+    /// first character in the text.
+    pub fn is_cursor_position(&self) -> bool {
+        self.get_bit(4)
+    }
+
+    /// is first character in a word
+    pub fn is_word_start(&self) -> bool {
+        self.get_bit(5)
+    }
+
+    /// is first non-word char after a word
+    pub fn is_word_end(&self) -> bool {
+        self.get_bit(6)
+    }
+
+    /// is a sentence boundary.
+    pub fn is_sentence_boundary(&self) -> bool {
+        self.get_bit(7)
+    }
+
+    /// is first character in a sentence
+    pub fn is_sentence_start(&self) -> bool {
+        self.get_bit(8)
+    }
+
+    /// is first char after a sentence
+    pub fn is_sentence_end(&self) -> bool {
+        self.get_bit(9)
+    }
+
+    /// if set, backspace deletes one character rather than the entire grapheme cluster.
+    pub fn backspace_deletes_character(&self) -> bool {
+        self.get_bit(10)
+    }
+
+    /// is a whitespace character that can possibly be expanded for justification purposes.
+    /// (Since: 1.18)
+    pub fn is_expandable_space(&self) -> bool {
+        self.get_bit(11)
+    }
+
+    /// is a word boundary. More specifically, means that this is not a position in the middle of
+    /// a word. (Since: 1.22)
+    pub fn is_word_boundary(&self) -> bool {
+        self.get_bit(12)
+    }
+}
+
+impl Default for LogAttr {
+    fn default() -> LogAttr {
+        LogAttr {
+            bits: 0
+        }
+    }
+}
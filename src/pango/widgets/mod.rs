@@ -17,8 +17,34 @@ pub use self::item::Item;
 pub use self::rectangle::Rectangle;
 pub use self::matrix::Matrix;
 pub use self::glyph_string::GlyphString;
+pub use self::context::Context;
+pub use self::attr_list::AttrList;
+pub use self::language::Language;
+pub use self::font::Font;
+pub use self::analysis::Analysis;
+pub use self::log_attr::LogAttr;
+pub use self::script_iter::ScriptIter;
+pub use self::font_description::FontDescription;
+pub use self::tab_array::TabArray;
+pub use self::layout::Layout;
+pub use self::layout_line::LayoutLine;
+pub use self::layout_iter::LayoutIter;
+pub use self::layout_cache::LayoutCache;
 
 mod item;
 mod rectangle;
 mod matrix;
-mod glyph_string;
\ No newline at end of file
+mod glyph_string;
+mod context;
+mod attr_list;
+mod language;
+mod font;
+mod analysis;
+mod log_attr;
+mod script_iter;
+mod font_description;
+mod tab_array;
+mod layout;
+mod layout_line;
+mod layout_iter;
+mod layout_cache;
\ No newline at end of file
@@ -0,0 +1,74 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::{self, ffi};
+use glib::to_bool;
+use glib::translate::from_glib;
+use libc::{c_char, c_int};
+
+/// A ScriptIter is used to iterate through a string and identify ranges in different scripts.
+/// Each step yields a slice of the text written in a single script, along with that script.
+pub struct ScriptIter<'a> {
+    pointer: *mut ffi::C_PangoScriptIter,
+    text: &'a str,
+    done: bool
+}
+
+impl<'a> ScriptIter<'a> {
+    pub fn new(text: &'a str) -> Option<ScriptIter<'a>> {
+        let tmp = unsafe { ffi::pango_script_iter_new(text.as_ptr() as *const c_char, text.len() as c_int) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(ScriptIter {
+                pointer: tmp,
+                text: text,
+                done: text.is_empty()
+            })
+        }
+    }
+}
+
+impl<'a> Iterator for ScriptIter<'a> {
+    type Item = (&'a str, pango::Script);
+
+    fn next(&mut self) -> Option<(&'a str, pango::Script)> {
+        if self.done {
+            return None;
+        }
+
+        let mut start = ::std::ptr::null();
+        let mut end = ::std::ptr::null();
+        let mut script = 0;
+
+        unsafe { ffi::pango_script_iter_get_range(self.pointer, &mut start, &mut end, &mut script) };
+
+        let base = self.text.as_ptr() as usize;
+        let range = &self.text[(start as usize - base)..(end as usize - base)];
+
+        self.done = unsafe { !to_bool(ffi::pango_script_iter_next(self.pointer)) };
+        Some((range, from_glib(script)))
+    }
+}
+
+impl<'a> Drop for ScriptIter<'a> {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::pango_script_iter_free(self.pointer) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// This file is part of rgtk.
+//
+// rgtk is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Lesser General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// rgtk is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with rgtk.  If not, see <http://www.gnu.org/licenses/>.
+
+use pango::{self, ffi};
+use glib::{to_bool, to_gboolean};
+use libc::c_int;
+
+/// A PangoTabArray struct contains an array of tab stops. Each tab stop has an alignment and a
+/// position, either in Pango units or in pixels.
+pub struct TabArray {
+    pointer: *mut ffi::C_PangoTabArray
+}
+
+impl TabArray {
+    /// Creates an array of `initial_size` tab stops. Tab stops are specified in pixel units if
+    /// `positions_in_pixels` is true, otherwise in Pango units. All stops are initially at
+    /// position 0.
+    pub fn new(initial_size: i32, positions_in_pixels: bool) -> Option<TabArray> {
+        let tmp = unsafe { ffi::pango_tab_array_new(initial_size as c_int, to_gboolean(positions_in_pixels)) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(TabArray {
+                pointer: tmp
+            })
+        }
+    }
+
+    /// Creates a tab array holding the given `(alignment, location)` tab stops.
+    pub fn new_with_positions(positions_in_pixels: bool, tabs: &[(pango::TabAlign, i32)]) -> Option<TabArray> {
+        TabArray::new(tabs.len() as i32, positions_in_pixels).map(|array| {
+            for (i, &(alignment, location)) in tabs.iter().enumerate() {
+                array.set_tab(i as i32, alignment, location);
+            }
+            array
+        })
+    }
+
+    pub fn get_size(&self) -> i32 {
+        unsafe { ffi::pango_tab_array_get_size(self.pointer) }
+    }
+
+    /// Resizes a tab array. You must subsequently initialize any tabs that were added as a result
+    /// of growing the array.
+    pub fn resize(&self, new_size: i32) {
+        unsafe { ffi::pango_tab_array_resize(self.pointer, new_size as c_int) }
+    }
+
+    pub fn set_tab(&self, tab_index: i32, alignment: pango::TabAlign, location: i32) {
+        unsafe { ffi::pango_tab_array_set_tab(self.pointer, tab_index as c_int, alignment, location as c_int) }
+    }
+
+    pub fn get_tab(&self, tab_index: i32, alignment: &mut pango::TabAlign, location: &mut i32) {
+        unsafe { ffi::pango_tab_array_get_tab(self.pointer, tab_index as c_int, alignment, location) }
+    }
+
+    /// Returns all the tab stops as `(alignment, location)` pairs.
+    pub fn get_tabs(&self) -> Vec<(pango::TabAlign, i32)> {
+        let size = self.get_size();
+        let mut ret = Vec::with_capacity(size as usize);
+
+        for i in 0..size {
+            let mut alignment = pango::TabAlign::Left;
+            let mut location = 0;
+
+            self.get_tab(i, &mut alignment, &mut location);
+            ret.push((alignment, location));
+        }
+        ret
+    }
+
+    pub fn get_positions_in_pixels(&self) -> bool {
+        unsafe { to_bool(ffi::pango_tab_array_get_positions_in_pixels(self.pointer)) }
+    }
+
+    /// Takes ownership of a PangoTabArray.
+    pub fn wrap_pointer(pointer: *mut ffi::C_PangoTabArray) -> TabArray {
+        TabArray {
+            pointer: pointer
+        }
+    }
+
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_PangoTabArray {
+        self.pointer
+    }
+}
+
+impl Clone for TabArray {
+    fn clone(&self) -> TabArray {
+        TabArray {
+            pointer: unsafe { ffi::pango_tab_array_copy(self.pointer) }
+        }
+    }
+}
+
+impl Drop for TabArray {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::pango_tab_array_free(self.pointer) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}